@@ -0,0 +1,62 @@
+//! Power/Thermal Consistency Checks
+//!
+//! Power and thermal analyses are run independently, so nothing stops a caller
+//! from sizing solar panels for one orbit and radiators for another. This module
+//! cross-checks the two results and reports any disagreement.
+
+use crate::power::PowerBudget;
+use crate::thermal::ThermalResult;
+
+/// Allowed difference in eclipse fraction between analyses
+const ECLIPSE_FRACTION_TOLERANCE: f64 = 0.01;
+/// Allowed relative difference between power load and heat dissipation
+const DISSIPATION_TOLERANCE: f64 = 0.01;
+
+/// Check that a power budget and thermal result describe the same system.
+///
+/// Verifies that both analyses used the same eclipse fraction and that the
+/// thermal model dissipates the power the electrical system delivers.
+///
+/// # Returns
+///
+/// Human-readable discrepancies; empty if the results are consistent.
+pub fn check(power: &PowerBudget, thermal: &ThermalResult) -> Vec<String> {
+    let mut discrepancies = Vec::new();
+
+    if (power.eclipse_fraction - thermal.eclipse_fraction).abs() > ECLIPSE_FRACTION_TOLERANCE {
+        discrepancies.push(format!(
+            "Eclipse fraction mismatch: power uses {:.3}, thermal uses {:.3}",
+            power.eclipse_fraction, thermal.eclipse_fraction
+        ));
+    }
+
+    let load = power.average_power_w;
+    let dissipation = thermal.heat_dissipation_w;
+    if (load - dissipation).abs() > DISSIPATION_TOLERANCE * load.abs().max(1.0) {
+        discrepancies.push(format!(
+            "Heat dissipation ({:.1} W) does not match power load ({:.1} W)",
+            dissipation, load
+        ));
+    }
+
+    discrepancies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PowerAnalyzer, PowerProfile, ThermalConfig, ThermalEnvironment, ThermalSimulator};
+
+    #[test]
+    fn test_mismatched_inputs() {
+        let power =
+            PowerAnalyzer::new(550.0).analyze(&PowerProfile::new(800.0), None, None, None, None);
+        let thermal = ThermalSimulator::new()
+            .simulate(&ThermalConfig::for_power(500.0), &ThermalEnvironment::geo());
+
+        let discrepancies = check(&power, &thermal);
+        assert_eq!(discrepancies.len(), 2);
+        assert!(discrepancies[0].contains("Eclipse fraction"));
+        assert!(discrepancies[1].contains("Heat dissipation"));
+    }
+}
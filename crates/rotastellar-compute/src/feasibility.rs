@@ -13,12 +13,26 @@
 //! - Interactive apps (user-facing latency)
 //! - Database OLTP (requires persistent connections)
 
+use rotastellar::constants::EARTH_RADIUS_MEAN_KM;
+use rotastellar::ValidationError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::precision::Precision;
+use crate::thermal::eclipse_fraction;
+
 // TODO(subhadipmitra): Add cost estimation to feasibility report
 // TODO: Factor in constellation coverage for latency-sensitive workloads
 
+/// Ground stations in the contact network assumed for availability estimates
+const CONTACT_NETWORK_STATIONS: i32 = 10;
+/// Minimum elevation for ground contact in degrees
+const CONTACT_MIN_ELEVATION_DEG: f64 = 10.0;
+/// Fraction of compute shed during eclipse to stay within battery limits
+const ECLIPSE_COMPUTE_SHED: f64 = 0.5;
+/// Lowest altitude analyzed; below this drag decays an orbit within days
+const MIN_ORBIT_ALTITUDE_KM: f64 = 150.0;
+
 /// Types of compute workloads.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -120,9 +134,115 @@ impl WorkloadProfile {
         self.latency_requirement_ms = Some(latency_ms);
         self
     }
+
+    /// Set required uptime percentage (0-100).
+    pub fn with_availability_requirement(mut self, availability_pct: f64) -> Self {
+        self.availability_requirement = Some(availability_pct);
+        self
+    }
+
+    /// Check that every requirement is a finite, physically meaningful value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if compute, memory, storage, data transfer,
+    /// or batch duration is negative or non-finite, if the latency
+    /// requirement is not positive, or if the availability requirement is
+    /// outside 0-100.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        non_negative("compute_tflops", self.compute_tflops)?;
+        let optional = [
+            ("memory_gb", self.memory_gb),
+            ("storage_gb", self.storage_gb),
+            ("data_transfer_gb", self.data_transfer_gb),
+            ("batch_duration_hours", self.batch_duration_hours),
+        ];
+        for (field, value) in optional {
+            if let Some(value) = value {
+                non_negative(field, value)?;
+            }
+        }
+        if let Some(latency_ms) = self.latency_requirement_ms {
+            if !(latency_ms.is_finite() && latency_ms > 0.0) {
+                return Err(ValidationError::new(
+                    "latency_requirement_ms",
+                    "Must be a positive number",
+                ));
+            }
+        }
+        if let Some(availability) = self.availability_requirement {
+            if !(0.0..=100.0).contains(&availability) {
+                return Err(ValidationError::new(
+                    "availability_requirement",
+                    "Must be between 0 and 100",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Preset for training a large language model.
+    ///
+    /// Assumes ~10 TFLOPS sustained per billion parameters, 16 bytes of
+    /// training state per parameter (mixed precision + Adam), and one fp16
+    /// checkpoint downlinked per day.
+    ///
+    /// # Arguments
+    ///
+    /// * `params_billions` - Model size in billions of parameters
+    pub fn llm_training(params_billions: f64) -> Self {
+        Self {
+            memory_gb: Some(params_billions * 16.0),
+            storage_gb: Some(params_billions * 16.0 * 3.0),
+            data_transfer_gb: Some(params_billions * 2.0),
+            batch_duration_hours: Some(24.0 * 7.0),
+            ..Self::new(WorkloadType::Training, params_billions * 10.0)
+        }
+    }
+
+    /// Preset for an offline rendering job.
+    ///
+    /// Cost per frame scales with pixel count; finished frames are downlinked
+    /// as uncompressed 32-bit RGBA.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - Number of frames to render
+    /// * `resolution` - Frame size as (width, height) in pixels
+    pub fn image_rendering(frames: u64, resolution: (u32, u32)) -> Self {
+        let megapixels = resolution.0 as f64 * resolution.1 as f64 / 1e6;
+        let seconds_per_frame = 30.0 * megapixels;
+        Self {
+            memory_gb: Some(8.0 + 2.0 * megapixels),
+            storage_gb: Some(frames as f64 * megapixels * 4.0 / 1000.0),
+            data_transfer_gb: Some(frames as f64 * megapixels * 4.0 / 1000.0),
+            batch_duration_hours: Some(frames as f64 * seconds_per_frame / 3600.0),
+            ..Self::new(WorkloadType::Render, (2.0 * megapixels).min(80.0))
+        }
+    }
+
+    /// Preset for on-orbit inference over Earth observation imagery.
+    ///
+    /// Raw images (~0.5 GB each) stay on board; only ~0.5 MB of results per
+    /// image is downlinked, which is the point of processing in orbit.
+    ///
+    /// # Arguments
+    ///
+    /// * `images_per_day` - Images captured and processed per day
+    pub fn earth_observation_inference(images_per_day: f64) -> Self {
+        Self {
+            memory_gb: Some(32.0),
+            storage_gb: Some(images_per_day * 0.5),
+            data_transfer_gb: Some(images_per_day * 0.0005),
+            ..Self::new(WorkloadType::Inference, 5.0 + images_per_day / 2000.0)
+        }
+    }
 }
 
 /// Result of feasibility analysis.
+///
+/// `score` is rounded to two decimals unless the calculator's [`Precision`]
+/// is `Full`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeasibilityResult {
     /// Whether the workload is feasible for orbital compute
@@ -141,6 +261,9 @@ pub struct FeasibilityResult {
     pub latency_feasible: bool,
     /// Whether data transfer requirements can be met
     pub data_transfer_feasible: bool,
+    /// Whether the availability requirement can be met
+    #[serde(default = "default_true")]
+    pub availability_feasible: bool,
     /// List of recommendations
     pub recommendations: Vec<String>,
     /// Key constraints identified
@@ -149,6 +272,95 @@ pub struct FeasibilityResult {
     pub estimated_cost_factor: f64,
 }
 
+fn default_true() -> bool {
+    true
+}
+
+fn non_negative(field: &str, value: f64) -> Result<(), ValidationError> {
+    if value.is_finite() && value >= 0.0 {
+        Ok(())
+    } else {
+        Err(ValidationError::new(field, "Must be a non-negative number"))
+    }
+}
+
+impl FeasibilityResult {
+    /// Render the result as a Markdown report.
+    ///
+    /// Includes the verdict, a per-constraint score table, recommendations,
+    /// and the cost factor. Constraints missing from `constraints` are shown
+    /// as "n/a"; any extra entries are listed after the standard ones.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Orbital Compute Feasibility\n\n");
+        out.push_str(&format!(
+            "**Verdict:** {}  \n**Rating:** {} ({:.1}/100)  \n",
+            if self.feasible {
+                "Feasible"
+            } else {
+                "Not feasible"
+            },
+            self.rating,
+            self.score
+        ));
+        if let Some(altitude) = self.constraints.get("orbit_altitude_km") {
+            out.push_str(&format!("**Orbit altitude:** {:.0} km  \n", altitude));
+        }
+        out.push_str(&format!(
+            "**Estimated cost factor:** {:.2}x terrestrial\n\n",
+            self.estimated_cost_factor
+        ));
+
+        out.push_str("## Constraints\n\n| Constraint | Status | Score |\n|---|---|---|\n");
+        let standard = [
+            ("Compute", "compute_score", self.compute_feasible),
+            ("Thermal", "thermal_score", self.thermal_feasible),
+            ("Power", "power_score", self.power_feasible),
+            ("Latency", "latency_score", self.latency_feasible),
+            (
+                "Data transfer",
+                "data_transfer_score",
+                self.data_transfer_feasible,
+            ),
+            (
+                "Availability",
+                "availability_score",
+                self.availability_feasible,
+            ),
+        ];
+        for (label, key, ok) in standard {
+            out.push_str(&format!(
+                "| {} | {} | {} |\n",
+                label,
+                if ok { "Pass" } else { "**Fail**" },
+                self.constraints
+                    .get(key)
+                    .map_or_else(|| "n/a".to_string(), |score| format!("{:.1}", score))
+            ));
+        }
+
+        let mut extra: Vec<(&String, &f64)> = self
+            .constraints
+            .iter()
+            .filter(|(key, _)| {
+                key.as_str() != "orbit_altitude_km" && !standard.iter().any(|(_, k, _)| k == key)
+            })
+            .collect();
+        extra.sort_by(|a, b| a.0.cmp(b.0));
+        for (key, value) in extra {
+            out.push_str(&format!("| {} | - | {:.1} |\n", key, value));
+        }
+
+        out.push_str("\n## Recommendations\n\n");
+        if self.recommendations.is_empty() {
+            out.push_str("None.\n");
+        }
+        for recommendation in &self.recommendations {
+            out.push_str(&format!("- {}\n", recommendation));
+        }
+        out
+    }
+}
+
 #[derive(Clone, Copy)]
 struct WorkloadCharacteristics {
     thermal_factor: f64,
@@ -217,11 +429,13 @@ impl WorkloadCharacteristics {
 /// let profile = WorkloadProfile::new(WorkloadType::Inference, 10.0)
 ///     .with_memory_gb(32.0)
 ///     .with_latency_requirement_ms(100.0);
-/// let result = calculator.analyze(&profile, None);
+/// let result = calculator.analyze(&profile, None).unwrap();
 /// println!("Feasible: {}, Rating: {}", result.feasible, result.rating);
 /// ```
 pub struct FeasibilityCalculator {
     orbit_altitude_km: f64,
+    nodes: u32,
+    precision: Precision,
 }
 
 impl FeasibilityCalculator {
@@ -240,7 +454,25 @@ impl FeasibilityCalculator {
     ///
     /// * `orbit_altitude_km` - Default orbit altitude in kilometers
     pub fn new(orbit_altitude_km: f64) -> Self {
-        Self { orbit_altitude_km }
+        Self {
+            orbit_altitude_km,
+            nodes: 1,
+            precision: Precision::Rounded,
+        }
+    }
+
+    /// Set how many interchangeable nodes serve the workload (default: 1).
+    ///
+    /// Redundant nodes only affect the availability check.
+    pub fn with_redundant_nodes(mut self, nodes: u32) -> Self {
+        self.nodes = nodes.max(1);
+        self
+    }
+
+    /// Set how the feasibility score is rounded (see [`Precision`]).
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
     }
 
     /// Create a calculator with default altitude (550 km).
@@ -254,8 +486,25 @@ impl FeasibilityCalculator {
     ///
     /// * `profile` - The workload profile to analyze
     /// * `orbit_altitude_km` - Optional override for orbit altitude
-    pub fn analyze(&self, profile: &WorkloadProfile, orbit_altitude_km: Option<f64>) -> FeasibilityResult {
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if the profile fails
+    /// [`validate`](WorkloadProfile::validate) or the altitude is below
+    /// 150 km, where drag makes a sustained orbit impractical.
+    pub fn analyze(
+        &self,
+        profile: &WorkloadProfile,
+        orbit_altitude_km: Option<f64>,
+    ) -> Result<FeasibilityResult, ValidationError> {
+        profile.validate()?;
         let altitude = orbit_altitude_km.unwrap_or(self.orbit_altitude_km);
+        if !(altitude.is_finite() && altitude >= MIN_ORBIT_ALTITUDE_KM) {
+            return Err(ValidationError::new(
+                "orbit_altitude_km",
+                format!("Must be at least {} km", MIN_ORBIT_ALTITUDE_KM),
+            ));
+        }
         let characteristics = WorkloadCharacteristics::for_workload(profile.workload_type);
 
         let memory_gb = profile.memory_gb.unwrap_or(16.0);
@@ -268,13 +517,26 @@ impl FeasibilityCalculator {
         let (latency_ok, latency_score) =
             self.check_latency(profile.latency_requirement_ms, altitude, &characteristics);
         let (data_ok, data_score) = self.check_data_transfer(data_transfer_gb);
-
-        // Calculate overall score
-        let scores = [compute_score, thermal_score, power_score, latency_score, data_score];
+        let achievable_availability = self.achievable_availability(altitude);
+        let availability_check = profile
+            .availability_requirement
+            .map(|required| self.check_availability(required, achievable_availability));
+        let availability_ok = !matches!(availability_check, Some((false, _)));
+
+        // Calculate overall score; availability only counts when required
+        let mut scores = vec![
+            compute_score,
+            thermal_score,
+            power_score,
+            latency_score,
+            data_score,
+        ];
+        scores.extend(availability_check.map(|(_, score)| score));
         let overall_score = scores.iter().sum::<f64>() / scores.len() as f64;
 
         // Determine feasibility
-        let feasible = compute_ok && thermal_ok && power_ok && latency_ok && data_ok;
+        let feasible =
+            compute_ok && thermal_ok && power_ok && latency_ok && data_ok && availability_ok;
 
         // Determine rating
         let rating = if overall_score >= 85.0 {
@@ -290,7 +552,7 @@ impl FeasibilityCalculator {
         };
 
         // Generate recommendations
-        let recommendations = self.generate_recommendations(
+        let mut recommendations = self.generate_recommendations(
             profile,
             compute_ok,
             thermal_ok,
@@ -298,6 +560,18 @@ impl FeasibilityCalculator {
             latency_ok,
             data_ok,
         );
+        if let (Some(required), false) = (profile.availability_requirement, availability_ok) {
+            recommendations.push(match self.nodes_for_availability(required, altitude) {
+                Some(nodes) => format!(
+                    "Deploy at least {} redundant nodes to reach {}% availability",
+                    nodes, required
+                ),
+                None => format!(
+                    "No number of redundant nodes reaches {}% availability at this altitude",
+                    required
+                ),
+            });
+        }
 
         // Estimate cost factor
         let cost_factor = self.estimate_cost_factor(profile, &characteristics);
@@ -310,42 +584,94 @@ impl FeasibilityCalculator {
         constraints.insert("latency_score".to_string(), latency_score);
         constraints.insert("data_transfer_score".to_string(), data_score);
         constraints.insert("orbit_altitude_km".to_string(), altitude);
+        if let Some((_, score)) = availability_check {
+            constraints.insert("availability_score".to_string(), score);
+            constraints.insert(
+                "achievable_availability_pct".to_string(),
+                achievable_availability,
+            );
+        }
 
-        FeasibilityResult {
+        Ok(FeasibilityResult {
             feasible,
             rating,
-            score: (overall_score * 100.0).round() / 100.0,
+            score: self.precision.apply(overall_score, 2),
             compute_feasible: compute_ok,
             thermal_feasible: thermal_ok,
             power_feasible: power_ok,
             latency_feasible: latency_ok,
             data_transfer_feasible: data_ok,
+            availability_feasible: availability_ok,
             recommendations,
             constraints,
             estimated_cost_factor: cost_factor,
-        }
+        })
     }
 
     /// Compare feasibility across different orbit scenarios.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`analyze`](Self::analyze) error.
     pub fn compare_scenarios(
         &self,
         profile: &WorkloadProfile,
         altitudes: &[f64],
-    ) -> Vec<ScenarioResult> {
+    ) -> Result<Vec<ScenarioResult>, ValidationError> {
         altitudes
             .iter()
             .map(|&altitude| {
-                let result = self.analyze(profile, Some(altitude));
-                ScenarioResult {
+                let result = self.analyze(profile, Some(altitude))?;
+                Ok(ScenarioResult {
                     altitude_km: altitude,
                     feasible: result.feasible,
                     rating: result.rating,
                     score: result.score,
-                }
+                })
             })
             .collect()
     }
 
+    /// Compare feasibility for every workload at every altitude.
+    ///
+    /// Returns one row per profile, each with one entry per altitude, in the
+    /// order given.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`analyze`](Self::analyze) error.
+    pub fn compare_matrix(
+        &self,
+        profiles: &[WorkloadProfile],
+        altitudes: &[f64],
+    ) -> Result<Vec<Vec<ScenarioResult>>, ValidationError> {
+        profiles
+            .iter()
+            .map(|profile| self.compare_scenarios(profile, altitudes))
+            .collect()
+    }
+
+    /// Find the candidate LEO altitude that maximizes the feasibility score.
+    ///
+    /// Candidates span 300-2000 km in 100 km steps; ties resolve to the lowest
+    /// altitude.
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if the profile is invalid.
+    pub fn best_altitude(&self, profile: &WorkloadProfile) -> Result<f64, ValidationError> {
+        let altitudes: Vec<f64> = (3..=20).map(|i| i as f64 * 100.0).collect();
+        Ok(self
+            .compare_scenarios(profile, &altitudes)?
+            .into_iter()
+            .fold(None::<ScenarioResult>, |best, scenario| match best {
+                Some(b) if b.score >= scenario.score => Some(b),
+                _ => Some(scenario),
+            })
+            .map(|s| s.altitude_km)
+            .unwrap_or(self.orbit_altitude_km))
+    }
+
     fn check_compute(&self, compute_tflops: f64, memory_gb: f64) -> (bool, f64) {
         if compute_tflops > Self::MAX_COMPUTE_TFLOPS {
             return (false, 20.0);
@@ -433,6 +759,51 @@ impl FeasibilityCalculator {
         }
     }
 
+    /// Uptime of a single node as a fraction: compute shed in eclipse times
+    /// the share of the orbit in contact with the ground network.
+    fn single_node_availability(&self, altitude_km: f64) -> f64 {
+        let compute_uptime = 1.0 - eclipse_fraction(altitude_km) * ECLIPSE_COMPUTE_SHED;
+
+        // Earth central angle visible above the minimum elevation
+        let elevation = CONTACT_MIN_ELEVATION_DEG.to_radians();
+        let nadir =
+            (EARTH_RADIUS_MEAN_KM * elevation.cos() / (EARTH_RADIUS_MEAN_KM + altitude_km)).acos();
+        let central_angle = nadir - elevation;
+        let per_station = (1.0 - central_angle.cos()) / 2.0;
+        let contact_coverage = 1.0 - (1.0 - per_station).powi(CONTACT_NETWORK_STATIONS);
+
+        compute_uptime * contact_coverage
+    }
+
+    /// Percentage of time at least one of the calculator's nodes is up.
+    fn achievable_availability(&self, altitude_km: f64) -> f64 {
+        let single = self.single_node_availability(altitude_km);
+        100.0 * (1.0 - (1.0 - single).powf(self.nodes as f64))
+    }
+
+    /// Smallest node count that reaches the required availability percentage,
+    /// or None if no `u32` count does, as when a single node is never up.
+    fn nodes_for_availability(&self, required_pct: f64, altitude_km: f64) -> Option<u32> {
+        let single = self.single_node_availability(altitude_km);
+        let allowed_downtime = (1.0 - required_pct / 100.0).max(f64::MIN_POSITIVE);
+        let nodes = (allowed_downtime.ln() / (1.0 - single).ln()).ceil();
+        (0.0..=u32::MAX as f64)
+            .contains(&nodes)
+            .then(|| (nodes as u32).max(1))
+    }
+
+    fn check_availability(&self, required_pct: f64, achievable_pct: f64) -> (bool, f64) {
+        if achievable_pct < required_pct {
+            return (false, 20.0);
+        }
+        let allowed_downtime = 100.0 - required_pct;
+        if 100.0 - achievable_pct <= 0.5 * allowed_downtime {
+            (true, 100.0)
+        } else {
+            (true, 80.0)
+        }
+    }
+
     fn generate_recommendations(
         &self,
         profile: &WorkloadProfile,
@@ -480,7 +851,7 @@ impl FeasibilityCalculator {
             base_factor *= 1.3;
         }
 
-        (base_factor * 100.0).round() / 100.0
+        self.precision.apply(base_factor, 2)
     }
 }
 
@@ -503,6 +874,36 @@ pub struct ScenarioResult {
     pub score: f64,
 }
 
+impl ScenarioResult {
+    /// Render scenarios (e.g. from `compare_scenarios`) as a Markdown table.
+    ///
+    /// The best-scoring scenario is marked in bold.
+    pub fn to_markdown_table(scenarios: &[ScenarioResult]) -> String {
+        let best = scenarios
+            .iter()
+            .map(|s| s.score)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let mut out =
+            String::from("| Altitude (km) | Feasible | Rating | Score |\n|---|---|---|---|\n");
+        for scenario in scenarios {
+            let score = if scenario.score == best {
+                format!("**{:.1}**", scenario.score)
+            } else {
+                format!("{:.1}", scenario.score)
+            };
+            out.push_str(&format!(
+                "| {:.0} | {} | {} | {} |\n",
+                scenario.altitude_km,
+                if scenario.feasible { "Yes" } else { "No" },
+                scenario.rating,
+                score
+            ));
+        }
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -514,7 +915,7 @@ mod tests {
             .with_memory_gb(32.0)
             .with_latency_requirement_ms(100.0);
 
-        let result = calculator.analyze(&profile, None);
+        let result = calculator.analyze(&profile, None).unwrap();
 
         assert!(result.feasible);
         assert!(result.score > 50.0);
@@ -525,7 +926,7 @@ mod tests {
         let calculator = FeasibilityCalculator::new(550.0);
         let profile = WorkloadProfile::new(WorkloadType::Training, 150.0);
 
-        let result = calculator.analyze(&profile, None);
+        let result = calculator.analyze(&profile, None).unwrap();
 
         assert!(!result.compute_feasible);
         assert!(!result.feasible);
@@ -536,7 +937,7 @@ mod tests {
         let calculator = FeasibilityCalculator::new(550.0);
         let profile = WorkloadProfile::new(WorkloadType::Batch, 20.0);
 
-        let result = calculator.analyze(&profile, None);
+        let result = calculator.analyze(&profile, None).unwrap();
 
         assert!(result.feasible);
         assert!(result.recommendations.iter().any(|r| r.contains("well-suited")));
@@ -547,7 +948,9 @@ mod tests {
         let calculator = FeasibilityCalculator::new(550.0);
         let profile = WorkloadProfile::new(WorkloadType::Inference, 10.0);
 
-        let scenarios = calculator.compare_scenarios(&profile, &[400.0, 550.0, 800.0, 1200.0]);
+        let scenarios = calculator
+            .compare_scenarios(&profile, &[400.0, 550.0, 800.0, 1200.0])
+            .unwrap();
 
         assert_eq!(scenarios.len(), 4);
         assert_eq!(scenarios[0].altitude_km, 400.0);
@@ -559,7 +962,7 @@ mod tests {
         let profile = WorkloadProfile::new(WorkloadType::Streaming, 5.0)
             .with_latency_requirement_ms(2.0); // Too low for orbital
 
-        let result = calculator.analyze(&profile, None);
+        let result = calculator.analyze(&profile, None).unwrap();
 
         assert!(!result.latency_feasible);
     }
@@ -569,9 +972,162 @@ mod tests {
         let calculator = FeasibilityCalculator::default();
         let profile = WorkloadProfile::new(WorkloadType::Analytics, 5.0);
 
-        let result = calculator.analyze(&profile, None);
+        let result = calculator.analyze(&profile, None).unwrap();
 
         assert!(result.feasible);
         assert_eq!(result.constraints.get("orbit_altitude_km"), Some(&550.0));
     }
+
+    #[test]
+    fn test_workload_presets() {
+        let calculator = FeasibilityCalculator::new(550.0);
+
+        let llm = WorkloadProfile::llm_training(7.0);
+        assert_eq!(llm.workload_type, WorkloadType::Training);
+        assert!(llm.compute_tflops >= 50.0);
+        assert!(llm.memory_gb.unwrap() >= 100.0);
+        let result = calculator.analyze(&llm, None).unwrap();
+        assert!(result.feasible);
+        assert!(result.constraints["thermal_score"] < 50.0);
+
+        // A frontier-scale model exceeds a single node's limits
+        let result = calculator
+            .analyze(&WorkloadProfile::llm_training(70.0), None)
+            .unwrap();
+        assert!(!result.compute_feasible);
+        assert!(!result.feasible);
+
+        let render = WorkloadProfile::image_rendering(1000, (3840, 2160));
+        assert_eq!(render.workload_type, WorkloadType::Render);
+        assert!(calculator.analyze(&render, None).unwrap().feasible);
+
+        let eo = WorkloadProfile::earth_observation_inference(5000.0);
+        assert_eq!(eo.workload_type, WorkloadType::Inference);
+        assert!(eo.data_transfer_gb.unwrap() < eo.storage_gb.unwrap());
+        assert!(calculator.analyze(&eo, None).unwrap().feasible);
+    }
+
+    #[test]
+    fn test_compare_matrix_and_best_altitude() {
+        let calculator = FeasibilityCalculator::default_altitude();
+        let latency_sensitive =
+            WorkloadProfile::new(WorkloadType::Inference, 10.0).with_latency_requirement_ms(30.0);
+        let thermal_heavy = WorkloadProfile::new(WorkloadType::Training, 60.0);
+        let altitudes = [400.0, 1000.0, 2000.0];
+
+        let matrix = calculator
+            .compare_matrix(
+                &[latency_sensitive.clone(), thermal_heavy.clone()],
+                &altitudes,
+            )
+            .unwrap();
+        assert_eq!(matrix.len(), 2);
+        assert!(matrix.iter().all(|row| row.len() == altitudes.len()));
+
+        // Latency margin shrinks with altitude
+        assert!(matrix[0][0].score > matrix[0][2].score);
+        assert!(calculator.best_altitude(&latency_sensitive).unwrap() <= 1000.0);
+
+        // Thermal limits don't depend on altitude
+        assert!(matrix[1].iter().all(|s| s.score == matrix[1][0].score));
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let calculator = FeasibilityCalculator::new(550.0);
+        let result = calculator
+            .analyze(&WorkloadProfile::llm_training(70.0), None)
+            .unwrap();
+        assert!(!result.feasible);
+
+        let report = result.to_markdown();
+        assert!(report.contains("Not feasible"));
+        assert!(report.contains("| Compute | **Fail** |"));
+        for recommendation in &result.recommendations {
+            assert!(report.contains(recommendation.as_str()));
+        }
+        assert!(report.contains("partitioning workload"));
+
+        let scenarios = calculator
+            .compare_scenarios(
+                &WorkloadProfile::new(WorkloadType::Inference, 10.0)
+                    .with_latency_requirement_ms(30.0),
+                &[400.0, 2000.0],
+            )
+            .unwrap();
+        let table = ScenarioResult::to_markdown_table(&scenarios);
+        assert_eq!(table.lines().count(), 4);
+        assert!(table.lines().nth(2).unwrap().contains("**"));
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let calculator = FeasibilityCalculator::new(550.0);
+
+        let negative = WorkloadProfile::new(WorkloadType::Training, -5.0);
+        assert_eq!(negative.validate().unwrap_err().field, "compute_tflops");
+        assert!(calculator.analyze(&negative, None).is_err());
+
+        let nan_latency = WorkloadProfile::new(WorkloadType::Inference, 10.0)
+            .with_latency_requirement_ms(f64::NAN);
+        assert!(calculator.analyze(&nan_latency, None).is_err());
+        let negative_memory =
+            WorkloadProfile::new(WorkloadType::Inference, 10.0).with_memory_gb(-1.0);
+        assert!(calculator.analyze(&negative_memory, None).is_err());
+
+        let profile = WorkloadProfile::new(WorkloadType::Batch, 10.0);
+        let error = calculator.analyze(&profile, Some(50.0)).unwrap_err();
+        assert_eq!(error.field, "orbit_altitude_km");
+        assert!(FeasibilityCalculator::new(0.0)
+            .analyze(&profile, None)
+            .is_err());
+        assert!(calculator
+            .compare_scenarios(&profile, &[550.0, 50.0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_availability_requires_redundancy() {
+        let profile =
+            WorkloadProfile::new(WorkloadType::Batch, 10.0).with_availability_requirement(99.99);
+
+        let single = FeasibilityCalculator::new(550.0)
+            .analyze(&profile, None)
+            .unwrap();
+        assert!(!single.availability_feasible);
+        assert!(!single.feasible);
+        assert!(single.constraints["achievable_availability_pct"] < 50.0);
+        assert!(single
+            .recommendations
+            .iter()
+            .any(|r| r.contains("redundant nodes")));
+
+        let constellation = FeasibilityCalculator::new(550.0)
+            .with_redundant_nodes(100)
+            .analyze(&profile, None)
+            .unwrap();
+        assert!(constellation.availability_feasible);
+        assert!(constellation.feasible);
+        assert!(constellation.constraints["achievable_availability_pct"] >= 99.99);
+
+        // Without a requirement the check is skipped and the score unchanged
+        let unconstrained = FeasibilityCalculator::new(550.0)
+            .analyze(&WorkloadProfile::new(WorkloadType::Batch, 10.0), None)
+            .unwrap();
+        assert!(unconstrained.availability_feasible);
+        assert!(!unconstrained.constraints.contains_key("availability_score"));
+
+        // Node counts past i32 saturate instead of wrapping negative
+        let huge = FeasibilityCalculator::new(550.0).with_redundant_nodes(u32::MAX);
+        assert_eq!(huge.achievable_availability(550.0), 100.0);
+
+        // A node that is never in contact cannot be made up for by numbers
+        let calculator = FeasibilityCalculator::new(550.0);
+        assert_eq!(calculator.nodes_for_availability(99.99, 0.0), None);
+        let nodes = calculator.nodes_for_availability(99.99, 550.0).unwrap();
+        let enough = FeasibilityCalculator::new(550.0).with_redundant_nodes(nodes);
+        assert!(enough.achievable_availability(550.0) >= 99.99);
+        let fewer = FeasibilityCalculator::new(550.0).with_redundant_nodes(nodes - 1);
+        assert!(fewer.achievable_availability(550.0) < 99.99);
+    }
 }
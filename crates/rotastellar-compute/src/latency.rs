@@ -2,10 +2,15 @@
 //!
 //! Model end-to-end latency for space-based data processing.
 
+use rotastellar::constants::EARTH_RADIUS_MEAN_KM;
 use serde::{Deserialize, Serialize};
 
+use crate::precision::Precision;
+
 /// Speed of light in km/s
 const SPEED_OF_LIGHT_KM_S: f64 = 299792.458;
+/// Smallest latitude-band fraction used for near-equatorial constellations
+const MIN_BAND_FRACTION: f64 = 0.05;
 
 /// Type of communication link.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -74,6 +79,9 @@ impl LatencyComponent {
 }
 
 /// Result of latency simulation.
+///
+/// Latencies are reported to 0.1 ms and the terrestrial ratio to two
+/// decimals, unless the simulator's [`Precision`] is `Full`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LatencyResult {
     /// Total end-to-end latency in ms
@@ -129,6 +137,7 @@ pub struct LatencySimulator {
     orbit_altitude_km: f64,
     processing_latency_ms: f64,
     ground_network_latency_ms: f64,
+    precision: Precision,
 }
 
 impl LatencySimulator {
@@ -142,6 +151,7 @@ impl LatencySimulator {
             orbit_altitude_km,
             processing_latency_ms: 5.0,
             ground_network_latency_ms: 10.0,
+            precision: Precision::Rounded,
         }
     }
 
@@ -162,6 +172,12 @@ impl LatencySimulator {
         self
     }
 
+    /// Set how reported latencies are rounded (see [`Precision`]).
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
     /// Simulate end-to-end latency.
     ///
     /// # Arguments
@@ -234,8 +250,8 @@ impl LatencySimulator {
         let terrestrial_latency = self.estimate_terrestrial_latency();
         let terrestrial_comparison = TerrestrialComparison {
             terrestrial_latency_ms: terrestrial_latency,
-            difference_ms: (total_latency - terrestrial_latency * 10.0).round() / 10.0,
-            ratio: (total_latency / terrestrial_latency * 100.0).round() / 100.0,
+            difference_ms: self.precision.apply(total_latency - terrestrial_latency, 1),
+            ratio: self.precision.apply(total_latency / terrestrial_latency, 2),
             competitive: total_latency < terrestrial_latency * 2.0,
         };
 
@@ -245,14 +261,14 @@ impl LatencySimulator {
             .unwrap_or(true);
 
         LatencyResult {
-            total_latency_ms: (total_latency * 10.0).round() / 10.0,
-            propagation_latency_ms: (propagation_latency * 10.0).round() / 10.0,
+            total_latency_ms: self.precision.apply(total_latency, 1),
+            propagation_latency_ms: self.precision.apply(propagation_latency, 1),
             processing_latency_ms: self.processing_latency_ms,
             queueing_latency_ms: queueing_latency,
             transmission_latency_ms: transmission_latency,
             components,
-            one_way_latency_ms: (one_way_latency * 10.0).round() / 10.0,
-            round_trip_latency_ms: (round_trip_latency * 10.0).round() / 10.0,
+            one_way_latency_ms: self.precision.apply(one_way_latency, 1),
+            round_trip_latency_ms: self.precision.apply(round_trip_latency, 1),
             terrestrial_comparison,
             meets_requirement,
             requirement_ms: latency_requirement_ms,
@@ -267,6 +283,48 @@ impl LatencySimulator {
         uplink + downlink
     }
 
+    /// Calculate minimum latency for a constellation of satellites.
+    ///
+    /// Treats the satellites as spread uniformly over the latitude band their
+    /// inclination covers and uses the median distance to the nearest one. A
+    /// dense constellation usually has a satellite close to zenith, so the floor
+    /// drops well below the single-satellite estimate of [`min_latency_ms`](Self::min_latency_ms).
+    ///
+    /// # Arguments
+    ///
+    /// * `num_satellites` - Number of satellites in the constellation
+    /// * `inclination_deg` - Orbital inclination in degrees
+    pub fn min_latency_for_constellation(&self, num_satellites: u32, inclination_deg: f64) -> f64 {
+        let single = self.min_latency_ms();
+        if num_satellites == 0 {
+            return single;
+        }
+
+        let r = EARTH_RADIUS_MEAN_KM + self.orbit_altitude_km;
+
+        // Satellites per steradian within the covered latitude band
+        let band_fraction = inclination_deg
+            .to_radians()
+            .sin()
+            .abs()
+            .max(MIN_BAND_FRACTION);
+        let density = num_satellites as f64 / (4.0 * std::f64::consts::PI * band_fraction);
+
+        // Median central angle to the nearest satellite (Poisson: P(none in cap) = 1/2)
+        let cos_theta = 1.0 - std::f64::consts::LN_2 / (2.0 * std::f64::consts::PI * density);
+        if cos_theta <= EARTH_RADIUS_MEAN_KM / r {
+            // Nearest satellite is typically below the horizon
+            return single;
+        }
+
+        let slant_range = (EARTH_RADIUS_MEAN_KM.powi(2) + r.powi(2)
+            - 2.0 * EARTH_RADIUS_MEAN_KM * r * cos_theta)
+            .sqrt();
+        let round_trip = 2.0 * (slant_range / SPEED_OF_LIGHT_KM_S) * 1000.0;
+
+        self.precision.apply(round_trip.min(single), 2)
+    }
+
     /// Calculate latency for a specific elevation angle.
     pub fn latency_at_elevation(&self, elevation_deg: f64) -> ElevationLatency {
         let slant_range = self.slant_range_km(elevation_deg);
@@ -285,11 +343,11 @@ impl LatencySimulator {
 
         ElevationLatency {
             elevation_deg,
-            slant_range_km: (slant_range * 10.0).round() / 10.0,
-            propagation_ms: (propagation_ms * 100.0).round() / 100.0,
+            slant_range_km: self.precision.apply(slant_range, 1),
+            propagation_ms: self.precision.apply(propagation_ms, 2),
             atmospheric_delay_ms: atmospheric_delay,
-            total_one_way_ms: (total_one_way * 100.0).round() / 100.0,
-            total_round_trip_ms: (total_one_way * 2.0 * 100.0).round() / 100.0,
+            total_one_way_ms: self.precision.apply(total_one_way, 2),
+            total_round_trip_ms: self.precision.apply(total_one_way * 2.0, 2),
         }
     }
 
@@ -321,14 +379,13 @@ impl LatencySimulator {
     }
 
     fn slant_range_km(&self, elevation_deg: f64) -> f64 {
-        let earth_radius = 6371.0;
         let elevation_rad = elevation_deg.to_radians();
-        let r = earth_radius + self.orbit_altitude_km;
+        let r = EARTH_RADIUS_MEAN_KM + self.orbit_altitude_km;
 
         // Slant range formula
         let sin_el = elevation_rad.sin();
-        let term1 = (r / earth_radius).powi(2) - (elevation_rad.cos()).powi(2);
-        earth_radius * (term1.sqrt() - sin_el)
+        let term1 = (r / EARTH_RADIUS_MEAN_KM).powi(2) - (elevation_rad.cos()).powi(2);
+        EARTH_RADIUS_MEAN_KM * (term1.sqrt() - sin_el)
     }
 
     fn isl_propagation_delay_ms(&self) -> f64 {
@@ -444,6 +501,18 @@ mod tests {
 
         assert!(result.terrestrial_comparison.terrestrial_latency_ms > 0.0);
         assert!(result.terrestrial_comparison.ratio > 0.0);
+
+        // Difference is total minus terrestrial, not total minus 10x terrestrial
+        let comparison = &result.terrestrial_comparison;
+        let expected = result.total_latency_ms - comparison.terrestrial_latency_ms;
+        assert!((comparison.difference_ms - expected).abs() < 0.11);
+
+        let full = simulator
+            .with_precision(Precision::Full)
+            .simulate(None, None);
+        let comparison = &full.terrestrial_comparison;
+        let expected = full.total_latency_ms - comparison.terrestrial_latency_ms;
+        assert!((comparison.difference_ms - expected).abs() < 1e-9);
     }
 
     #[test]
@@ -455,4 +524,17 @@ mod tests {
         assert!(min > 3.0);
         assert!(min < 20.0);
     }
+
+    #[test]
+    fn test_min_latency_for_constellation() {
+        let simulator = LatencySimulator::new(550.0);
+        let single = simulator.min_latency_ms();
+
+        let dense = simulator.min_latency_for_constellation(4000, 53.0);
+        assert!(dense < single);
+        // Can't beat a satellite directly overhead
+        assert!(dense >= 2.0 * 550.0 / SPEED_OF_LIGHT_KM_S * 1000.0);
+
+        assert_eq!(simulator.min_latency_for_constellation(1, 53.0), single);
+    }
 }
@@ -30,7 +30,7 @@
 //! let calc = FeasibilityCalculator::new(550.0);
 //! let profile = WorkloadProfile::new(WorkloadType::Inference, 10.0)
 //!     .with_memory_gb(32.0);
-//! let feasibility = calc.analyze(&profile, None);
+//! let feasibility = calc.analyze(&profile, None).unwrap();
 //! println!("Feasible: {}, Rating: {}", feasibility.feasible, feasibility.rating);
 //!
 //! // Thermal simulation
@@ -54,10 +54,12 @@
 //!
 //! ## Modules
 //!
+//! - [`consistency`] — Power/thermal cross-checks
 //! - [`feasibility`] — Workload feasibility analysis
 //! - [`thermal`] — Thermal simulation for orbital systems
 //! - [`latency`] — Latency modeling for space-ground communication
 //! - [`power`] — Power system analysis and sizing
+//! - [`precision`] — Rounding policy for analyzer results
 //!
 //! ## Links
 //!
@@ -69,9 +71,11 @@
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
+pub mod consistency;
 pub mod feasibility;
 pub mod latency;
 pub mod power;
+pub mod precision;
 pub mod thermal;
 
 // Re-export commonly used items at crate root
@@ -81,8 +85,8 @@ pub use feasibility::{
 };
 
 pub use thermal::{
-    OrbitType, RadiatorSizing, ThermalConfig, ThermalEnvironment, ThermalResult, ThermalSimulator,
-    ThermalTimePoint,
+    OrbitThermalSummary, OrbitType, RadiatorSizing, ThermalConfig, ThermalEnvironment,
+    ThermalResult, ThermalSimulator, ThermalTimePoint, TwoNodeThermalResult,
 };
 
 pub use latency::{
@@ -95,6 +99,8 @@ pub use power::{
     SolarCellType, SolarConfig, SolarPanelSizing,
 };
 
+pub use precision::Precision;
+
 /// Current version of the crate.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -114,7 +120,7 @@ mod tests {
             .with_memory_gb(32.0)
             .with_latency_requirement_ms(100.0);
 
-        let result = calc.analyze(&profile, None);
+        let result = calc.analyze(&profile, None).unwrap();
 
         assert!(result.feasible);
         assert!(matches!(
@@ -169,11 +175,9 @@ mod tests {
         let thermal_env = ThermalEnvironment::leo(550.0);
         let thermal_result = thermal_sim.simulate(&thermal_config, &thermal_env);
 
-        // Both should be analyzing similar orbital parameters
-        assert!(
-            (power_result.eclipse_duration_min - thermal_result.equilibrium_temp_k).abs()
-                < thermal_result.equilibrium_temp_k
-        );
+        // Both should be analyzing the same orbit and load
+        let discrepancies = consistency::check(&power_result, &thermal_result);
+        assert!(discrepancies.is_empty(), "{:?}", discrepancies);
     }
 
     #[test]
@@ -185,7 +189,9 @@ mod tests {
 
         let profile = WorkloadProfile::new(WorkloadType::Batch, 20.0);
 
-        let feasibility_results = feasibility_calc.compare_scenarios(&profile, &altitudes);
+        let feasibility_results = feasibility_calc
+            .compare_scenarios(&profile, &altitudes)
+            .unwrap();
         let latency_results = latency_sim.compare_altitudes(&altitudes);
 
         assert_eq!(feasibility_results.len(), altitudes.len());
@@ -2,8 +2,12 @@
 //!
 //! Solar panel and battery sizing for orbital compute systems.
 
+use rotastellar::constants::{EARTH_MU, EARTH_RADIUS_MEAN_KM};
+use rotastellar::ValidationError;
 use serde::{Deserialize, Serialize};
 
+use crate::precision::Precision;
+
 /// Solar constant at 1 AU (W/m²)
 const SOLAR_CONSTANT: f64 = 1361.0;
 
@@ -237,6 +241,9 @@ impl BatteryConfig {
 }
 
 /// Complete power budget analysis.
+///
+/// Powers and durations are reported to one decimal, masses to two and areas
+/// and fractions to three, unless the analyzer's [`Precision`] is `Full`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PowerBudget {
     /// Required power with margin in watts
@@ -253,6 +260,10 @@ pub struct PowerBudget {
     pub solar_panel_mass_kg: f64,
     /// Eclipse duration in minutes
     pub eclipse_duration_min: f64,
+    /// Fraction of the orbit spent in eclipse (0.0-1.0)
+    pub eclipse_fraction: f64,
+    /// Average power load before design margin in watts
+    pub average_power_w: f64,
     /// Whether power margin is positive
     pub positive_margin: bool,
     /// Power margin percentage
@@ -276,17 +287,50 @@ pub struct PowerBudget {
 /// ```
 pub struct PowerAnalyzer {
     orbit_altitude_km: f64,
+    solar_specific_power_w_kg: f64,
+    precision: Precision,
 }
 
 impl PowerAnalyzer {
-    /// Solar panel specific power (W/kg)
+    /// Default solar panel specific power (W/kg)
     pub const SOLAR_PANEL_SPECIFIC_POWER: f64 = 100.0;
     /// Design margin (20%)
     pub const DESIGN_MARGIN: f64 = 0.2;
 
     /// Create a new power analyzer.
     pub fn new(orbit_altitude_km: f64) -> Self {
-        Self { orbit_altitude_km }
+        Self {
+            orbit_altitude_km,
+            solar_specific_power_w_kg: Self::SOLAR_PANEL_SPECIFIC_POWER,
+            precision: Precision::Rounded,
+        }
+    }
+
+    /// Set the solar array specific power used for mass estimates.
+    ///
+    /// Defaults to [`SOLAR_PANEL_SPECIFIC_POWER`](Self::SOLAR_PANEL_SPECIFIC_POWER)
+    /// (100 W/kg, rigid triple-junction panels); modern flexible arrays reach
+    /// 150 W/kg or more. Battery mass follows the battery's own
+    /// `specific_energy_wh_kg`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `w_per_kg` is not a positive number.
+    pub fn with_solar_specific_power(mut self, w_per_kg: f64) -> Result<Self, ValidationError> {
+        if !(w_per_kg.is_finite() && w_per_kg > 0.0) {
+            return Err(ValidationError::new(
+                "solar_specific_power_w_kg",
+                "Must be a positive number",
+            ));
+        }
+        self.solar_specific_power_w_kg = w_per_kg;
+        Ok(self)
+    }
+
+    /// Set how budget figures are rounded (see [`Precision`]).
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
     }
 
     /// Create an analyzer with default altitude (550 km).
@@ -347,7 +391,7 @@ impl PowerAnalyzer {
 
         // Mass estimates
         let battery_mass = battery_capacity / battery.specific_energy_wh_kg;
-        let solar_mass = solar_power / Self::SOLAR_PANEL_SPECIFIC_POWER;
+        let solar_mass = solar_power / self.solar_specific_power_w_kg;
 
         // Check margin
         let available_power = solar_power * (sunlight_duration / orbital_period_min);
@@ -370,15 +414,17 @@ impl PowerAnalyzer {
         }
 
         PowerBudget {
-            power_required_w: (power_required * 10.0).round() / 10.0,
-            solar_power_generated_w: (solar_power * 10.0).round() / 10.0,
-            battery_capacity_wh: (battery_capacity * 10.0).round() / 10.0,
-            solar_panel_area_m2: (panel_area * 1000.0).round() / 1000.0,
-            battery_mass_kg: (battery_mass * 100.0).round() / 100.0,
-            solar_panel_mass_kg: (solar_mass * 100.0).round() / 100.0,
-            eclipse_duration_min: (eclipse_duration * 10.0).round() / 10.0,
+            power_required_w: self.precision.apply(power_required, 1),
+            solar_power_generated_w: self.precision.apply(solar_power, 1),
+            battery_capacity_wh: self.precision.apply(battery_capacity, 1),
+            solar_panel_area_m2: self.precision.apply(panel_area, 3),
+            battery_mass_kg: self.precision.apply(battery_mass, 2),
+            solar_panel_mass_kg: self.precision.apply(solar_mass, 2),
+            eclipse_duration_min: self.precision.apply(eclipse_duration, 1),
+            eclipse_fraction: self.precision.apply(eclipse_fraction, 3),
+            average_power_w: profile.average_power_w,
             positive_margin,
-            margin_percent: (margin_percent * 10.0).round() / 10.0,
+            margin_percent: self.precision.apply(margin_percent, 1),
             warnings,
         }
     }
@@ -407,12 +453,14 @@ impl PowerAnalyzer {
         let panel_area = required_solar / (SOLAR_CONSTANT * eol_efficiency * cosine_factor);
 
         SolarPanelSizing {
-            panel_area_m2: (panel_area * 1000.0).round() / 1000.0,
+            panel_area_m2: self.precision.apply(panel_area, 3),
             cell_type: cell,
             bol_efficiency: efficiency,
-            eol_efficiency: (eol_efficiency * 1000.0).round() / 1000.0,
-            solar_power_w: (required_solar * 10.0).round() / 10.0,
-            mass_estimate_kg: ((required_solar / Self::SOLAR_PANEL_SPECIFIC_POWER) * 100.0).round() / 100.0,
+            eol_efficiency: self.precision.apply(eol_efficiency, 3),
+            solar_power_w: self.precision.apply(required_solar, 1),
+            mass_estimate_kg: self
+                .precision
+                .apply(required_solar / self.solar_specific_power_w_kg, 2),
         }
     }
 
@@ -440,29 +488,26 @@ impl PowerAnalyzer {
         let cycles_per_year = orbits_per_day * 365.0;
 
         BatterySizing {
-            capacity_wh: (capacity * 10.0).round() / 10.0,
+            capacity_wh: self.precision.apply(capacity, 1),
             chemistry: chem,
-            mass_kg: (mass * 100.0).round() / 100.0,
-            eclipse_duration_min: (eclipse_min * 10.0).round() / 10.0,
+            mass_kg: self.precision.apply(mass, 2),
+            eclipse_duration_min: self.precision.apply(eclipse_min, 1),
             depth_of_discharge: chars.depth_of_discharge,
             cycles_per_year: cycles_per_year.round() as u32,
-            expected_life_years: ((chars.cycle_life as f64 / cycles_per_year) * 10.0).round() / 10.0,
+            expected_life_years: self
+                .precision
+                .apply(chars.cycle_life as f64 / cycles_per_year, 1),
         }
     }
 
     fn orbital_period(&self, altitude_km: f64) -> f64 {
-        let earth_radius = 6371.0;
-        let earth_mu = 398600.4418;
-        let a = earth_radius + altitude_km;
-        let period_s = 2.0 * std::f64::consts::PI * (a.powi(3) / earth_mu).sqrt();
+        let a = EARTH_RADIUS_MEAN_KM + altitude_km;
+        let period_s = 2.0 * std::f64::consts::PI * (a.powi(3) / EARTH_MU).sqrt();
         period_s / 60.0
     }
 
     fn eclipse_fraction(&self, altitude_km: f64) -> f64 {
-        let earth_radius = 6371.0;
-        let r = earth_radius + altitude_km;
-        let sin_rho = earth_radius / r;
-        sin_rho.asin() / std::f64::consts::PI
+        crate::thermal::eclipse_fraction(altitude_km)
     }
 }
 
@@ -548,6 +593,35 @@ mod tests {
         assert!(sizing.mass_estimate_kg > 0.0);
     }
 
+    #[test]
+    fn test_custom_solar_specific_power() {
+        let profile = PowerProfile::new(500.0);
+        let legacy = PowerAnalyzer::new(550.0);
+        let modern = PowerAnalyzer::new(550.0)
+            .with_solar_specific_power(150.0)
+            .unwrap();
+
+        let legacy_budget = legacy.analyze(&profile, None, None, None, None);
+        let modern_budget = modern.analyze(&profile, None, None, None, None);
+        assert_eq!(
+            modern_budget.solar_power_generated_w,
+            legacy_budget.solar_power_generated_w
+        );
+        assert!(modern_budget.solar_panel_mass_kg < legacy_budget.solar_panel_mass_kg);
+
+        let legacy_sizing = legacy.size_solar_panels(500.0, None, None, None);
+        let modern_sizing = modern.size_solar_panels(500.0, None, None, None);
+        assert!(
+            (modern_sizing.mass_estimate_kg / legacy_sizing.mass_estimate_kg - 2.0 / 3.0).abs()
+                < 0.01
+        );
+
+        for specific_power in [0.0, -150.0, f64::INFINITY] {
+            let rejected = PowerAnalyzer::new(550.0).with_solar_specific_power(specific_power);
+            assert!(rejected.is_err());
+        }
+    }
+
     #[test]
     fn test_size_battery() {
         let analyzer = PowerAnalyzer::new(550.0);
@@ -609,4 +683,64 @@ mod tests {
         assert!(budget.battery_capacity_wh > 1000.0);
         assert!(budget.warnings.iter().any(|w| w.contains("battery")));
     }
+
+    #[test]
+    fn test_eclipse_fraction_matches_tracker() {
+        use chrono::{Duration, TimeZone, Utc};
+        use rotastellar_intel::{Tracker, TLE};
+
+        // Equatorial orbit at the March equinox, so the Sun lies in the
+        // orbit plane (beta angle of zero)
+        let altitude = 550.0;
+        let epoch = Utc.with_ymd_and_hms(2024, 3, 20, 3, 6, 0).unwrap();
+        let tle = TLE::builder()
+            .norad_id(90001)
+            .epoch(epoch)
+            .semi_major_axis_km(EARTH_RADIUS_MEAN_KM + altitude)
+            .inclination_deg(0.0)
+            .build()
+            .unwrap();
+        let mut tracker = Tracker::new();
+        tracker.add_tle("EQ", tle);
+
+        // Whole eclipses only: the first and last may be clipped by the window
+        let intervals = tracker
+            .eclipse_intervals("EQ", epoch, epoch + Duration::hours(12))
+            .unwrap();
+        let whole = &intervals[1..intervals.len() - 1];
+        let mean_eclipse_hours =
+            whole.iter().map(|w| w.duration_hours()).sum::<f64>() / whole.len() as f64;
+        let period_hours = (whole[whole.len() - 1].start - whole[0].start).num_milliseconds()
+            as f64
+            / 3_600_000.0
+            / (whole.len() - 1) as f64;
+
+        // The tracker's shadow cylinder has the equatorial radius, which
+        // lengthens the eclipse by about 0.001 of an orbit
+        let measured = mean_eclipse_hours / period_hours;
+        let modeled = PowerAnalyzer::new(altitude).eclipse_fraction(altitude);
+        assert!(
+            (measured - modeled).abs() < 0.005,
+            "{} vs {}",
+            measured,
+            modeled
+        );
+    }
+
+    #[test]
+    fn test_eclipse_fraction_matches_thermal() {
+        use crate::thermal::ThermalEnvironment;
+
+        for altitude in [400.0, 550.0, 1200.0] {
+            let analyzer = PowerAnalyzer::new(altitude);
+            assert_eq!(
+                analyzer.eclipse_fraction(altitude),
+                ThermalEnvironment::leo(altitude).eclipse_fraction
+            );
+            assert_eq!(
+                analyzer.eclipse_fraction(altitude),
+                ThermalEnvironment::sun_synchronous(altitude).eclipse_fraction
+            );
+        }
+    }
 }
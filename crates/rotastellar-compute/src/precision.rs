@@ -0,0 +1,47 @@
+//! Result Precision
+//!
+//! Analyzer results are rounded to a few decimals for presentation. Callers
+//! feeding results into further calculations should request full precision so
+//! rounding error doesn't accumulate across chained steps.
+
+use serde::{Deserialize, Serialize};
+
+/// How analyzers round the numeric fields of their results.
+///
+/// Every analyzer defaults to [`Rounded`](Self::Rounded) and takes a policy
+/// through its `with_precision` builder. Use [`Full`](Self::Full) when
+/// results feed further calculations, so rounding error doesn't accumulate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Precision {
+    /// Round each field to the decimals documented on it (for display)
+    #[default]
+    Rounded,
+    /// Return unrounded values
+    Full,
+}
+
+impl Precision {
+    /// Apply this policy to a value, rounding to `decimals` places if rounded.
+    pub fn apply(self, value: f64, decimals: i32) -> f64 {
+        match self {
+            Self::Rounded => {
+                let scale = 10f64.powi(decimals);
+                (value * scale).round() / scale
+            }
+            Self::Full => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(Precision::Rounded.apply(293.14159, 1), 293.1);
+        assert_eq!(Precision::Rounded.apply(0.12345, 3), 0.123);
+        assert_eq!(Precision::Full.apply(293.14159, 1), 293.14159);
+    }
+}
@@ -10,8 +10,12 @@
 //!
 //! The model accounts for: solar input, Earth albedo, Earth IR, and eclipse cycling.
 
+use rotastellar::constants::{EARTH_MU, EARTH_RADIUS_MEAN_KM};
+use rotastellar::ValidationError;
 use serde::{Deserialize, Serialize};
 
+use crate::precision::Precision;
+
 // TODO(subhadipmitra): Add transient analysis for eclipse thermal cycling
 // TODO: Model deployable radiators for high-power systems
 
@@ -24,6 +28,12 @@ const SOLAR_CONSTANT: f64 = 1361.0;
 const EARTH_IR: f64 = 237.0;
 /// Earth albedo factor - fraction of solar radiation reflected by Earth
 const EARTH_ALBEDO: f64 = 0.3;
+/// Time step for converged orbit summaries (s)
+const SUMMARY_TIME_STEP_S: f64 = 30.0;
+/// Maximum orbits simulated while waiting for the cycle to settle
+const MAX_CONVERGENCE_ORBITS: usize = 50;
+/// Orbit-to-orbit change in min/max below which the cycle is converged (K)
+const CONVERGENCE_TOLERANCE_K: f64 = 0.1;
 
 /// Orbit type for thermal analysis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -74,13 +84,18 @@ impl Default for ThermalEnvironment {
     }
 }
 
+/// Fraction of a circular orbit spent in Earth's cylindrical shadow,
+/// for the worst-case beta angle of zero.
+pub(crate) fn eclipse_fraction(altitude_km: f64) -> f64 {
+    let r = EARTH_RADIUS_MEAN_KM + altitude_km;
+    let sin_rho = EARTH_RADIUS_MEAN_KM / r;
+    sin_rho.asin() / std::f64::consts::PI
+}
+
 impl ThermalEnvironment {
     /// Create a new thermal environment for LEO.
     pub fn leo(altitude_km: f64) -> Self {
-        let earth_radius = 6371.0;
-        let r = earth_radius + altitude_km;
-        let sin_rho = earth_radius / r;
-        let eclipse_fraction = sin_rho.asin() / std::f64::consts::PI;
+        let eclipse_fraction = eclipse_fraction(altitude_km);
 
         Self {
             orbit_type: OrbitType::Leo,
@@ -102,10 +117,7 @@ impl ThermalEnvironment {
 
     /// Create a Sun-Synchronous orbit environment.
     pub fn sun_synchronous(altitude_km: f64) -> Self {
-        let earth_radius = 6371.0;
-        let r = earth_radius + altitude_km;
-        let sin_rho = earth_radius / r;
-        let eclipse_fraction = sin_rho.asin() / std::f64::consts::PI;
+        let eclipse_fraction = eclipse_fraction(altitude_km);
 
         Self {
             orbit_type: OrbitType::Sso,
@@ -172,6 +184,9 @@ impl ThermalConfig {
 }
 
 /// Result of thermal simulation.
+///
+/// Temperatures and heat flows are reported to one decimal and areas and
+/// fractions to three, unless the simulator's [`Precision`] is `Full`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThermalResult {
     /// Equilibrium temperature in Kelvin
@@ -192,6 +207,10 @@ pub struct ThermalResult {
     pub radiated_w: f64,
     /// Required radiator area for thermal balance (m²)
     pub required_radiator_area_m2: f64,
+    /// Internal heat dissipation in watts
+    pub heat_dissipation_w: f64,
+    /// Eclipse fraction used for the simulation (0.0-1.0)
+    pub eclipse_fraction: f64,
     /// Whether the temperature stays within operating limits
     pub within_limits: bool,
     /// Margin to max temperature in Kelvin
@@ -204,6 +223,10 @@ pub struct ThermalResult {
 
 /// Simulate thermal conditions in orbital environments.
 ///
+/// Carries the radiator areal density used for mass estimates and the
+/// rounding applied to results; [`ThermalSimulator::new`] and [`Default`]
+/// use the documented defaults.
+///
 /// # Example
 ///
 /// ```rust
@@ -217,12 +240,47 @@ pub struct ThermalResult {
 /// println!("Equilibrium temperature: {:.1}°C", result.equilibrium_temp_c);
 /// println!("Within limits: {}", result.within_limits);
 /// ```
-pub struct ThermalSimulator;
+pub struct ThermalSimulator {
+    radiator_areal_density_kg_m2: f64,
+    precision: Precision,
+}
 
 impl ThermalSimulator {
+    /// Default radiator areal density (kg/m²), mid-range for deployable panels
+    pub const RADIATOR_AREAL_DENSITY_KG_M2: f64 = 7.5;
+
     /// Create a new thermal simulator.
     pub fn new() -> Self {
-        Self
+        Self {
+            radiator_areal_density_kg_m2: Self::RADIATOR_AREAL_DENSITY_KG_M2,
+            precision: Precision::Rounded,
+        }
+    }
+
+    /// Set the radiator areal density used for mass estimates.
+    ///
+    /// Defaults to [`RADIATOR_AREAL_DENSITY_KG_M2`](Self::RADIATOR_AREAL_DENSITY_KG_M2)
+    /// (7.5 kg/m², typical deployable radiators span 5-10); lightweight
+    /// composite panels reach about 4 kg/m².
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `kg_per_m2` is not a positive number.
+    pub fn with_radiator_areal_density(mut self, kg_per_m2: f64) -> Result<Self, ValidationError> {
+        if !(kg_per_m2.is_finite() && kg_per_m2 > 0.0) {
+            return Err(ValidationError::new(
+                "radiator_areal_density_kg_m2",
+                "Must be a positive number",
+            ));
+        }
+        self.radiator_areal_density_kg_m2 = kg_per_m2;
+        Ok(self)
+    }
+
+    /// Set how temperatures, heat flows and areas are rounded (see [`Precision`]).
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
     }
 
     /// Simulate thermal conditions.
@@ -313,18 +371,78 @@ impl ThermalSimulator {
         }
 
         ThermalResult {
-            equilibrium_temp_k: (equilibrium_temp_k * 10.0).round() / 10.0,
-            equilibrium_temp_c: ((equilibrium_temp_k - 273.15) * 10.0).round() / 10.0,
-            hot_case_k: (hot_case_k * 10.0).round() / 10.0,
-            cold_case_k: (cold_case_k * 10.0).round() / 10.0,
-            solar_absorbed_w: (solar_absorbed * 10.0).round() / 10.0,
-            earth_ir_absorbed_w: (earth_ir_absorbed * 10.0).round() / 10.0,
-            albedo_absorbed_w: (albedo_absorbed * 10.0).round() / 10.0,
-            radiated_w: (radiated_w * 10.0).round() / 10.0,
-            required_radiator_area_m2: (required_radiator_area * 1000.0).round() / 1000.0,
+            equilibrium_temp_k: self.precision.apply(equilibrium_temp_k, 1),
+            equilibrium_temp_c: self.precision.apply(equilibrium_temp_k - 273.15, 1),
+            hot_case_k: self.precision.apply(hot_case_k, 1),
+            cold_case_k: self.precision.apply(cold_case_k, 1),
+            solar_absorbed_w: self.precision.apply(solar_absorbed, 1),
+            earth_ir_absorbed_w: self.precision.apply(earth_ir_absorbed, 1),
+            albedo_absorbed_w: self.precision.apply(albedo_absorbed, 1),
+            radiated_w: self.precision.apply(radiated_w, 1),
+            required_radiator_area_m2: self.precision.apply(required_radiator_area, 3),
+            heat_dissipation_w: config.heat_dissipation_w,
+            eclipse_fraction: self.precision.apply(environment.eclipse_fraction, 3),
             within_limits,
-            hot_margin_k: (hot_margin * 10.0).round() / 10.0,
-            cold_margin_k: (cold_margin * 10.0).round() / 10.0,
+            hot_margin_k: self.precision.apply(hot_margin, 1),
+            cold_margin_k: self.precision.apply(cold_margin, 1),
+            warnings,
+        }
+    }
+
+    /// Simulate a two-node model: electronics junction coupled to the radiator.
+    ///
+    /// The radiator node balances the same heat loads as [`simulate`](Self::simulate).
+    /// All internal dissipation must cross the conductive path to reach it,
+    /// so the junction sits `Q / G` above the radiator at steady state.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Thermal configuration (limits apply to both nodes)
+    /// * `environment` - Orbital thermal environment
+    /// * `conductance_w_per_k` - Junction-to-radiator conductance (W/K)
+    pub fn simulate_two_node(
+        &self,
+        config: &ThermalConfig,
+        environment: &ThermalEnvironment,
+        conductance_w_per_k: f64,
+    ) -> TwoNodeThermalResult {
+        let radiator = self.simulate(config, environment);
+        let radiator_temp_k = radiator.equilibrium_temp_k;
+
+        // Q = G·(T_junction - T_radiator)
+        let gradient_k = if conductance_w_per_k > 0.0 {
+            config.heat_dissipation_w / conductance_w_per_k
+        } else {
+            f64::INFINITY
+        };
+        let junction_temp_k = radiator_temp_k + gradient_k;
+
+        let radiator_within_limits =
+            radiator_temp_k <= config.max_temp_k && radiator_temp_k >= config.min_temp_k;
+        let junction_within_limits =
+            junction_temp_k <= config.max_temp_k && junction_temp_k >= config.min_temp_k;
+
+        let mut warnings = Vec::new();
+        if junction_temp_k > config.max_temp_k {
+            warnings.push(format!(
+                "Junction exceeds maximum temperature by {:.1}K",
+                junction_temp_k - config.max_temp_k
+            ));
+            if radiator_within_limits {
+                warnings.push(
+                    "Radiator is within limits - improve the conductive path, not the radiator"
+                        .to_string(),
+                );
+            }
+        }
+
+        TwoNodeThermalResult {
+            junction_temp_k: self.precision.apply(junction_temp_k, 1),
+            radiator_temp_k,
+            gradient_k: self.precision.apply(gradient_k, 1),
+            conductance_w_per_k,
+            junction_within_limits,
+            radiator_within_limits,
             warnings,
         }
     }
@@ -337,15 +455,90 @@ impl ThermalSimulator {
         time_step_s: f64,
         duration_orbits: f64,
     ) -> Vec<ThermalTimePoint> {
+        // Start at equilibrium
+        let initial_result = self.simulate(config, environment);
+        self.run_transient(
+            config,
+            environment,
+            initial_result.equilibrium_temp_k,
+            time_step_s,
+            duration_orbits,
+        )
+        .0
+    }
+
+    /// Summarize the converged transient temperature cycle over one orbit.
+    ///
+    /// Repeats the transient run orbit by orbit until the min/max temperatures
+    /// settle, then reports the actual cycle instead of the analytic hot/cold
+    /// approximations from [`simulate`](Self::simulate).
+    ///
+    /// The analytic cases are steady states at worst-case inputs (120% of the
+    /// dissipation in full sun, 30% of it in eclipse), which the thermal mass
+    /// never reaches within one orbit. The cycle therefore lies inside them:
+    /// the analytic cold and hot cases bracket the summary's min and max, not
+    /// the other way round.
+    pub fn orbit_thermal_summary(
+        &self,
+        config: &ThermalConfig,
+        environment: &ThermalEnvironment,
+    ) -> OrbitThermalSummary {
+        let mut temp_k = self.simulate(config, environment).equilibrium_temp_k;
+        let mut previous: Option<(f64, f64)> = None;
+        let mut orbits = 0;
+        let mut cycle = Vec::new();
+
+        while orbits < MAX_CONVERGENCE_ORBITS {
+            let (points, final_temp_k) =
+                self.run_transient(config, environment, temp_k, SUMMARY_TIME_STEP_S, 1.0);
+            temp_k = final_temp_k;
+            orbits += 1;
+
+            let temps: Vec<f64> = points.iter().map(|p| p.temperature_k).collect();
+            let min = temps.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = temps.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            cycle = temps;
+
+            if let Some((prev_min, prev_max)) = previous {
+                if (min - prev_min).abs() < CONVERGENCE_TOLERANCE_K
+                    && (max - prev_max).abs() < CONVERGENCE_TOLERANCE_K
+                {
+                    break;
+                }
+            }
+            previous = Some((min, max));
+        }
+
+        let min_temp_k = cycle.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max_temp_k = cycle.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let mean_temp_k = cycle.iter().sum::<f64>() / cycle.len().max(1) as f64;
+
+        OrbitThermalSummary {
+            min_temp_k,
+            max_temp_k,
+            mean_temp_k: self.precision.apply(mean_temp_k, 1),
+            swing_k: self.precision.apply(max_temp_k - min_temp_k, 1),
+            orbits_simulated: orbits,
+            within_limits: max_temp_k <= config.max_temp_k && min_temp_k >= config.min_temp_k,
+        }
+    }
+
+    /// Integrate the lumped thermal model, returning the time series and final temperature.
+    fn run_transient(
+        &self,
+        config: &ThermalConfig,
+        environment: &ThermalEnvironment,
+        initial_temp_k: f64,
+        time_step_s: f64,
+        duration_orbits: f64,
+    ) -> (Vec<ThermalTimePoint>, f64) {
         let orbital_period_s = self.orbital_period_seconds(environment.altitude_km);
         let total_time_s = duration_orbits * orbital_period_s;
         let num_steps = (total_time_s / time_step_s) as usize;
 
         let earth_view_factor = self.calculate_earth_view_factor(environment.altitude_km);
 
-        // Start at equilibrium
-        let initial_result = self.simulate(config, environment);
-        let mut current_temp_k = initial_result.equilibrium_temp_k;
+        let mut current_temp_k = initial_temp_k;
 
         let mut results = Vec::with_capacity(num_steps);
 
@@ -385,15 +578,15 @@ impl ThermalSimulator {
 
             results.push(ThermalTimePoint {
                 time_s,
-                temperature_k: (current_temp_k * 10.0).round() / 10.0,
-                temperature_c: ((current_temp_k - 273.15) * 10.0).round() / 10.0,
+                temperature_k: self.precision.apply(current_temp_k, 1),
+                temperature_c: self.precision.apply(current_temp_k - 273.15, 1),
                 in_eclipse,
-                heat_in_w: (total_heat_in * 10.0).round() / 10.0,
-                heat_out_w: (heat_out * 10.0).round() / 10.0,
+                heat_in_w: self.precision.apply(total_heat_in, 1),
+                heat_out_w: self.precision.apply(heat_out, 1),
             });
         }
 
-        results
+        (results, current_temp_k)
     }
 
     /// Size radiator for a given power dissipation.
@@ -432,32 +625,28 @@ impl ThermalSimulator {
         // Add margin
         let recommended_area = required_area * 1.3;
 
-        // Mass estimate (typical deployable radiator: 5-10 kg/m²)
-        let mass_estimate = recommended_area * 7.5;
+        let mass_estimate = recommended_area * self.radiator_areal_density_kg_m2;
 
         RadiatorSizing {
-            required_area_m2: (required_area * 1000.0).round() / 1000.0,
-            recommended_area_m2: (recommended_area * 1000.0).round() / 1000.0,
+            required_area_m2: self.precision.apply(required_area, 3),
+            recommended_area_m2: self.precision.apply(recommended_area, 3),
             target_temp_c,
             heat_dissipation_w,
-            mass_estimate_kg: (mass_estimate * 100.0).round() / 100.0,
+            mass_estimate_kg: self.precision.apply(mass_estimate, 2),
             emissivity,
             feasible: required_area.is_finite() && required_area < 50.0,
         }
     }
 
     fn calculate_earth_view_factor(&self, altitude_km: f64) -> f64 {
-        let earth_radius = 6371.0;
-        let r = earth_radius + altitude_km;
-        let sin_rho = earth_radius / r;
+        let r = EARTH_RADIUS_MEAN_KM + altitude_km;
+        let sin_rho = EARTH_RADIUS_MEAN_KM / r;
         sin_rho.powi(2)
     }
 
     fn orbital_period_seconds(&self, altitude_km: f64) -> f64 {
-        let earth_radius = 6371.0;
-        let earth_mu = 398600.4418;
-        let a = earth_radius + altitude_km;
-        2.0 * std::f64::consts::PI * (a.powi(3) / earth_mu).sqrt()
+        let a = EARTH_RADIUS_MEAN_KM + altitude_km;
+        2.0 * std::f64::consts::PI * (a.powi(3) / EARTH_MU).sqrt()
     }
 }
 
@@ -484,6 +673,42 @@ pub struct ThermalTimePoint {
     pub heat_out_w: f64,
 }
 
+/// Converged transient temperature cycle over one orbit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrbitThermalSummary {
+    /// Minimum temperature over the orbit in Kelvin
+    pub min_temp_k: f64,
+    /// Maximum temperature over the orbit in Kelvin
+    pub max_temp_k: f64,
+    /// Orbit-averaged temperature in Kelvin
+    pub mean_temp_k: f64,
+    /// Peak-to-peak temperature swing in Kelvin
+    pub swing_k: f64,
+    /// Number of orbits simulated to reach convergence
+    pub orbits_simulated: usize,
+    /// Whether the whole cycle stays within operating limits
+    pub within_limits: bool,
+}
+
+/// Result of a two-node (junction + radiator) thermal simulation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoNodeThermalResult {
+    /// Electronics junction temperature in Kelvin
+    pub junction_temp_k: f64,
+    /// Radiator temperature in Kelvin
+    pub radiator_temp_k: f64,
+    /// Junction-to-radiator temperature difference in Kelvin
+    pub gradient_k: f64,
+    /// Junction-to-radiator conductance in W/K
+    pub conductance_w_per_k: f64,
+    /// Whether the junction stays within operating limits
+    pub junction_within_limits: bool,
+    /// Whether the radiator stays within operating limits
+    pub radiator_within_limits: bool,
+    /// Warnings about thermal conditions
+    pub warnings: Vec<String>,
+}
+
 /// Radiator sizing result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RadiatorSizing {
@@ -535,6 +760,23 @@ mod tests {
         assert!(result.equilibrium_temp_k < 400.0);
     }
 
+    #[test]
+    fn test_full_precision() {
+        let config = ThermalConfig::for_power(500.0);
+        let environment = ThermalEnvironment::leo(550.0);
+
+        let rounded = ThermalSimulator::new().simulate(&config, &environment);
+        let full = ThermalSimulator::new()
+            .with_precision(Precision::Full)
+            .simulate(&config, &environment);
+
+        assert_ne!(full.equilibrium_temp_c, rounded.equilibrium_temp_c);
+        assert_eq!(
+            Precision::Rounded.apply(full.equilibrium_temp_c, 1),
+            rounded.equilibrium_temp_c
+        );
+    }
+
     #[test]
     fn test_high_power_exceeds_limits() {
         let simulator = ThermalSimulator::new();
@@ -559,6 +801,24 @@ mod tests {
         assert!(sizing.feasible);
     }
 
+    #[test]
+    fn test_radiator_areal_density() {
+        let environment = ThermalEnvironment::leo(550.0);
+        let sizing = ThermalSimulator::new().size_radiator(500.0, 20.0, &environment);
+
+        let light = ThermalSimulator::new()
+            .with_radiator_areal_density(4.0)
+            .unwrap();
+        let light_sizing = light.size_radiator(500.0, 20.0, &environment);
+        assert_eq!(light_sizing.recommended_area_m2, sizing.recommended_area_m2);
+        assert!(light_sizing.mass_estimate_kg < sizing.mass_estimate_kg);
+
+        for density in [0.0, -4.0, f64::NAN] {
+            let rejected = ThermalSimulator::new().with_radiator_areal_density(density);
+            assert!(rejected.is_err());
+        }
+    }
+
     #[test]
     fn test_simulate_orbit() {
         let simulator = ThermalSimulator::new();
@@ -579,4 +839,40 @@ mod tests {
         assert_eq!(environment.orbit_type, OrbitType::Geo);
         assert!(environment.eclipse_fraction < 0.05);
     }
+
+    #[test]
+    fn test_two_node_gradient() {
+        let simulator = ThermalSimulator::new();
+        let config = ThermalConfig::for_power(200.0)
+            .with_radiator_area(4.0)
+            .with_emissivity(0.9);
+        let environment = ThermalEnvironment::leo(550.0);
+
+        let good = simulator.simulate_two_node(&config, &environment, 100.0);
+        assert!(good.gradient_k < 5.0);
+        assert!(good.junction_within_limits);
+
+        let poor = simulator.simulate_two_node(&config, &environment, 2.0);
+        assert!((poor.gradient_k - 100.0).abs() < 0.1);
+        assert!(poor.radiator_within_limits);
+        assert!(!poor.junction_within_limits);
+        assert!(poor.junction_temp_k > config.max_temp_k);
+    }
+
+    #[test]
+    fn test_orbit_thermal_summary_brackets() {
+        let simulator = ThermalSimulator::new();
+        let config = ThermalConfig::for_power(500.0);
+        let environment = ThermalEnvironment::leo(550.0);
+
+        let analytic = simulator.simulate(&config, &environment);
+        let summary = simulator.orbit_thermal_summary(&config, &environment);
+
+        assert!(summary.orbits_simulated < MAX_CONVERGENCE_ORBITS);
+        assert!(summary.min_temp_k < summary.mean_temp_k);
+        assert!(summary.mean_temp_k < summary.max_temp_k);
+        // The analytic worst cases bound the transient cycle from outside
+        assert!(summary.min_temp_k >= analytic.cold_case_k);
+        assert!(summary.max_temp_k <= analytic.hot_case_k);
+    }
 }
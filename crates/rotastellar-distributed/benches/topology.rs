@@ -0,0 +1,40 @@
+//! Full topology rebuild against incremental updates on a 1000-node mesh.
+//!
+//! Run with `cargo bench -p rotastellar-distributed --bench topology`. The
+//! fraction of moved nodes at which the two cost the same sets
+//! `INCREMENTAL_MAX_MOVED_FRACTION` in `mesh.rs`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rotastellar_distributed::create_constellation;
+
+const PLANES: usize = 20;
+const SATS_PER_PLANE: usize = 50;
+
+fn topology_updates(c: &mut Criterion) {
+    let mut mesh = create_constellation("bench", PLANES, SATS_PER_PLANE, 550.0, 53.0, 3000.0);
+    let ids: Vec<String> = (0..PLANES)
+        .flat_map(|p| (0..SATS_PER_PLANE).map(move |s| format!("bench_P{}_S{}", p, s)))
+        .collect();
+
+    let mut group = c.benchmark_group("topology");
+    group.sample_size(20);
+    group.bench_function("full", |b| b.iter(|| mesh.update_topology()));
+    for percent in [1, 10, 25, 50, 75, 100] {
+        // Spread the moved nodes evenly across planes
+        let moved: Vec<&str> = ids
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i * percent % 100 < percent)
+            .map(|(_, id)| id.as_str())
+            .collect();
+        group.bench_with_input(
+            BenchmarkId::new("incremental", percent),
+            &moved,
+            |b, moved| b.iter(|| mesh.update_topology_incremental(moved)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, topology_updates);
+criterion_main!(benches);
@@ -3,6 +3,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::federated::CompressionStats;
+
 /// Type of compute node in the Earth-space infrastructure.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -185,6 +187,14 @@ impl TrainingMetrics {
         self.sync_count += 1;
     }
 
+    /// Record the compression achieved so far in the run.
+    pub fn record_compression(&mut self, stats: &CompressionStats) {
+        if stats.compressions > 0 {
+            self.compression_ratio = stats.mean_ratio;
+            self.sparsity_achieved = stats.mean_sparsity;
+        }
+    }
+
     /// Total bytes transferred.
     pub fn total_bytes_transferred(&self) -> u64 {
         self.bytes_uploaded + self.bytes_downloaded
@@ -17,6 +17,11 @@ use std::collections::HashMap;
 // TODO(subhadipmitra): Add SIMD optimization for the quantization loop
 // TODO: Benchmark against tch-rs (PyTorch bindings) gradient compression
 
+/// Bits per dense gradient value (f32 on the wire)
+const VALUE_BITS: f64 = 32.0;
+/// Bits per sparse index
+const INDEX_BITS: f64 = 32.0;
+
 /// Gradient compression method.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -109,11 +114,11 @@ impl CompressionConfig {
     pub fn theoretical_compression_ratio(&self) -> f64 {
         match self.method {
             CompressionMethod::None => 1.0,
-            CompressionMethod::TopK => self.k_ratio * (1.0 + 32.0 / 32.0),
+            CompressionMethod::TopK => self.k_ratio * (1.0 + INDEX_BITS / VALUE_BITS),
             CompressionMethod::TopKQuantized => {
-                self.k_ratio * (self.quantization_bits as f64 / 32.0 + 32.0 / 32.0)
+                self.k_ratio * ((self.quantization_bits as f64 + INDEX_BITS) / VALUE_BITS)
             }
-            CompressionMethod::Quantization => self.quantization_bits as f64 / 32.0,
+            CompressionMethod::Quantization => self.quantization_bits as f64 / VALUE_BITS,
             CompressionMethod::RandomK => self.k_ratio,
         }
     }
@@ -145,10 +150,27 @@ impl CompressedGradient {
     }
 }
 
+/// Compression totals accumulated over a training run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompressionStats {
+    /// Number of gradients compressed
+    pub compressions: u64,
+    /// Total compressed size in bytes
+    pub total_compressed_bytes: u64,
+    /// Total dense size in bytes (32-bit floats)
+    pub total_original_bytes: u64,
+    /// Mean sparsity across compressed gradients
+    pub mean_sparsity: f64,
+    /// Mean per-gradient compression ratio
+    pub mean_ratio: f64,
+}
+
 /// Compress gradients for bandwidth-efficient synchronization.
 pub struct GradientCompressor {
     config: CompressionConfig,
     error_accumulator: Option<Vec<f64>>,
+    named_error_accumulators: HashMap<String, Vec<f64>>,
+    stats: CompressionStats,
 }
 
 impl GradientCompressor {
@@ -157,22 +179,118 @@ impl GradientCompressor {
         Self {
             config,
             error_accumulator: None,
+            named_error_accumulators: HashMap::new(),
+            stats: CompressionStats::default(),
         }
     }
 
     /// Compress gradients.
     pub fn compress(&mut self, gradients: &[f64]) -> CompressedGradient {
-        let original_size = gradients.len();
-        let mut working: Vec<f64> = gradients.to_vec();
+        let mut errors = self.error_accumulator.take();
+        let compressed = self.compress_with_errors(gradients, &mut errors);
+        self.error_accumulator = errors;
+        self.record(&compressed);
+        compressed
+    }
 
-        // Apply error feedback if enabled
-        if self.config.error_feedback {
-            if let Some(ref errors) = self.error_accumulator {
-                for (w, e) in working.iter_mut().zip(errors.iter()) {
-                    *w += e;
+    /// Statistics accumulated over every gradient compressed so far.
+    pub fn stats(&self) -> CompressionStats {
+        self.stats
+    }
+
+    fn record(&mut self, compressed: &CompressedGradient) {
+        let stats = &mut self.stats;
+        let n = stats.compressions as f64;
+        let sparsity = if compressed.original_size > 0 {
+            compressed.sparsity()
+        } else {
+            0.0
+        };
+
+        stats.compressions += 1;
+        stats.total_compressed_bytes += compressed.compressed_size as u64;
+        stats.total_original_bytes += compressed.original_size as u64 * 4;
+        stats.mean_sparsity = (stats.mean_sparsity * n + sparsity) / (n + 1.0);
+        stats.mean_ratio = (stats.mean_ratio * n + compressed.compression_ratio) / (n + 1.0);
+    }
+
+    /// Compress a dictionary of named gradient tensors.
+    ///
+    /// For Top-K methods the `k_ratio` budget is applied globally across all
+    /// tensors, so the largest magnitudes are kept regardless of which tensor
+    /// they came from (as in Deep Gradient Compression). Other methods are
+    /// applied per tensor. Error feedback is tracked separately for each name.
+    pub fn compress_dict(
+        &mut self,
+        grads: &HashMap<String, Vec<f64>>,
+    ) -> HashMap<String, CompressedGradient> {
+        let mut names: Vec<&String> = grads.keys().collect();
+        names.sort();
+
+        if !matches!(
+            self.config.method,
+            CompressionMethod::TopK | CompressionMethod::TopKQuantized
+        ) {
+            let mut result = HashMap::new();
+            for name in names {
+                let mut errors = self.named_error_accumulators.remove(name);
+                let compressed = self.compress_with_errors(&grads[name], &mut errors);
+                if let Some(errors) = errors {
+                    self.named_error_accumulators.insert(name.clone(), errors);
                 }
+                self.record(&compressed);
+                result.insert(name.clone(), compressed);
+            }
+            return result;
+        }
+
+        let working: Vec<Vec<f64>> = names
+            .iter()
+            .map(|name| {
+                self.apply_error_feedback(&grads[*name], self.named_error_accumulators.get(*name))
+            })
+            .collect();
+
+        // Global Top-K selection across every tensor
+        let total_size: usize = working.iter().map(|w| w.len()).sum();
+        let k = (total_size as f64 * self.config.k_ratio).ceil() as usize;
+        let k = k.max(1).min(total_size);
+
+        // Non-finite values (diverged training) are never selected
+        let mut candidates: Vec<(usize, usize, f64)> = working
+            .iter()
+            .enumerate()
+            .flat_map(|(t, w)| w.iter().enumerate().map(move |(i, &v)| (t, i, v.abs())))
+            .filter(|c| c.2.is_finite())
+            .collect();
+        candidates.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+        let mut selected: Vec<Vec<usize>> = vec![Vec::new(); working.len()];
+        for &(t, i, _) in candidates.iter().take(k) {
+            selected[t].push(i);
+        }
+
+        let mut result = HashMap::new();
+        for ((name, w), indices) in names.into_iter().zip(working.iter()).zip(selected) {
+            let values = indices.iter().map(|&i| w[i]).collect();
+            let mut errors = self.named_error_accumulators.remove(name);
+            let compressed = self.finish_compression(w, indices, values, &mut errors);
+            if let Some(errors) = errors {
+                self.named_error_accumulators.insert(name.clone(), errors);
             }
+            self.record(&compressed);
+            result.insert(name.clone(), compressed);
         }
+        result
+    }
+
+    fn compress_with_errors(
+        &self,
+        gradients: &[f64],
+        errors: &mut Option<Vec<f64>>,
+    ) -> CompressedGradient {
+        let original_size = gradients.len();
+        let working = self.apply_error_feedback(gradients, errors.as_ref());
 
         if self.config.method == CompressionMethod::None {
             return CompressedGradient {
@@ -189,15 +307,16 @@ impl GradientCompressor {
         let k = (original_size as f64 * self.config.k_ratio).ceil() as usize;
         let k = k.max(1).min(original_size);
 
-        let (indices, mut values) = match self.config.method {
+        let (indices, values) = match self.config.method {
             CompressionMethod::TopK | CompressionMethod::TopKQuantized => {
                 // Top-K selection
                 let mut indexed: Vec<(usize, f64, f64)> = working
                     .iter()
                     .enumerate()
                     .map(|(i, &v)| (i, v.abs(), v))
+                    .filter(|x| x.1.is_finite())
                     .collect();
-                indexed.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+                indexed.sort_by(|a, b| b.1.total_cmp(&a.1));
                 let selected: Vec<_> = indexed.into_iter().take(k).collect();
                 let indices: Vec<usize> = selected.iter().map(|x| x.0).collect();
                 let values: Vec<f64> = selected.iter().map(|x| x.2).collect();
@@ -219,6 +338,33 @@ impl GradientCompressor {
             }
         };
 
+        self.finish_compression(&working, indices, values, errors)
+    }
+
+    fn apply_error_feedback(&self, gradients: &[f64], errors: Option<&Vec<f64>>) -> Vec<f64> {
+        let mut working: Vec<f64> = gradients.to_vec();
+
+        // Apply error feedback if enabled
+        if self.config.error_feedback {
+            if let Some(errors) = errors {
+                for (w, e) in working.iter_mut().zip(errors.iter()) {
+                    *w += e;
+                }
+            }
+        }
+        working
+    }
+
+    /// Quantize the selected values, update error feedback, and size the result.
+    fn finish_compression(
+        &self,
+        working: &[f64],
+        indices: Vec<usize>,
+        mut values: Vec<f64>,
+        errors: &mut Option<Vec<f64>>,
+    ) -> CompressedGradient {
+        let original_size = working.len();
+
         // Apply quantization if needed
         if self.config.method == CompressionMethod::TopKQuantized && !values.is_empty() {
             values = self.quantize(&values);
@@ -230,7 +376,7 @@ impl GradientCompressor {
             for (i, &idx) in indices.iter().enumerate() {
                 reconstructed[idx] = values[i];
             }
-            self.error_accumulator = Some(
+            *errors = Some(
                 working
                     .iter()
                     .zip(reconstructed.iter())
@@ -264,12 +410,52 @@ impl GradientCompressor {
     }
 
     /// Decompress gradients back to dense representation.
-    pub fn decompress(&self, compressed: &CompressedGradient) -> Vec<f64> {
+    ///
+    /// Returns an error if the gradient is malformed (e.g. corrupted on the
+    /// wire) rather than panicking on an out-of-range index.
+    pub fn decompress(&self, compressed: &CompressedGradient) -> Result<Vec<f64>, &'static str> {
+        if compressed.indices.len() != compressed.values.len() {
+            return Err("Gradient indices and values have different lengths");
+        }
+        if compressed
+            .indices
+            .iter()
+            .any(|&idx| idx >= compressed.original_size)
+        {
+            return Err("Gradient index out of range for original size");
+        }
+
         let mut result = vec![0.0; compressed.original_size];
         for (i, &idx) in compressed.indices.iter().enumerate() {
             result[idx] = compressed.values[i];
         }
-        result
+        Ok(result)
+    }
+
+    /// Relative L2 error between the original gradients and their compressed form.
+    ///
+    /// Useful for tuning `k_ratio` and `quantization_bits` against accuracy.
+    /// Returns `f64::INFINITY` if the compressed gradient cannot be decompressed
+    /// or does not match the original length.
+    pub fn reconstruction_error(&self, original: &[f64], compressed: &CompressedGradient) -> f64 {
+        let reconstructed = match self.decompress(compressed) {
+            Ok(r) if r.len() == original.len() => r,
+            _ => return f64::INFINITY,
+        };
+
+        let error_norm = original
+            .iter()
+            .zip(reconstructed.iter())
+            .map(|(o, r)| (o - r).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let original_norm = original.iter().map(|o| o * o).sum::<f64>().sqrt();
+
+        if original_norm == 0.0 {
+            error_norm
+        } else {
+            error_norm / original_norm
+        }
     }
 
     fn quantize(&self, values: &[f64]) -> Vec<f64> {
@@ -357,6 +543,37 @@ impl FederatedClient {
         self.compressor.compress(gradients)
     }
 
+    /// Capture the client state needed to resume after a restart.
+    ///
+    /// Includes the compression error accumulators and statistics, so error
+    /// feedback and run totals are not lost when a duty-cycled node reboots.
+    pub fn checkpoint(&self) -> ClientState {
+        ClientState {
+            node_id: self.node_id.clone(),
+            node_type: self.node_type.clone(),
+            local_steps: self.local_steps,
+            compression: self.compression.clone(),
+            error_accumulator: self.compressor.error_accumulator.clone(),
+            named_error_accumulators: self.compressor.named_error_accumulators.clone(),
+            compression_stats: self.compressor.stats,
+        }
+    }
+
+    /// Restore a client from a checkpoint.
+    pub fn restore(state: ClientState) -> Self {
+        let mut compressor = GradientCompressor::new(state.compression.clone());
+        compressor.error_accumulator = state.error_accumulator;
+        compressor.named_error_accumulators = state.named_error_accumulators;
+        compressor.stats = state.compression_stats;
+        Self {
+            node_id: state.node_id,
+            node_type: state.node_type,
+            compression: state.compression,
+            compressor,
+            local_steps: state.local_steps,
+        }
+    }
+
     /// Apply aggregated update to local model.
     pub fn apply_update(&self, model_params: &[f64], update: &[f64], lr: f64) -> Vec<f64> {
         model_params
@@ -380,15 +597,46 @@ impl FederatedClient {
     }
 }
 
+/// Serializable snapshot of a [`FederatedClient`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientState {
+    /// Node ID
+    pub node_id: String,
+    /// Node type
+    pub node_type: String,
+    /// Local training steps completed
+    pub local_steps: u64,
+    /// Compression config
+    pub compression: CompressionConfig,
+    /// Accumulated compression error for flat gradients
+    pub error_accumulator: Option<Vec<f64>>,
+    /// Accumulated compression error per named tensor
+    #[serde(default)]
+    pub named_error_accumulators: HashMap<String, Vec<f64>>,
+    /// Compression statistics accumulated so far
+    #[serde(default)]
+    pub compression_stats: CompressionStats,
+}
+
 /// Central aggregator for gradient synchronization.
+///
+/// All participants must agree on the model size. It is either set up front
+/// with [`set_model_size`](Self::set_model_size) or taken from the first
+/// accepted gradient, and is then enforced for every later submission and
+/// round. Named tensors submitted with
+/// [`receive_gradients_dict`](Self::receive_gradients_dict) are sized the same
+/// way, per tensor name.
 pub struct GradientAggregator {
     /// Aggregation strategy
     pub strategy: AggregationStrategy,
     /// Minimum participants required
     pub min_participants: usize,
-    /// Model size (if known)
+    /// Model size (set explicitly or inferred from the first accepted gradient)
     pub model_size: Option<usize>,
     pending_gradients: HashMap<String, (CompressedGradient, u64)>,
+    pending_named: HashMap<String, (HashMap<String, CompressedGradient>, u64)>,
+    tensor_sizes: HashMap<String, usize>,
+    rejected: HashMap<String, u64>,
     round: u64,
 }
 
@@ -400,26 +648,136 @@ impl GradientAggregator {
             min_participants,
             model_size: None,
             pending_gradients: HashMap::new(),
+            pending_named: HashMap::new(),
+            tensor_sizes: HashMap::new(),
+            rejected: HashMap::new(),
             round: 0,
         }
     }
 
+    /// Set the number of parameters every gradient must have.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if gradients of a different size are already pending.
+    pub fn set_model_size(&mut self, model_size: usize) -> Result<(), &'static str> {
+        if self
+            .pending_gradients
+            .values()
+            .any(|(g, _)| g.original_size != model_size)
+        {
+            return Err("Pending gradients do not match model size");
+        }
+        self.model_size = Some(model_size);
+        Ok(())
+    }
+
     /// Receive gradients from a node.
-    pub fn receive_gradients(&mut self, node_id: &str, gradients: CompressedGradient, samples: u64) {
+    ///
+    /// Gradients are checked before they are stored, so one bad client
+    /// cannot panic or poison the aggregation. The first accepted gradient
+    /// fixes the model size if it was not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (and counts the rejection against `node_id`) if
+    /// `original_size` differs from the model size, if indices and values
+    /// differ in length, if an index is out of range, or if a value is not
+    /// finite.
+    pub fn receive_gradients(
+        &mut self,
+        node_id: &str,
+        gradients: CompressedGradient,
+        samples: u64,
+    ) -> Result<(), &'static str> {
+        if let Err(e) = Self::validate(&gradients, self.model_size) {
+            *self.rejected.entry(node_id.to_string()).or_insert(0) += 1;
+            return Err(e);
+        }
+        self.model_size.get_or_insert(gradients.original_size);
         self.pending_gradients
             .insert(node_id.to_string(), (gradients, samples));
+        Ok(())
+    }
+
+    /// Receive per-tensor gradients from a node, as produced by
+    /// [`GradientCompressor::compress_dict`].
+    ///
+    /// Each tensor is checked like [`receive_gradients`](Self::receive_gradients),
+    /// against the size first accepted for that tensor name. Nodes may submit
+    /// different subsets of tensors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (and counts the rejection against `node_id`) if any
+    /// tensor fails validation; nothing from the submission is stored.
+    pub fn receive_gradients_dict(
+        &mut self,
+        node_id: &str,
+        gradients: HashMap<String, CompressedGradient>,
+        samples: u64,
+    ) -> Result<(), &'static str> {
+        for (name, tensor) in &gradients {
+            if let Err(e) = Self::validate(tensor, self.tensor_sizes.get(name).copied()) {
+                *self.rejected.entry(node_id.to_string()).or_insert(0) += 1;
+                return Err(e);
+            }
+        }
+        for (name, tensor) in &gradients {
+            self.tensor_sizes
+                .entry(name.clone())
+                .or_insert(tensor.original_size);
+        }
+        self.pending_named
+            .insert(node_id.to_string(), (gradients, samples));
+        Ok(())
+    }
+
+    fn validate(
+        gradients: &CompressedGradient,
+        expected_size: Option<usize>,
+    ) -> Result<(), &'static str> {
+        if expected_size.is_some_and(|size| size != gradients.original_size) {
+            return Err("Gradient size does not match model size");
+        }
+        if gradients.indices.len() != gradients.values.len() {
+            return Err("Gradient indices and values differ in length");
+        }
+        if gradients
+            .indices
+            .iter()
+            .any(|&i| i >= gradients.original_size)
+        {
+            return Err("Gradient index out of range");
+        }
+        if gradients.values.iter().any(|v| !v.is_finite()) {
+            return Err("Gradient contains non-finite values");
+        }
+        Ok(())
     }
 
-    /// Number of nodes that have submitted gradients.
+    /// Number of nodes with flat gradients pending for
+    /// [`aggregate`](Self::aggregate).
     pub fn num_participants(&self) -> usize {
         self.pending_gradients.len()
     }
 
+    /// Number of nodes with named tensors pending for
+    /// [`aggregate_dict`](Self::aggregate_dict).
+    pub fn num_named_participants(&self) -> usize {
+        self.pending_named.len()
+    }
+
     /// Check if enough participants for aggregation.
     pub fn ready_to_aggregate(&self) -> bool {
         self.num_participants() >= self.min_participants
     }
 
+    /// Check if enough participants for [`aggregate_dict`](Self::aggregate_dict).
+    pub fn ready_to_aggregate_dict(&self) -> bool {
+        self.num_named_participants() >= self.min_participants
+    }
+
     /// Aggregate gradients using configured strategy.
     pub fn aggregate(&mut self) -> Result<Vec<f64>, &'static str> {
         if self.pending_gradients.is_empty() {
@@ -434,23 +792,73 @@ impl GradientAggregator {
                 .unwrap_or(0)
         });
 
-        let result = match self.strategy {
-            AggregationStrategy::FedAvg | AggregationStrategy::WeightedAvg => {
-                self.fed_avg(model_size)
-            }
-            AggregationStrategy::AsyncFedAvg => self.async_fed_avg(model_size),
-        };
+        let entries: Vec<_> = self
+            .pending_gradients
+            .values()
+            .map(|(g, s)| (g, *s))
+            .collect();
+        let result = self.combine(model_size, &entries);
 
         self.pending_gradients.clear();
         self.round += 1;
         Ok(result)
     }
 
-    fn fed_avg(&self, model_size: usize) -> Vec<f64> {
-        let total_samples: u64 = self.pending_gradients.values().map(|(_, s)| s).sum();
-        let mut aggregated = vec![0.0; model_size];
+    /// Aggregate named tensors submitted with
+    /// [`receive_gradients_dict`](Self::receive_gradients_dict).
+    ///
+    /// Each tensor is aggregated independently over the nodes that submitted
+    /// it, so sample weights are normalized per tensor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no named gradients are pending, or if fewer than
+    /// `min_participants` nodes submitted them; pending submissions are kept.
+    pub fn aggregate_dict(&mut self) -> Result<HashMap<String, Vec<f64>>, &'static str> {
+        if self.pending_named.is_empty() {
+            return Err("No named gradients to aggregate");
+        }
+        if !self.ready_to_aggregate_dict() {
+            return Err("Not enough participants for named aggregation");
+        }
+
+        let mut by_tensor: HashMap<&String, Vec<(&CompressedGradient, u64)>> = HashMap::new();
+        for (tensors, samples) in self.pending_named.values() {
+            for (name, tensor) in tensors {
+                by_tensor.entry(name).or_default().push((tensor, *samples));
+            }
+        }
+
+        let result = by_tensor
+            .into_iter()
+            .map(|(name, entries)| {
+                (
+                    name.clone(),
+                    self.combine(self.tensor_sizes[name], &entries),
+                )
+            })
+            .collect();
+
+        self.pending_named.clear();
+        self.round += 1;
+        Ok(result)
+    }
+
+    /// Combine gradients using the configured strategy.
+    fn combine(&self, size: usize, entries: &[(&CompressedGradient, u64)]) -> Vec<f64> {
+        match self.strategy {
+            AggregationStrategy::FedAvg | AggregationStrategy::WeightedAvg => {
+                Self::fed_avg(size, entries)
+            }
+            AggregationStrategy::AsyncFedAvg => Self::async_fed_avg(size, entries),
+        }
+    }
+
+    fn fed_avg(size: usize, entries: &[(&CompressedGradient, u64)]) -> Vec<f64> {
+        let total_samples: u64 = entries.iter().map(|(_, s)| s).sum();
+        let mut aggregated = vec![0.0; size];
 
-        for (grad, samples) in self.pending_gradients.values() {
+        for (grad, samples) in entries {
             let weight = *samples as f64 / total_samples as f64;
             for (i, &idx) in grad.indices.iter().enumerate() {
                 aggregated[idx] += grad.values[i] * weight;
@@ -460,11 +868,11 @@ impl GradientAggregator {
         aggregated
     }
 
-    fn async_fed_avg(&self, model_size: usize) -> Vec<f64> {
-        let n = self.pending_gradients.len() as f64;
-        let mut aggregated = vec![0.0; model_size];
+    fn async_fed_avg(size: usize, entries: &[(&CompressedGradient, u64)]) -> Vec<f64> {
+        let n = entries.len() as f64;
+        let mut aggregated = vec![0.0; size];
 
-        for (grad, _) in self.pending_gradients.values() {
+        for (grad, _) in entries {
             for (i, &idx) in grad.indices.iter().enumerate() {
                 aggregated[idx] += grad.values[i] / n;
             }
@@ -482,10 +890,22 @@ impl GradientAggregator {
             "pending_participants".to_string(),
             self.num_participants().to_string(),
         );
+        stats.insert(
+            "pending_named_participants".to_string(),
+            self.num_named_participants().to_string(),
+        );
         stats.insert(
             "min_participants".to_string(),
             self.min_participants.to_string(),
         );
+        stats.insert(
+            "rejected_clients".to_string(),
+            self.rejected.len().to_string(),
+        );
+        stats.insert(
+            "rejected_gradients".to_string(),
+            self.rejected.values().sum::<u64>().to_string(),
+        );
         stats
     }
 }
@@ -515,10 +935,38 @@ mod tests {
         assert!(compressed.indices.len() <= 100); // ~1% of 1000
         assert!(compressed.compression_ratio < 0.5);
 
-        let decompressed = compressor.decompress(&compressed);
+        let decompressed = compressor.decompress(&compressed).unwrap();
         assert_eq!(decompressed.len(), gradients.len());
     }
 
+    #[test]
+    fn test_reconstruction_error_and_corrupt_gradient() {
+        let gradients: Vec<f64> = (0..200).map(|i| (i as f64 * 0.37).sin()).collect();
+
+        let mut errors = Vec::new();
+        for bits in [8, 4, 2] {
+            let mut config = CompressionConfig::new(CompressionMethod::TopKQuantized, 0.5, bits);
+            config.error_feedback = false;
+            let mut compressor = GradientCompressor::new(config);
+            let compressed = compressor.compress(&gradients);
+            errors.push(compressor.reconstruction_error(&gradients, &compressed));
+        }
+        assert!(errors[0] < errors[1]);
+        assert!(errors[1] < errors[2]);
+
+        let compressor = GradientCompressor::new(CompressionConfig::balanced());
+        let corrupt = CompressedGradient {
+            indices: vec![0, 42],
+            values: vec![0.1, 0.2],
+            shape: vec![10],
+            original_size: 10,
+            compressed_size: 10,
+            compression_ratio: 0.25,
+            quantization_bits: None,
+        };
+        assert!(compressor.decompress(&corrupt).is_err());
+    }
+
     #[test]
     fn test_federated_client() {
         let mut client = FederatedClient::orbital("sat-1");
@@ -555,12 +1003,243 @@ mod tests {
             quantization_bits: None,
         };
 
-        aggregator.receive_gradients("node-1", grad1, 100);
-        aggregator.receive_gradients("node-2", grad2, 100);
+        aggregator.receive_gradients("node-1", grad1, 100).unwrap();
+        aggregator.receive_gradients("node-2", grad2, 100).unwrap();
 
         assert!(aggregator.ready_to_aggregate());
 
         let result = aggregator.aggregate().unwrap();
         assert_eq!(result.len(), 10);
     }
+
+    #[test]
+    fn test_aggregator_rejects_invalid_gradients() {
+        let mut aggregator = GradientAggregator::new(AggregationStrategy::FedAvg, 1);
+        let valid = CompressedGradient {
+            indices: vec![0, 9],
+            values: vec![0.5, -0.5],
+            shape: vec![10],
+            original_size: 10,
+            compressed_size: 16,
+            compression_ratio: 0.2,
+            quantization_bits: None,
+        };
+        aggregator
+            .receive_gradients("good", valid.clone(), 100)
+            .unwrap();
+
+        let out_of_range = CompressedGradient {
+            indices: vec![0, 10],
+            ..valid.clone()
+        };
+        assert!(aggregator
+            .receive_gradients("bad", out_of_range, 100)
+            .is_err());
+        let nan = CompressedGradient {
+            values: vec![f64::NAN, 0.1],
+            ..valid.clone()
+        };
+        assert!(aggregator.receive_gradients("bad", nan, 100).is_err());
+        let resized = CompressedGradient {
+            original_size: 20,
+            ..valid
+        };
+        assert!(aggregator.receive_gradients("other", resized, 100).is_err());
+
+        let stats = aggregator.get_stats();
+        assert_eq!(stats["rejected_clients"], "2");
+        assert_eq!(stats["rejected_gradients"], "3");
+        assert_eq!(aggregator.num_participants(), 1);
+
+        let result = aggregator.aggregate().unwrap();
+        assert_eq!(
+            result,
+            vec![0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5]
+        );
+    }
+
+    #[test]
+    fn test_aggregator_enforces_model_size() {
+        let gradient = |size: usize| CompressedGradient {
+            indices: vec![0],
+            values: vec![1.0],
+            shape: vec![size],
+            original_size: size,
+            compressed_size: 8,
+            compression_ratio: 0.1,
+            quantization_bits: None,
+        };
+
+        let mut aggregator = GradientAggregator::new(AggregationStrategy::FedAvg, 1);
+        aggregator
+            .receive_gradients("node-1", gradient(10), 100)
+            .unwrap();
+        assert_eq!(aggregator.model_size, Some(10));
+        assert!(aggregator
+            .receive_gradients("node-2", gradient(12), 100)
+            .is_err());
+        assert!(aggregator.set_model_size(12).is_err());
+        assert_eq!(aggregator.aggregate().unwrap().len(), 10);
+
+        // Still enforced in later rounds
+        assert!(aggregator
+            .receive_gradients("node-2", gradient(12), 100)
+            .is_err());
+
+        let mut explicit = GradientAggregator::new(AggregationStrategy::FedAvg, 1);
+        explicit.set_model_size(12).unwrap();
+        assert!(explicit
+            .receive_gradients("node-1", gradient(10), 100)
+            .is_err());
+        assert!(explicit
+            .receive_gradients("node-2", gradient(12), 100)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_aggregate_dict() {
+        let mut compressor =
+            GradientCompressor::new(CompressionConfig::new(CompressionMethod::None, 1.0, 32));
+        let tensors = |scale: f64| -> HashMap<String, Vec<f64>> {
+            HashMap::from([
+                ("encoder".to_string(), vec![scale, 2.0 * scale]),
+                ("head".to_string(), vec![-scale, 0.5 * scale, 4.0 * scale]),
+            ])
+        };
+
+        let mut aggregator = GradientAggregator::new(AggregationStrategy::FedAvg, 2);
+        aggregator
+            .receive_gradients_dict("node-1", compressor.compress_dict(&tensors(1.0)), 100)
+            .unwrap();
+        // One node is below min_participants, and its submission is kept
+        assert!(!aggregator.ready_to_aggregate_dict());
+        assert!(aggregator.aggregate_dict().is_err());
+        assert_eq!(aggregator.num_named_participants(), 1);
+
+        aggregator
+            .receive_gradients_dict("node-2", compressor.compress_dict(&tensors(3.0)), 300)
+            .unwrap();
+        assert!(aggregator.ready_to_aggregate_dict());
+        // Named submissions don't count towards flat aggregation
+        assert!(!aggregator.ready_to_aggregate());
+        assert_eq!(aggregator.num_participants(), 0);
+
+        // A tensor whose size disagrees is rejected without storing anything
+        let wrong = HashMap::from([("head".to_string(), vec![1.0; 4])]);
+        assert!(aggregator
+            .receive_gradients_dict("node-3", compressor.compress_dict(&wrong), 50)
+            .is_err());
+        assert_eq!(aggregator.num_named_participants(), 2);
+        assert_eq!(aggregator.get_stats()["pending_named_participants"], "2");
+
+        let aggregated = aggregator.aggregate_dict().unwrap();
+        assert_eq!(aggregated.len(), 2);
+        // Weighted 1:3 → 2.5× the first client's values
+        for (name, expected) in tensors(2.5) {
+            for (a, e) in aggregated[&name].iter().zip(&expected) {
+                assert!((a - e).abs() < 1e-9, "{}: {} != {}", name, a, e);
+            }
+        }
+        assert!(aggregator.aggregate_dict().is_err());
+    }
+
+    #[test]
+    fn test_compress_dict_global_budget() {
+        let mut compressor =
+            GradientCompressor::new(CompressionConfig::new(CompressionMethod::TopK, 0.1, 32));
+
+        let mut grads = HashMap::new();
+        grads.insert(
+            "large".to_string(),
+            (0..100).map(|i| 1.0 + i as f64 * 0.01).collect(),
+        );
+        grads.insert(
+            "small".to_string(),
+            (0..100).map(|i| 0.001 * (i as f64).sin()).collect(),
+        );
+
+        let compressed = compressor.compress_dict(&grads);
+
+        // 10% of 200 elements, all drawn from the high-magnitude tensor
+        assert_eq!(compressed["large"].indices.len(), 20);
+        assert!(compressed["small"].indices.is_empty());
+        assert_eq!(compressed["small"].original_size, 100);
+
+        // Error feedback is kept per tensor, so dropped values resurface later
+        let zeros: HashMap<String, Vec<f64>> =
+            grads.keys().map(|k| (k.clone(), vec![0.0; 100])).collect();
+        let next = compressor.compress_dict(&zeros);
+        assert_eq!(next["large"].indices.len(), 20);
+
+        // A diverged value is skipped rather than ranked or panicking the sort
+        grads.get_mut("small").unwrap()[0] = f64::NAN;
+        let mut fresh =
+            GradientCompressor::new(CompressionConfig::new(CompressionMethod::TopK, 0.1, 32));
+        let compressed = fresh.compress_dict(&grads);
+        assert!(compressed["small"].indices.is_empty());
+        assert_eq!(compressed["large"].indices.len(), 20);
+        assert!(!fresh.compress(&grads["small"]).indices.contains(&0));
+    }
+
+    #[test]
+    fn test_compression_stats() {
+        let mut compressor = GradientCompressor::new(CompressionConfig::balanced());
+        let compressed: Vec<CompressedGradient> = [100, 1000, 5000]
+            .iter()
+            .map(|&n| {
+                let grads: Vec<f64> = (0..n).map(|i| (i as f64 * 0.7).sin()).collect();
+                compressor.compress(&grads)
+            })
+            .collect();
+
+        let stats = compressor.stats();
+        let manual_ratio = compressed.iter().map(|c| c.compression_ratio).sum::<f64>() / 3.0;
+        let manual_sparsity = compressed.iter().map(|c| c.sparsity()).sum::<f64>() / 3.0;
+        assert_eq!(stats.compressions, 3);
+        assert!((stats.mean_ratio - manual_ratio).abs() < 1e-12);
+        assert!((stats.mean_sparsity - manual_sparsity).abs() < 1e-12);
+        assert_eq!(stats.total_original_bytes, 6100 * 4);
+        assert_eq!(
+            stats.total_compressed_bytes,
+            compressed
+                .iter()
+                .map(|c| c.compressed_size as u64)
+                .sum::<u64>()
+        );
+
+        let mut metrics = crate::core::TrainingMetrics::new();
+        metrics.record_compression(&stats);
+        assert_eq!(metrics.compression_ratio, stats.mean_ratio);
+        assert_eq!(metrics.sparsity_achieved, stats.mean_sparsity);
+    }
+
+    #[test]
+    fn test_client_checkpoint_restore() {
+        let params = vec![0.0; 500];
+        let mut client = FederatedClient::orbital("sat-1");
+        for _ in 0..3 {
+            let grads = client.compute_gradients(&params, &[]);
+            client.compress(&grads);
+        }
+
+        let json = serde_json::to_string(&client.checkpoint()).unwrap();
+        let mut restored = FederatedClient::restore(serde_json::from_str(&json).unwrap());
+
+        let expected = {
+            let grads = client.compute_gradients(&params, &[]);
+            client.compress(&grads)
+        };
+        let actual = {
+            let grads = restored.compute_gradients(&params, &[]);
+            restored.compress(&grads)
+        };
+
+        assert_eq!(expected.indices, actual.indices);
+        let bits =
+            |g: &CompressedGradient| g.values.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(&expected), bits(&actual));
+        assert_eq!(restored.get_stats()["local_steps"], "4");
+        assert_eq!(restored.compressor.stats().compressions, 4);
+        assert_eq!(restored.compressor.stats(), client.compressor.stats());
+    }
 }
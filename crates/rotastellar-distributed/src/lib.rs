@@ -61,16 +61,18 @@ pub mod sync;
 pub use core::{NodeConfig, NodeType, Topology, TrainingMetrics};
 
 pub use federated::{
-    AggregationStrategy, CompressedGradient, CompressionConfig, CompressionMethod,
-    FederatedClient, GradientAggregator, GradientCompressor,
+    AggregationStrategy, ClientState, CompressedGradient, CompressionConfig, CompressionMethod,
+    CompressionStats, FederatedClient, GradientAggregator, GradientCompressor,
 };
 
 pub use partitioning::{
     LayerPlacement, LayerProfile, LayerType, ModelProfile, OptimizationObjective,
-    PartitionOptimizer, PartitionPlan, PlacementLocation,
+    PartitionOptimizer, PartitionPlan, PlacementLocation, TimelineEntry,
 };
 
-pub use sync::{GroundStation, Priority, PriorityQueue, SyncScheduler, SyncTask};
+pub use sync::{
+    GroundStation, GroundStationConfig, Priority, PriorityQueue, SyncScheduler, SyncTask,
+};
 
 pub use mesh::{create_constellation, ISLLink, LinkType, OrbitalNode, Route, SpaceMesh};
 
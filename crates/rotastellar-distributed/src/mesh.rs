@@ -13,6 +13,8 @@
 //! - Simplified orbital mechanics (circular orbits, no perturbations)
 //! - Static topology snapshot (real system would update every few seconds)
 
+use rotastellar::constants::{EARTH_MU, EARTH_RADIUS_MEAN_KM};
+use rotastellar_intel::TLE;
 use serde::{Deserialize, Serialize};
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::cmp::Ordering;
@@ -21,11 +23,22 @@ use std::cmp::Ordering;
 // TODO: Implement A* routing with angular distance heuristic for large constellations
 // FIXME: The LOS calculation is approximate - need proper ray-sphere intersection
 
+/// Share of moved nodes above which a full rebuild is cheaper than an
+/// incremental update (measured by `benches/topology.rs`)
+const INCREMENTAL_MAX_MOVED_FRACTION: f64 = 0.5;
+
 /// Type of communication link.
+///
+/// Serialized in snake_case (`"optical"`, `"rf"`, `"hybrid"`) to match the
+/// Python and TypeScript SDKs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LinkType {
+    /// Free-space optical (laser) link
     Optical,
+    /// Radio-frequency link
     Rf,
+    /// Optical with RF fallback
     Hybrid,
 }
 
@@ -63,6 +76,18 @@ impl OrbitalNode {
         self.mean_anomaly_deg = mean_anomaly_deg;
         self
     }
+
+    /// Create a node, keyed by NORAD ID, from a TLE's elements at its epoch.
+    pub fn from_tle(tle: &TLE) -> Self {
+        Self {
+            orbit_altitude_km: tle.semi_major_axis_km() - EARTH_RADIUS_MEAN_KM,
+            orbit_inclination_deg: tle.inclination,
+            raan_deg: tle.raan,
+            // Orbits are circular here, so the in-plane angle is the argument of latitude
+            mean_anomaly_deg: (tle.arg_perigee + tle.mean_anomaly).rem_euclid(360.0),
+            ..Self::new(&tle.norad_id.to_string())
+        }
+    }
 }
 
 /// Inter-satellite link.
@@ -86,6 +111,7 @@ pub struct Route {
     pub total_distance_km: f64,
     pub total_latency_ms: f64,
     pub min_bandwidth_gbps: f64,
+    /// Links traversed; 0 for a single-node or invalid route
     pub num_hops: usize,
 }
 
@@ -122,6 +148,9 @@ pub struct SpaceMesh {
     nodes: HashMap<String, OrbitalNode>,
     links: HashMap<String, ISLLink>,
     adjacency: HashMap<String, HashSet<String>>,
+    link_loads: HashMap<String, f64>,
+    evaluated_positions: HashMap<String, [f64; 3]>,
+    topology_tolerance_km: f64,
 }
 
 impl Default for SpaceMesh {
@@ -132,7 +161,10 @@ impl Default for SpaceMesh {
 
 impl SpaceMesh {
     const SPEED_OF_LIGHT_KM_S: f64 = 299792.458;
-    const EARTH_RADIUS_KM: f64 = 6371.0;
+    /// Smallest remaining capacity fraction used in the congestion penalty
+    const MIN_HEADROOM: f64 = 0.01;
+    /// Cost multiplier for links a flow would push past capacity
+    const SATURATED_LINK_PENALTY: f64 = 1000.0;
 
     /// Create a new space mesh.
     pub fn new(default_isl_range_km: f64) -> Self {
@@ -141,6 +173,9 @@ impl SpaceMesh {
             nodes: HashMap::new(),
             links: HashMap::new(),
             adjacency: HashMap::new(),
+            link_loads: HashMap::new(),
+            evaluated_positions: HashMap::new(),
+            topology_tolerance_km: 0.0,
         }
     }
 
@@ -151,6 +186,10 @@ impl SpaceMesh {
     }
 
     /// Update the mesh topology.
+    ///
+    /// Re-evaluates every node pair, O(n²). When only some nodes have moved,
+    /// [`update_topology_incremental`](Self::update_topology_incremental) is
+    /// cheaper.
     pub fn update_topology(&mut self) {
         self.links.clear();
         for adj in self.adjacency.values_mut() {
@@ -161,72 +200,219 @@ impl SpaceMesh {
 
         for i in 0..node_ids.len() {
             for j in (i + 1)..node_ids.len() {
-                let id1 = &node_ids[i];
-                let id2 = &node_ids[j];
-                let node1 = &self.nodes[id1];
-                let node2 = &self.nodes[id2];
-
-                let distance = self.calculate_distance(node1, node2);
-                let max_range = node1.isl_range_km.min(node2.isl_range_km);
-
-                if distance <= max_range && self.has_line_of_sight(node1, node2) {
-                    let bandwidth = node1.isl_bandwidth_gbps.min(node2.isl_bandwidth_gbps);
-                    let latency = (distance / Self::SPEED_OF_LIGHT_KM_S) * 1000.0;
-
-                    let link1 = ISLLink {
-                        source_id: id1.clone(),
-                        target_id: id2.clone(),
-                        distance_km: distance,
-                        bandwidth_gbps: bandwidth,
-                        latency_ms: latency,
-                        link_type: LinkType::Optical,
-                        active: true,
-                    };
-
-                    let link2 = ISLLink {
-                        source_id: id2.clone(),
-                        target_id: id1.clone(),
-                        distance_km: distance,
-                        bandwidth_gbps: bandwidth,
-                        latency_ms: latency,
-                        link_type: LinkType::Optical,
-                        active: true,
-                    };
-
-                    self.links.insert(format!("{}-{}", id1, id2), link1);
-                    self.links.insert(format!("{}-{}", id2, id1), link2);
-
-                    self.adjacency.get_mut(id1).unwrap().insert(id2.clone());
-                    self.adjacency.get_mut(id2).unwrap().insert(id1.clone());
+                self.evaluate_link(&node_ids[i], &node_ids[j]);
+            }
+        }
+
+        for node_id in &node_ids {
+            self.record_position(node_id);
+        }
+    }
+
+    /// Re-evaluate only the links touching the given nodes.
+    ///
+    /// Produces the same links as [`update_topology`](Self::update_topology)
+    /// provided no other node moved since the last update. Cost is
+    /// O(moved · n) against O(n²) for a full rebuild, plus removing the moved
+    /// nodes' old links first: on a 1000-node mesh, updating 1% of the
+    /// nodes is about 60× faster than a rebuild and the two break even at
+    /// about half the nodes (see `benches/topology.rs`). Unknown IDs are
+    /// ignored.
+    pub fn update_topology_incremental(&mut self, moved: &[&str]) {
+        let moved: HashSet<&str> = moved
+            .iter()
+            .copied()
+            .filter(|id| self.nodes.contains_key(*id))
+            .collect();
+        let node_ids: Vec<String> = self.nodes.keys().cloned().collect();
+
+        for &id in &moved {
+            let neighbors = std::mem::take(self.adjacency.get_mut(id).unwrap());
+            for neighbor in neighbors {
+                self.links.remove(&format!("{}-{}", id, neighbor));
+                self.links.remove(&format!("{}-{}", neighbor, id));
+                self.adjacency.get_mut(&neighbor).unwrap().remove(id);
+            }
+        }
+
+        for &id1 in &moved {
+            for id2 in &node_ids {
+                // Pairs of moved nodes are visited once, from the smaller ID
+                if id1 == id2 || (moved.contains(id2.as_str()) && id2.as_str() < id1) {
+                    continue;
                 }
+                self.evaluate_link(id1, id2);
             }
+            self.record_position(id1);
+        }
+    }
+
+    /// Advance every node along its circular orbit and update the topology.
+    ///
+    /// Nodes whose position has drifted more than the topology tolerance
+    /// since their links were last evaluated are re-evaluated incrementally,
+    /// falling back to a full rebuild once more than half of the nodes have
+    /// moved, where the rebuild becomes the cheaper of the two.
+    /// With the default tolerance of zero every node counts as moved, which is
+    /// exact but always rebuilds; see
+    /// [`with_topology_tolerance_km`](Self::with_topology_tolerance_km).
+    pub fn advance(&mut self, dt_seconds: f64) {
+        for node in self.nodes.values_mut() {
+            let r = EARTH_RADIUS_MEAN_KM + node.orbit_altitude_km;
+            let mean_motion_deg_s = (EARTH_MU / r.powi(3)).sqrt().to_degrees();
+            node.mean_anomaly_deg =
+                (node.mean_anomaly_deg + mean_motion_deg_s * dt_seconds).rem_euclid(360.0);
+        }
+
+        let moved: Vec<String> = self
+            .nodes
+            .values()
+            .filter(|node| {
+                !self
+                    .evaluated_positions
+                    .get(&node.node_id)
+                    .is_some_and(|last| {
+                        distance(*last, node_position(node)) <= self.topology_tolerance_km
+                    })
+            })
+            .map(|node| node.node_id.clone())
+            .collect();
+        if moved.len() as f64 > self.nodes.len() as f64 * INCREMENTAL_MAX_MOVED_FRACTION {
+            self.update_topology();
+        } else {
+            let moved: Vec<&str> = moved.iter().map(String::as_str).collect();
+            self.update_topology_incremental(&moved);
+        }
+    }
+
+    /// Let [`advance`](Self::advance) skip nodes that have drifted less than
+    /// `tolerance_km` since their links were last evaluated.
+    ///
+    /// Link distances and latencies may then be stale by up to twice the
+    /// tolerance, and links near the range limit may appear or drop late.
+    pub fn with_topology_tolerance_km(mut self, tolerance_km: f64) -> Self {
+        self.topology_tolerance_km = tolerance_km.max(0.0);
+        self
+    }
+
+    /// Add or drop the link between two nodes based on their current positions.
+    fn evaluate_link(&mut self, id1: &str, id2: &str) {
+        let node1 = &self.nodes[id1];
+        let node2 = &self.nodes[id2];
+
+        let distance = self.calculate_distance(node1, node2);
+        let max_range = node1.isl_range_km.min(node2.isl_range_km);
+
+        if distance <= max_range && self.has_line_of_sight(node1, node2) {
+            let bandwidth = node1.isl_bandwidth_gbps.min(node2.isl_bandwidth_gbps);
+            let latency = (distance / Self::SPEED_OF_LIGHT_KM_S) * 1000.0;
+
+            let link1 = ISLLink {
+                source_id: id1.to_string(),
+                target_id: id2.to_string(),
+                distance_km: distance,
+                bandwidth_gbps: bandwidth,
+                latency_ms: latency,
+                link_type: LinkType::Optical,
+                active: true,
+            };
+
+            let link2 = ISLLink {
+                source_id: id2.to_string(),
+                target_id: id1.to_string(),
+                distance_km: distance,
+                bandwidth_gbps: bandwidth,
+                latency_ms: latency,
+                link_type: LinkType::Optical,
+                active: true,
+            };
+
+            self.links.insert(format!("{}-{}", id1, id2), link1);
+            self.links.insert(format!("{}-{}", id2, id1), link2);
+
+            self.adjacency.get_mut(id1).unwrap().insert(id2.to_string());
+            self.adjacency.get_mut(id2).unwrap().insert(id1.to_string());
         }
     }
 
+    fn record_position(&mut self, node_id: &str) {
+        let position = node_position(&self.nodes[node_id]);
+        self.evaluated_positions
+            .insert(node_id.to_string(), position);
+    }
+
     /// Find optimal route between two nodes.
     pub fn find_route(&self, source_id: &str, destination_id: &str) -> Route {
+        let path = self.shortest_path(source_id, destination_id, |link| link.latency_ms);
+        self.build_route(source_id, destination_id, path)
+    }
+
+    /// Route a sequence of flows, steering later flows around loaded links.
+    ///
+    /// Flows are routed in order. Each link's cost is its latency scaled by
+    /// `1 / (1 - utilization)` after adding the flow, so paths get more
+    /// expensive as they approach `bandwidth_gbps`; a link the flow would push
+    /// past capacity is only used if no alternative exists. The accumulated
+    /// load is kept on the mesh and reported by [`get_mesh_stats`](Self::get_mesh_stats).
+    ///
+    /// # Arguments
+    ///
+    /// * `flows` - (source, destination, Gbps) for each flow
+    ///
+    /// # Returns
+    ///
+    /// One route per flow, in the same order. Unroutable flows get an
+    /// invalid (empty) route and add no load.
+    pub fn route_with_load(&mut self, flows: &[(&str, &str, f64)]) -> Vec<Route> {
+        let mut routes = Vec::with_capacity(flows.len());
+
+        for &(source_id, destination_id, gbps) in flows {
+            let loads = &self.link_loads;
+            let path = self.shortest_path(source_id, destination_id, |link| {
+                let load = loads
+                    .get(&link_key(&link.source_id, &link.target_id))
+                    .copied()
+                    .unwrap_or(0.0);
+                let utilization = (load + gbps) / link.bandwidth_gbps;
+                if utilization < 1.0 {
+                    link.latency_ms / (1.0 - utilization).max(Self::MIN_HEADROOM)
+                } else {
+                    link.latency_ms * Self::SATURATED_LINK_PENALTY
+                }
+            });
+
+            if let Some(ref path) = path {
+                for hop in path.windows(2) {
+                    *self
+                        .link_loads
+                        .entry(link_key(&hop[0], &hop[1]))
+                        .or_insert(0.0) += gbps;
+                }
+            }
+            routes.push(self.build_route(source_id, destination_id, path));
+        }
+
+        routes
+    }
+
+    /// Clear link loads accumulated by [`route_with_load`](Self::route_with_load).
+    pub fn clear_link_loads(&mut self) {
+        self.link_loads.clear();
+    }
+
+    /// Dijkstra over active links with a caller-supplied link cost.
+    fn shortest_path(
+        &self,
+        source_id: &str,
+        destination_id: &str,
+        link_cost: impl Fn(&ISLLink) -> f64,
+    ) -> Option<Vec<String>> {
         if !self.nodes.contains_key(source_id) || !self.nodes.contains_key(destination_id) {
-            return Route {
-                source_id: source_id.to_string(),
-                destination_id: destination_id.to_string(),
-                path: vec![],
-                total_distance_km: 0.0,
-                total_latency_ms: 0.0,
-                min_bandwidth_gbps: 0.0,
-                num_hops: 0,
-            };
+            return None;
         }
 
         if source_id == destination_id {
-            return Route {
-                source_id: source_id.to_string(),
-                destination_id: destination_id.to_string(),
-                path: vec![source_id.to_string()],
-                total_distance_km: 0.0,
-                total_latency_ms: 0.0,
-                min_bandwidth_gbps: f64::INFINITY,
-                num_hops: 0,
-            };
+            return Some(vec![source_id.to_string()]);
         }
 
         let mut distances: HashMap<String, f64> = self.nodes.keys().map(|k| (k.clone(), f64::INFINITY)).collect();
@@ -262,7 +448,7 @@ impl SpaceMesh {
                             continue;
                         }
 
-                        let new_cost = cost + link.latency_ms;
+                        let new_cost = cost + link_cost(link);
                         if new_cost < *distances.get(neighbor_id).unwrap_or(&f64::INFINITY) {
                             distances.insert(neighbor_id.clone(), new_cost);
                             predecessors.insert(neighbor_id.clone(), Some(node_id.clone()));
@@ -277,15 +463,7 @@ impl SpaceMesh {
         }
 
         if distances.get(destination_id).unwrap_or(&f64::INFINITY) == &f64::INFINITY {
-            return Route {
-                source_id: source_id.to_string(),
-                destination_id: destination_id.to_string(),
-                path: vec![],
-                total_distance_km: 0.0,
-                total_latency_ms: 0.0,
-                min_bandwidth_gbps: 0.0,
-                num_hops: 0,
-            };
+            return None;
         }
 
         let mut path = Vec::new();
@@ -295,13 +473,37 @@ impl SpaceMesh {
             current = predecessors.get(id).and_then(|p| p.clone());
         }
         path.reverse();
+        Some(path)
+    }
+
+    /// Summarize a path as a route; `None` yields an invalid route.
+    fn build_route(
+        &self,
+        source_id: &str,
+        destination_id: &str,
+        path: Option<Vec<String>>,
+    ) -> Route {
+        let path = match path {
+            Some(path) => path,
+            None => {
+                return Route {
+                    source_id: source_id.to_string(),
+                    destination_id: destination_id.to_string(),
+                    path: vec![],
+                    total_distance_km: 0.0,
+                    total_latency_ms: 0.0,
+                    min_bandwidth_gbps: 0.0,
+                    num_hops: 0,
+                }
+            }
+        };
 
         let mut total_distance = 0.0;
         let mut total_latency = 0.0;
         let mut min_bandwidth = f64::INFINITY;
 
-        for i in 0..(path.len() - 1) {
-            let link_key = format!("{}-{}", path[i], path[i + 1]);
+        for hop in path.windows(2) {
+            let link_key = format!("{}-{}", hop[0], hop[1]);
             if let Some(link) = self.links.get(&link_key) {
                 total_distance += link.distance_km;
                 total_latency += link.latency_ms;
@@ -309,14 +511,21 @@ impl SpaceMesh {
             }
         }
 
+        // A single-node path keeps infinite bandwidth (no link constrains it)
+        let min_bandwidth_gbps = if path.len() < 2 || min_bandwidth.is_finite() {
+            min_bandwidth
+        } else {
+            0.0
+        };
+
         Route {
             source_id: source_id.to_string(),
             destination_id: destination_id.to_string(),
+            num_hops: path.len().saturating_sub(1),
             path,
             total_distance_km: (total_distance * 100.0).round() / 100.0,
             total_latency_ms: (total_latency * 1000.0).round() / 1000.0,
-            min_bandwidth_gbps: if min_bandwidth == f64::INFINITY { 0.0 } else { min_bandwidth },
-            num_hops: 0, // Will be set below
+            min_bandwidth_gbps,
         }
     }
 
@@ -341,41 +550,69 @@ impl SpaceMesh {
         stats.insert("total_nodes".to_string(), self.nodes.len() as f64);
         stats.insert("active_links".to_string(), num_links as f64);
         stats.insert("avg_links_per_node".to_string(), (avg_links * 100.0).round() / 100.0);
+
+        // Per-link utilization from route_with_load, keyed "utilization:<a>-<b>"
+        let mut max_utilization: f64 = 0.0;
+        for (key, load) in &self.link_loads {
+            if let Some(link) = self.links.get(key) {
+                let utilization = load / link.bandwidth_gbps;
+                max_utilization = max_utilization.max(utilization);
+                stats.insert(
+                    format!("utilization:{}", key),
+                    (utilization * 1000.0).round() / 1000.0,
+                );
+            }
+        }
+        if !self.link_loads.is_empty() {
+            stats.insert(
+                "max_link_utilization".to_string(),
+                (max_utilization * 1000.0).round() / 1000.0,
+            );
+        }
         stats
     }
 
     fn calculate_distance(&self, node1: &OrbitalNode, node2: &OrbitalNode) -> f64 {
-        let r1 = Self::EARTH_RADIUS_KM + node1.orbit_altitude_km;
-        let r2 = Self::EARTH_RADIUS_KM + node2.orbit_altitude_km;
-
-        let theta1 = node1.mean_anomaly_deg.to_radians();
-        let theta2 = node2.mean_anomaly_deg.to_radians();
-
-        let inc1 = node1.orbit_inclination_deg.to_radians();
-        let inc2 = node2.orbit_inclination_deg.to_radians();
-
-        let raan1 = node1.raan_deg.to_radians();
-        let raan2 = node2.raan_deg.to_radians();
-
-        let x1 = r1 * (raan1.cos() * theta1.cos() - raan1.sin() * theta1.sin() * inc1.cos());
-        let y1 = r1 * (raan1.sin() * theta1.cos() + raan1.cos() * theta1.sin() * inc1.cos());
-        let z1 = r1 * theta1.sin() * inc1.sin();
-
-        let x2 = r2 * (raan2.cos() * theta2.cos() - raan2.sin() * theta2.sin() * inc2.cos());
-        let y2 = r2 * (raan2.sin() * theta2.cos() + raan2.cos() * theta2.sin() * inc2.cos());
-        let z2 = r2 * theta2.sin() * inc2.sin();
-
-        ((x2 - x1).powi(2) + (y2 - y1).powi(2) + (z2 - z1).powi(2)).sqrt()
+        distance(node_position(node1), node_position(node2))
     }
 
     fn has_line_of_sight(&self, node1: &OrbitalNode, node2: &OrbitalNode) -> bool {
         let min_altitude = node1.orbit_altitude_km.min(node2.orbit_altitude_km);
         let distance = self.calculate_distance(node1, node2);
-        let max_los = 2.0 * ((Self::EARTH_RADIUS_KM + min_altitude).powi(2) - Self::EARTH_RADIUS_KM.powi(2)).sqrt();
+        let max_los = 2.0
+            * ((EARTH_RADIUS_MEAN_KM + min_altitude).powi(2) - EARTH_RADIUS_MEAN_KM.powi(2)).sqrt();
         distance <= max_los
     }
 }
 
+/// Inertial position of a node on its circular orbit (km).
+fn node_position(node: &OrbitalNode) -> [f64; 3] {
+    let r = EARTH_RADIUS_MEAN_KM + node.orbit_altitude_km;
+    let theta = node.mean_anomaly_deg.to_radians();
+    let inc = node.orbit_inclination_deg.to_radians();
+    let raan = node.raan_deg.to_radians();
+
+    [
+        r * (raan.cos() * theta.cos() - raan.sin() * theta.sin() * inc.cos()),
+        r * (raan.sin() * theta.cos() + raan.cos() * theta.sin() * inc.cos()),
+        r * theta.sin() * inc.sin(),
+    ]
+}
+
+/// Straight-line distance between two positions (km).
+fn distance(a: [f64; 3], b: [f64; 3]) -> f64 {
+    ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2) + (b[2] - a[2]).powi(2)).sqrt()
+}
+
+/// Direction-independent key for a link between two nodes.
+fn link_key(a: &str, b: &str) -> String {
+    if a < b {
+        format!("{}-{}", a, b)
+    } else {
+        format!("{}-{}", b, a)
+    }
+}
+
 /// Create a Walker constellation mesh.
 ///
 /// subhadipmitra@: Walker constellations are parameterized as i:t/p/f where:
@@ -439,4 +676,168 @@ mod tests {
         let stats = mesh.get_mesh_stats();
         assert_eq!(stats.get("total_nodes"), Some(&8.0));
     }
+
+    const STARLINK_TLES: &str = "STARLINK-1013
+1 44713U 19074A   24001.50000000  .00001000  00000-0  73000-4 0  9997
+2 44713  53.0535 150.0000 0001400  90.0000   0.0000 15.06398000 22008
+STARLINK-1014
+1 44714U 19074A   24001.50000000  .00001000  00000-0  73000-4 0  9998
+2 44714  53.0535 150.0000 0001400  90.0000  20.0000 15.06398000 22001
+STARLINK-1015
+1 44715U 19074A   24001.50000000  .00001000  00000-0  73000-4 0  9999
+2 44715  53.0535 150.0000 0001400  90.0000  40.0000 15.06398000 22004
+STARLINK-1016
+1 44716U 19074A   24001.50000000  .00001000  00000-0  73000-4 0  9990
+2 44716  53.0535 170.0000 0001400  90.0000  10.0000 15.06398000 22004
+STARLINK-1017
+1 44717U 19074A   24001.50000000  .00001000  00000-0  73000-4 0  9991
+2 44717  53.0535 150.0000 0001400  90.0000 180.0000 15.06398000 22001";
+
+    #[test]
+    fn test_mesh_from_tles() {
+        let mut mesh = SpaceMesh::new(5000.0);
+        for tle in rotastellar_intel::parse_tle(STARLINK_TLES) {
+            let node = OrbitalNode::from_tle(&tle);
+            assert!(node.orbit_altitude_km > 540.0 && node.orbit_altitude_km < 575.0);
+            // The mesh's geometry puts the node at the TLE's semi-major axis
+            let radius = rotastellar::Coordinate3D::from(node_position(&node)).magnitude();
+            assert!((radius - tle.semi_major_axis_km()).abs() < 1e-6);
+            mesh.add_node(node);
+        }
+        mesh.update_topology();
+
+        // In-plane neighbours 20° apart are ~2400 km away
+        let link = &mesh.links["44713-44714"];
+        assert!(
+            (link.distance_km - 2406.0).abs() < 50.0,
+            "{}",
+            link.distance_km
+        );
+        assert!(mesh.links.contains_key("44713-44716"));
+
+        // The satellite on the far side of the plane has no line of sight
+        assert!(mesh.adjacency["44717"].is_empty());
+        assert!(mesh.find_route("44713", "44715").is_valid());
+        assert_eq!(mesh.get_mesh_stats().get("total_nodes"), Some(&5.0));
+    }
+
+    #[test]
+    fn test_route_with_load_avoids_saturated_path() {
+        let mut mesh = SpaceMesh::new(3000.0);
+        for (id, raan, anomaly) in [
+            ("a", 0.0, 0.0),
+            ("b", 0.0, 20.0),
+            ("c", 10.0, 14.0),
+            ("d", 0.0, 40.0),
+        ] {
+            let mut node = OrbitalNode::new(id).with_orbit(raan, anomaly);
+            node.isl_range_km = 3000.0;
+            mesh.add_node(node);
+        }
+        mesh.update_topology();
+        assert!(!mesh.links.contains_key("a-d"));
+
+        let primary = mesh.find_route("a", "d");
+        assert_eq!(primary.path, vec!["a", "b", "d"]);
+
+        let routes = mesh.route_with_load(&[("a", "d", 8.0), ("a", "d", 8.0)]);
+        assert_eq!(routes[0].path, vec!["a", "b", "d"]);
+        assert_eq!(routes[1].path, vec!["a", "c", "d"]);
+
+        let stats = mesh.get_mesh_stats();
+        assert_eq!(stats.get("utilization:a-b"), Some(&0.8));
+        assert_eq!(stats.get("utilization:a-c"), Some(&0.8));
+        assert_eq!(stats.get("max_link_utilization"), Some(&0.8));
+    }
+
+    fn link_set(mesh: &SpaceMesh) -> Vec<(String, f64)> {
+        let mut links: Vec<(String, f64)> = mesh
+            .links
+            .iter()
+            .map(|(key, link)| (key.clone(), link.distance_km))
+            .collect();
+        links.sort_by(|a, b| a.0.cmp(&b.0));
+        links
+    }
+
+    #[test]
+    fn test_route_num_hops() {
+        let mut mesh = SpaceMesh::new(3000.0);
+        for (id, raan, anomaly) in [
+            ("a", 0.0, 0.0),
+            ("b", 0.0, 20.0),
+            ("d", 0.0, 40.0),
+            ("far", 90.0, 180.0),
+        ] {
+            let mut node = OrbitalNode::new(id).with_orbit(raan, anomaly);
+            node.isl_range_km = 3000.0;
+            mesh.add_node(node);
+        }
+        mesh.update_topology();
+
+        assert_eq!(mesh.find_route("a", "b").num_hops, 1);
+        let route = mesh.find_route("a", "d");
+        assert_eq!(route.path, vec!["a", "b", "d"]);
+        assert_eq!(route.num_hops, 2);
+        assert_eq!(mesh.find_route("a", "a").num_hops, 0);
+
+        let unreachable = mesh.find_route("a", "far");
+        assert!(!unreachable.is_valid());
+        assert_eq!(unreachable.num_hops, 0);
+    }
+
+    #[test]
+    fn test_incremental_matches_full_rebuild() {
+        let mut incremental = create_constellation("test", 6, 12, 550.0, 53.0, 3000.0);
+        let moved = ["test_P0_S0", "test_P2_S5", "test_P2_S6", "test_P5_S11"];
+        for id in moved {
+            let node = incremental.nodes.get_mut(id).unwrap();
+            node.mean_anomaly_deg += 17.0;
+            node.raan_deg += 4.0;
+        }
+        incremental.update_topology_incremental(&moved);
+
+        let mut full = SpaceMesh::new(3000.0);
+        for node in incremental.nodes.values() {
+            full.add_node(node.clone());
+        }
+        full.update_topology();
+
+        assert_eq!(link_set(&incremental), link_set(&full));
+        assert_eq!(incremental.adjacency, full.adjacency);
+
+        // With no tolerance, advancing matches a full rebuild at the new positions
+        incremental.advance(120.0);
+        let mut full = SpaceMesh::new(3000.0);
+        for node in incremental.nodes.values() {
+            full.add_node(node.clone());
+        }
+        full.update_topology();
+        assert_eq!(link_set(&incremental), link_set(&full));
+
+        // A large tolerance leaves links untouched for small steps
+        let mut tolerant = incremental.with_topology_tolerance_km(100.0);
+        let before = link_set(&tolerant);
+        tolerant.advance(1.0);
+        assert_eq!(link_set(&tolerant), before);
+    }
+
+    #[test]
+    fn test_link_wire_format() {
+        // Mirrors the Python SDK's ISLLink serialization
+        let fixture = r#"{"source_id":"test_P0_S0","target_id":"test_P0_S1","distance_km":4200.5,"bandwidth_gbps":10.0,"latency_ms":14.01,"link_type":"optical","active":true}"#;
+        let link: ISLLink = serde_json::from_str(fixture).unwrap();
+        assert_eq!(link.link_type, LinkType::Optical);
+
+        let round_trip: serde_json::Value = serde_json::to_value(&link).unwrap();
+        assert_eq!(
+            round_trip,
+            serde_json::from_str::<serde_json::Value>(fixture).unwrap()
+        );
+        assert_eq!(serde_json::to_string(&LinkType::Rf).unwrap(), r#""rf""#);
+        assert_eq!(
+            serde_json::to_string(&LinkType::Hybrid).unwrap(),
+            r#""hybrid""#
+        );
+    }
 }
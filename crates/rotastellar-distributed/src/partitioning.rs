@@ -160,6 +160,22 @@ pub struct LayerPlacement {
     pub node_id: Option<String>,
     pub estimated_latency_ms: f64,
     pub data_transfer_bytes: u64,
+    /// Part of `estimated_latency_ms` spent moving data to this layer
+    #[serde(default)]
+    pub transfer_latency_ms: f64,
+}
+
+/// One layer's slot in the execution timeline of a plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineEntry {
+    /// Layer name
+    pub layer_name: String,
+    /// Where the layer runs
+    pub location: PlacementLocation,
+    /// When the layer starts computing, after any incoming transfer
+    pub start_ms: f64,
+    /// When the layer finishes
+    pub end_ms: f64,
 }
 
 /// Complete partitioning plan.
@@ -183,6 +199,53 @@ impl PartitionPlan {
     pub fn orbital_layers(&self) -> Vec<&LayerPlacement> {
         self.placements.iter().filter(|p| p.location == PlacementLocation::Orbital).collect()
     }
+
+    /// Execution timeline for rendering as a Gantt chart.
+    ///
+    /// Layers run back to back; a transfer into a layer shows up as a gap
+    /// before its entry. The last entry ends at `total_latency_ms`.
+    pub fn timeline(&self) -> Vec<TimelineEntry> {
+        let mut cursor = 0.0;
+        self.placements
+            .iter()
+            .map(|placement| {
+                let start_ms = cursor + placement.transfer_latency_ms;
+                cursor += placement.estimated_latency_ms;
+                TimelineEntry {
+                    layer_name: placement.layer_name.clone(),
+                    location: placement.location,
+                    start_ms,
+                    end_ms: cursor,
+                }
+            })
+            .collect()
+    }
+
+    /// Time spent computing across all layers.
+    pub fn compute_ms(&self) -> f64 {
+        self.placements
+            .iter()
+            .map(|p| p.estimated_latency_ms - p.transfer_latency_ms)
+            .sum()
+    }
+
+    /// Time spent moving data between segments.
+    pub fn transfer_ms(&self) -> f64 {
+        self.placements.iter().map(|p| p.transfer_latency_ms).sum()
+    }
+
+    /// Length of the critical path through the pipeline.
+    ///
+    /// Segments run strictly in sequence, so every layer and transfer is on
+    /// the critical path.
+    pub fn critical_path_ms(&self) -> f64 {
+        self.compute_ms() + self.transfer_ms()
+    }
+
+    /// Whether transfers rather than compute dominate the critical path.
+    pub fn is_transfer_bound(&self) -> bool {
+        self.transfer_ms() > self.compute_ms()
+    }
 }
 
 /// Optimize model partitioning.
@@ -285,12 +348,14 @@ impl PartitionOptimizer {
 
             let mut layer_latency_ms = (layer.flops as f64 / (compute_tflops * 1e12)) * 1000.0;
             let mut transfer_bytes: u64 = 0;
+            let mut transfer_latency_ms = 0.0;
 
             if i == split_idx && split_idx > 0 && split_idx < model.layers.len() {
                 transfer_bytes = layer.input_size;
                 let transfer_latency = (transfer_bytes as f64 * 8.0) / (self.uplink_bandwidth_mbps * 1e6) * 1000.0;
                 let propagation = (self.orbit_altitude_km / 299792.458) * 1000.0;
-                layer_latency_ms += transfer_latency + propagation;
+                transfer_latency_ms = transfer_latency + propagation;
+                layer_latency_ms += transfer_latency_ms;
                 total_transfer += transfer_bytes;
                 num_transfers += 1;
             }
@@ -301,6 +366,7 @@ impl PartitionOptimizer {
                 node_id: None,
                 estimated_latency_ms: layer_latency_ms,
                 data_transfer_bytes: transfer_bytes,
+                transfer_latency_ms,
             });
 
             total_latency_ms += layer_latency_ms;
@@ -337,4 +403,31 @@ mod tests {
         assert!(!plan.placements.is_empty());
         assert!(plan.total_latency_ms > 0.0);
     }
+
+    #[test]
+    fn test_timeline() {
+        let model = ModelProfile::create_transformer(6, 768, 50000, 512);
+        let plan = PartitionOptimizer::default().optimize(&model, OptimizationObjective::Balance);
+
+        let timeline = plan.timeline();
+        assert_eq!(timeline.len(), plan.placements.len());
+        assert!((timeline.last().unwrap().end_ms - plan.total_latency_ms).abs() < 1e-9);
+        assert!((plan.critical_path_ms() - plan.total_latency_ms).abs() < 1e-9);
+        assert!(timeline.windows(2).all(|w| w[1].start_ms >= w[0].end_ms));
+
+        // A thin uplink makes the split transfer dominate
+        let mut optimizer = PartitionOptimizer::new(10.0, 10.0);
+        optimizer.uplink_bandwidth_mbps = 0.1;
+        let plan = optimizer.optimize(&model, OptimizationObjective::Balance);
+        assert_eq!(plan.ground_orbital_transfers, 1);
+        assert!(plan.is_transfer_bound());
+
+        let timeline = plan.timeline();
+        let largest_gap = timeline
+            .windows(2)
+            .map(|w| w[1].start_ms - w[0].end_ms)
+            .fold(0.0, f64::max);
+        assert!(largest_gap > 0.9 * plan.transfer_ms(), "{}", largest_gap);
+        assert!((timeline.last().unwrap().end_ms - plan.total_latency_ms).abs() < 1e-6);
+    }
 }
@@ -12,26 +12,47 @@
 //! - GMAT or STK for precise pass predictions
 //! - AWS Ground Station or Azure Orbital for actual antenna scheduling
 
+use rotastellar::constants::{EARTH_MU, EARTH_RADIUS_MEAN_KM};
+use rotastellar::{Position, ValidationError};
+use rotastellar_intel::SatellitePass;
 use serde::{Deserialize, Serialize};
-use std::collections::BinaryHeap;
 use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, VecDeque};
 
 // TODO(subhadipmitra): Add actual pass prediction using SGP4
 // TODO: Integrate with ground station APIs (AWS/Azure/KSAT)
 // NOTE: Orbital period calculation assumes circular orbit (good enough for LEO)
 
 /// Priority level for sync operations.
+///
+/// Serialized in snake_case (`"critical"`, `"high"`, `"normal"`, `"low"`) to
+/// match the Python and TypeScript SDKs; the numeric values are only used for
+/// ordering.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Priority {
+    /// Must be sent at the next opportunity
     Critical = 0,
+    /// Sent ahead of routine traffic
     High = 1,
+    /// Routine traffic
     Normal = 2,
+    /// Sent only when capacity is left over
     Low = 3,
 }
 
-/// Ground station configuration.
+pub use rotastellar_intel::GroundStation;
+
+/// Bandwidth assumed for stations that do not specify one (Mbps)
+const DEFAULT_BANDWIDTH_MBPS: f64 = 100.0;
+
+/// Flat ground station configuration.
+///
+/// Superseded by [`GroundStation`], which is shared with pass prediction in
+/// `rotastellar-intel`. Kept so existing configurations can be converted with
+/// `TryFrom` during the transition.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GroundStation {
+pub struct GroundStationConfig {
     pub name: String,
     pub latitude: f64,
     pub longitude: f64,
@@ -40,15 +61,15 @@ pub struct GroundStation {
     pub min_elevation_deg: f64,
 }
 
-impl GroundStation {
-    /// Create a new ground station.
+impl GroundStationConfig {
+    /// Create a new ground station configuration.
     pub fn new(name: &str, lat: f64, lon: f64) -> Self {
         Self {
             name: name.to_string(),
             latitude: lat,
             longitude: lon,
             elevation_m: 0.0,
-            bandwidth_mbps: 100.0,
+            bandwidth_mbps: DEFAULT_BANDWIDTH_MBPS,
             min_elevation_deg: 5.0,
         }
     }
@@ -69,6 +90,36 @@ impl GroundStation {
     }
 }
 
+/// Fails if the latitude or longitude is out of range.
+impl TryFrom<GroundStationConfig> for GroundStation {
+    type Error = ValidationError;
+
+    fn try_from(config: GroundStationConfig) -> Result<Self, Self::Error> {
+        let position = Position::new(
+            config.latitude,
+            config.longitude,
+            config.elevation_m / 1000.0,
+        )?;
+        Ok(
+            GroundStation::new(config.name, position, Some(config.min_elevation_deg))
+                .with_bandwidth(config.bandwidth_mbps),
+        )
+    }
+}
+
+impl From<&GroundStation> for GroundStationConfig {
+    fn from(station: &GroundStation) -> Self {
+        Self {
+            name: station.name.clone(),
+            latitude: station.position.latitude,
+            longitude: station.position.longitude,
+            elevation_m: station.position.altitude_km * 1000.0,
+            bandwidth_mbps: station.bandwidth_mbps.unwrap_or(DEFAULT_BANDWIDTH_MBPS),
+            min_elevation_deg: station.min_elevation_deg,
+        }
+    }
+}
+
 /// A sync task.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncTask {
@@ -77,6 +128,9 @@ pub struct SyncTask {
     pub data_size_bytes: u64,
     pub priority: Priority,
     pub description: String,
+    /// Submission order within the queue (not serialized)
+    #[serde(skip)]
+    pub sequence: u64,
 }
 
 impl PartialEq for SyncTask {
@@ -105,6 +159,9 @@ impl Ord for SyncTask {
 pub struct PriorityQueue {
     heap: BinaryHeap<SyncTask>,
     counter: u64,
+    node_quota: Option<usize>,
+    /// Node served last by a fair drain, per priority tier
+    fair_cursor: [Option<String>; 4],
 }
 
 impl PriorityQueue {
@@ -113,6 +170,14 @@ impl PriorityQueue {
         Self::default()
     }
 
+    /// Create a queue that limits how many tasks each node gets per fair drain.
+    pub fn with_node_quota(max_per_node_per_drain: usize) -> Self {
+        Self {
+            node_quota: Some(max_per_node_per_drain),
+            ..Self::default()
+        }
+    }
+
     /// Add a task to the queue.
     pub fn add_task(&mut self, node_id: &str, data_size_bytes: u64, priority: Priority, description: &str) -> String {
         self.counter += 1;
@@ -123,6 +188,7 @@ impl PriorityQueue {
             data_size_bytes,
             priority,
             description: description.to_string(),
+            sequence: self.counter,
         };
         self.heap.push(task);
         task_id
@@ -133,6 +199,70 @@ impl PriorityQueue {
         self.heap.pop()
     }
 
+    /// Pop up to `n` tasks, round-robin across nodes within each priority tier.
+    ///
+    /// Higher tiers are still drained first, but within a tier no node gets a
+    /// second task until every other node with pending work has had one. Each
+    /// drain resumes the rotation after the node served last, so repeated
+    /// small drains still reach every node. With a node quota set, each node
+    /// is capped at that many tasks per drain. Tasks not drained remain queued.
+    pub fn drain_fair(&mut self, n: usize) -> Vec<SyncTask> {
+        let mut tiers: Vec<Vec<SyncTask>> = vec![Vec::new(); 4];
+        for task in self.heap.drain() {
+            tiers[task.priority as usize].push(task);
+        }
+
+        let mut drained = Vec::new();
+        let mut taken_per_node: HashMap<String, usize> = HashMap::new();
+        let mut remaining = Vec::new();
+
+        for (tier_index, mut tier) in tiers.into_iter().enumerate() {
+            // Group by node, keeping submission order within each node
+            let mut by_node: BTreeMap<String, VecDeque<SyncTask>> = BTreeMap::new();
+            tier.sort_by_key(|t| t.sequence);
+            for task in tier {
+                by_node
+                    .entry(task.node_id.clone())
+                    .or_default()
+                    .push_back(task);
+            }
+
+            // Start after the node this tier served last
+            let mut order: Vec<String> = by_node.keys().cloned().collect();
+            if let Some(last) = &self.fair_cursor[tier_index] {
+                let start = order.partition_point(|node_id| node_id <= last);
+                order.rotate_left(start);
+            }
+
+            loop {
+                let mut progressed = false;
+                for node_id in &order {
+                    if drained.len() >= n {
+                        break;
+                    }
+                    let taken = taken_per_node.entry(node_id.clone()).or_insert(0);
+                    if self.node_quota.is_some_and(|quota| *taken >= quota) {
+                        continue;
+                    }
+                    if let Some(task) = by_node.get_mut(node_id).and_then(VecDeque::pop_front) {
+                        drained.push(task);
+                        *taken += 1;
+                        progressed = true;
+                        self.fair_cursor[tier_index] = Some(node_id.clone());
+                    }
+                }
+                if !progressed || drained.len() >= n {
+                    break;
+                }
+            }
+
+            remaining.extend(by_node.into_values().flatten());
+        }
+
+        self.heap.extend(remaining);
+        drained
+    }
+
     /// Peek at the highest priority task.
     pub fn peek_task(&self) -> Option<&SyncTask> {
         self.heap.peek()
@@ -165,7 +295,10 @@ pub struct SyncScheduler {
 impl Default for SyncScheduler {
     fn default() -> Self {
         Self {
-            ground_stations: GroundStation::default_network(),
+            ground_stations: GroundStationConfig::default_network()
+                .into_iter()
+                .map(|config| GroundStation::try_from(config).expect("built-in stations are valid"))
+                .collect(),
             orbit_altitude_km: 550.0,
             orbit_inclination_deg: 51.6,
             queue: PriorityQueue::new(),
@@ -179,12 +312,24 @@ impl SyncScheduler {
         Self::default()
     }
 
+    /// Use the given ground stations instead of the default network.
+    pub fn with_ground_stations(mut self, ground_stations: Vec<GroundStation>) -> Self {
+        self.ground_stations = ground_stations;
+        self
+    }
+
+    /// Bytes that can be transferred to a station during a pass.
+    ///
+    /// Stations without a bandwidth are assumed to have 100 Mbps.
+    pub fn pass_capacity_bytes(&self, station: &GroundStation, pass: &SatellitePass) -> u64 {
+        let bandwidth_mbps = station.bandwidth_mbps.unwrap_or(DEFAULT_BANDWIDTH_MBPS);
+        (bandwidth_mbps * 1e6 / 8.0 * pass.duration_seconds().max(0.0)) as u64
+    }
+
     /// Orbital period in minutes.
     pub fn orbital_period_minutes(&self) -> f64 {
-        let earth_radius = 6371.0;
-        let earth_mu = 398600.4418;
-        let a = earth_radius + self.orbit_altitude_km;
-        let period_s = 2.0 * std::f64::consts::PI * (a.powi(3) / earth_mu).sqrt();
+        let a = EARTH_RADIUS_MEAN_KM + self.orbit_altitude_km;
+        let period_s = 2.0 * std::f64::consts::PI * (a.powi(3) / EARTH_MU).sqrt();
         period_s / 60.0
     }
 
@@ -211,6 +356,11 @@ impl SyncScheduler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rotastellar_intel::{FixedClock, Tracker, TLE};
+
+    const ISS_TLE: &str = r#"ISS (ZARYA)
+1 25544U 98067A   21275.52243902  .00001082  00000-0  27450-4 0  9999
+2 25544  51.6443 208.5943 0003631 355.3422 144.3824 15.48919755304818"#;
 
     #[test]
     fn test_priority_queue() {
@@ -231,4 +381,119 @@ mod tests {
         assert_eq!(scheduler.queue.size(), 1);
         assert!(scheduler.orbital_period_minutes() > 90.0);
     }
+
+    #[test]
+    fn test_drain_fair() {
+        let mut queue = PriorityQueue::with_node_quota(5);
+        for node in ["node-a", "node-b", "node-c"] {
+            for i in 0..10 {
+                queue.add_task(
+                    node,
+                    1000,
+                    Priority::Critical,
+                    &format!("{} task {}", node, i),
+                );
+            }
+        }
+        queue.add_task("node-d", 1000, Priority::Low, "low task");
+
+        let drained = queue.drain_fair(6);
+        assert_eq!(drained.len(), 6);
+        for node in ["node-a", "node-b", "node-c"] {
+            assert_eq!(drained.iter().filter(|t| t.node_id == node).count(), 2);
+        }
+        assert_eq!(queue.size(), 25);
+
+        // The quota caps each node even when the drain could take more
+        let drained = queue.drain_fair(100);
+        assert_eq!(drained.len(), 16);
+        assert_eq!(drained.last().unwrap().priority, Priority::Low);
+        assert_eq!(queue.size(), 9);
+    }
+
+    #[test]
+    fn test_drain_fair_rotates_across_drains() {
+        let mut queue = PriorityQueue::new();
+        let nodes = ["node-a", "node-b", "node-c", "node-d", "node-e"];
+        for node in nodes {
+            for i in 0..3 {
+                queue.add_task(
+                    node,
+                    1000,
+                    Priority::Normal,
+                    &format!("{} task {}", node, i),
+                );
+            }
+        }
+
+        let served: Vec<String> = (0..3)
+            .flat_map(|_| queue.drain_fair(2))
+            .map(|task| task.node_id)
+            .collect();
+        assert_eq!(
+            served,
+            ["node-a", "node-b", "node-c", "node-d", "node-e", "node-a"]
+        );
+
+        // Each node's tasks still come out in submission order
+        let first: Vec<u64> = queue
+            .drain_fair(5)
+            .iter()
+            .map(|task| task.sequence)
+            .collect();
+        assert_eq!(first, [5, 8, 11, 14, 3]);
+    }
+
+    #[test]
+    fn test_shared_ground_station() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let now = tle.epoch() + std::time::Duration::from_secs(12 * 3600);
+        let mut tracker = Tracker::new().with_clock(FixedClock::new(now));
+        tracker.add_tle("ISS", tle);
+
+        let position = Position::new(40.0, -105.0, 1.6).unwrap();
+        let station = GroundStation::new("Boulder", position, Some(5.0)).with_bandwidth(200.0);
+
+        // Passes are predicted from the tracker's clock, not the TLE epoch
+        let passes = tracker.predict_passes("ISS", &station, Some(24.0)).unwrap();
+        assert!(!passes.is_empty());
+        assert!(passes.iter().all(|pass| pass.aos >= now));
+
+        let scheduler = SyncScheduler::new().with_ground_stations(vec![station.clone()]);
+        let capacity = scheduler.pass_capacity_bytes(&scheduler.ground_stations[0], &passes[0]);
+        let expected = 200e6 / 8.0 * passes[0].duration_seconds();
+        assert!((capacity as f64 - expected).abs() < 1.0);
+
+        let config = GroundStationConfig::from(&station);
+        assert_eq!(config.bandwidth_mbps, 200.0);
+        let round_trip = GroundStation::try_from(config).unwrap();
+        assert_eq!(round_trip.position, station.position);
+        assert_eq!(round_trip.bandwidth_mbps, Some(200.0));
+
+        // Out-of-range coordinates are rejected, as Position::new does
+        let invalid = GroundStationConfig::new("Nowhere", 91.0, 0.0);
+        assert!(GroundStation::try_from(invalid).is_err());
+    }
+
+    #[test]
+    fn test_task_wire_format() {
+        // Mirrors the Python SDK's SyncTask serialization
+        let fixture = r#"{"task_id":"task_1","node_id":"orbital-1","data_size_bytes":1048576,"priority":"critical","description":"Upload gradients"}"#;
+        let task: SyncTask = serde_json::from_str(fixture).unwrap();
+        assert_eq!(task.priority, Priority::Critical);
+
+        let round_trip: serde_json::Value = serde_json::to_value(&task).unwrap();
+        assert_eq!(
+            round_trip,
+            serde_json::from_str::<serde_json::Value>(fixture).unwrap()
+        );
+        for (priority, wire) in [
+            (Priority::High, "high"),
+            (Priority::Normal, "normal"),
+            (Priority::Low, "low"),
+        ] {
+            assert_eq!(serde_json::to_value(priority).unwrap(), wire);
+        }
+    }
 }
@@ -0,0 +1,147 @@
+//! RotaStellar Intel - Atmosphere Models
+//!
+//! Density models used wherever drag is applied (reentry and decay).
+//!
+//! Upper-atmosphere density varies by an order of magnitude with solar
+//! activity, so drag results are only as good as the density profile behind
+//! them. Implement [`Atmosphere`] to supply a profile for specific conditions.
+
+/// Atmospheric density profile.
+pub trait Atmosphere: Send + Sync {
+    /// Mass density at the given geometric altitude in kg/m³.
+    fn density_kg_m3(&self, altitude_km: f64) -> f64;
+}
+
+/// Single-layer exponential atmosphere.
+///
+/// `ρ(h) = ρ₀ · exp(-(h - h₀) / H)`. The default is fitted between 120 and
+/// 200 km, which is where reentry decay spends most of its time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialAtmosphere {
+    /// Reference altitude h₀ (km)
+    pub reference_altitude_km: f64,
+    /// Density at the reference altitude ρ₀ (kg/m³)
+    pub reference_density_kg_m3: f64,
+    /// Scale height H (km)
+    pub scale_height_km: f64,
+}
+
+impl ExponentialAtmosphere {
+    /// Create an exponential atmosphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference_altitude_km` - Reference altitude
+    /// * `reference_density_kg_m3` - Density at the reference altitude
+    /// * `scale_height_km` - Scale height
+    pub fn new(
+        reference_altitude_km: f64,
+        reference_density_kg_m3: f64,
+        scale_height_km: f64,
+    ) -> Self {
+        Self {
+            reference_altitude_km,
+            reference_density_kg_m3,
+            scale_height_km,
+        }
+    }
+}
+
+impl Default for ExponentialAtmosphere {
+    fn default() -> Self {
+        Self::new(120.0, 2.438e-8, 17.9)
+    }
+}
+
+impl Atmosphere for ExponentialAtmosphere {
+    fn density_kg_m3(&self, altitude_km: f64) -> f64 {
+        self.reference_density_kg_m3
+            * (-(altitude_km - self.reference_altitude_km) / self.scale_height_km).exp()
+    }
+}
+
+/// Piecewise-exponential fit to the U.S. Standard Atmosphere 1976
+/// (CIRA-72 above 25 km), valid from sea level to 1000 km.
+///
+/// Represents moderate solar activity. Altitudes above 1000 km extrapolate the
+/// top layer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Us76Atmosphere;
+
+/// Layer base altitude (km), base density (kg/m³), and scale height (km)
+const US76_LAYERS: [(f64, f64, f64); 28] = [
+    (0.0, 1.225, 7.249),
+    (25.0, 3.899e-2, 6.349),
+    (30.0, 1.774e-2, 6.682),
+    (40.0, 3.972e-3, 7.554),
+    (50.0, 1.057e-3, 8.382),
+    (60.0, 3.206e-4, 7.714),
+    (70.0, 8.770e-5, 6.549),
+    (80.0, 1.905e-5, 5.799),
+    (90.0, 3.396e-6, 5.382),
+    (100.0, 5.297e-7, 5.877),
+    (110.0, 9.661e-8, 7.263),
+    (120.0, 2.438e-8, 9.473),
+    (130.0, 8.484e-9, 12.636),
+    (140.0, 3.845e-9, 16.149),
+    (150.0, 2.070e-9, 22.523),
+    (180.0, 5.464e-10, 29.740),
+    (200.0, 2.789e-10, 37.105),
+    (250.0, 7.248e-11, 45.546),
+    (300.0, 2.418e-11, 53.628),
+    (350.0, 9.518e-12, 53.298),
+    (400.0, 3.725e-12, 58.515),
+    (450.0, 1.585e-12, 60.828),
+    (500.0, 6.967e-13, 63.822),
+    (600.0, 1.454e-13, 71.835),
+    (700.0, 3.614e-14, 88.667),
+    (800.0, 1.170e-14, 124.64),
+    (900.0, 5.245e-15, 181.05),
+    (1000.0, 3.019e-15, 268.00),
+];
+
+impl Atmosphere for Us76Atmosphere {
+    fn density_kg_m3(&self, altitude_km: f64) -> f64 {
+        let altitude_km = altitude_km.max(0.0);
+        let (base, density, scale_height) = US76_LAYERS
+            .iter()
+            .rev()
+            .find(|(base, _, _)| altitude_km >= *base)
+            .copied()
+            .unwrap_or(US76_LAYERS[0]);
+        density * (-(altitude_km - base) / scale_height).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_us76_continuous_and_decreasing() {
+        let atmosphere = Us76Atmosphere;
+        assert!((atmosphere.density_kg_m3(0.0) - 1.225).abs() < 1e-9);
+
+        let mut previous = f64::INFINITY;
+        for altitude in (0..=1000).step_by(5) {
+            let density = atmosphere.density_kg_m3(altitude as f64);
+            assert!(
+                density < previous,
+                "density not decreasing at {} km",
+                altitude
+            );
+            previous = density;
+        }
+
+        // Layer boundaries agree with the layer below to within a few percent
+        for window in US76_LAYERS.windows(2) {
+            let (below, above) = (window[0], window[1]);
+            let extrapolated = below.1 * (-(above.0 - below.0) / below.2).exp();
+            assert!(
+                (extrapolated / above.1 - 1.0).abs() < 0.15,
+                "gap at {} km",
+                above.0
+            );
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! RotaStellar Intel - Time Sources
+//!
+//! Injectable clocks so time-dependent behavior can be tested deterministically.
+
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+/// Source of the current time.
+pub trait Clock: Send + Sync {
+    /// Get the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock backed by the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always reports the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl FixedClock {
+    /// Create a clock fixed at the given time.
+    pub fn new(time: DateTime<Utc>) -> Self {
+        Self(time)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fixed_clock() {
+        let time = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(time));
+        assert_eq!(clock.now(), time);
+        assert_eq!(Arc::clone(&clock).now(), time);
+    }
+}
@@ -13,16 +13,25 @@
 //! Miss distance alone is NOT sufficient - a 1km miss with high covariance
 //! uncertainty might be riskier than a 100m miss with low uncertainty.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use rotastellar::{Coordinate3D, ValidationError, EARTH_MU};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
 use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::clock::{Clock, SystemClock};
+use crate::tle::{Frame, PropagationAccuracy, Sgp4Propagator, StateVector};
+use crate::window::QueryWindow;
+use crate::TLE;
 
 // TODO(subhadipmitra): Add Monte Carlo Pc estimation
-// TODO: Integrate with Space-Track CDM (Conjunction Data Messages)
-// FIXME: Current Pc calculation assumes spherical covariance (simplification)
+// FIXME: Screening Pc assumes spherical covariance; use compute_pc_2d once
+// TLE-derived covariances are available
 
 /// Conjunction risk level classification.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum RiskLevel {
     /// Immediate action required (P > 1e-4)
@@ -32,14 +41,71 @@ pub enum RiskLevel {
     /// Standard monitoring (P > 1e-6)
     Medium,
     /// Routine tracking (P > 1e-7)
+    #[default]
     Low,
     /// No action needed (P <= 1e-7)
     Negligible,
 }
 
-impl Default for RiskLevel {
-    fn default() -> Self {
-        Self::Low
+impl RiskLevel {
+    /// Classify a probability of collision using the thresholds above.
+    pub fn from_probability(probability: f64) -> Self {
+        if probability > 1e-4 {
+            Self::Critical
+        } else if probability > 1e-5 {
+            Self::High
+        } else if probability > 1e-6 {
+            Self::Medium
+        } else if probability > 1e-7 {
+            Self::Low
+        } else {
+            Self::Negligible
+        }
+    }
+
+    /// Ordinal severity used for triage, from 5 (Critical) to 1 (Negligible).
+    fn severity(self) -> f64 {
+        match self {
+            Self::Critical => 5.0,
+            Self::High => 4.0,
+            Self::Medium => 3.0,
+            Self::Low => 2.0,
+            Self::Negligible => 1.0,
+        }
+    }
+
+    /// Classify a miss distance when no covariance is available for Pc,
+    /// using the default screening thresholds.
+    ///
+    /// Critical within 1 km, High within 5 km, Medium within 10 km, Low
+    /// within 25 km, Negligible beyond. These defaults are not taken from a
+    /// published standard; use
+    /// [`from_miss_distance_thresholds`](Self::from_miss_distance_thresholds)
+    /// to apply an operator's own limits.
+    pub fn from_miss_distance_km(miss_distance_km: f64) -> Self {
+        Self::from_miss_distance_thresholds(miss_distance_km, MISS_DISTANCE_THRESHOLDS_KM)
+    }
+
+    /// Classify a miss distance against custom thresholds.
+    ///
+    /// # Arguments
+    ///
+    /// * `miss_distance_km` - Miss distance at TCA
+    /// * `thresholds_km` - Miss distances at or below which the approach is
+    ///   Critical, High, Medium and Low, in that order
+    pub fn from_miss_distance_thresholds(miss_distance_km: f64, thresholds_km: [f64; 4]) -> Self {
+        let [critical, high, medium, low] = thresholds_km;
+        if miss_distance_km <= critical {
+            Self::Critical
+        } else if miss_distance_km <= high {
+            Self::High
+        } else if miss_distance_km <= medium {
+            Self::Medium
+        } else if miss_distance_km <= low {
+            Self::Low
+        } else {
+            Self::Negligible
+        }
     }
 }
 
@@ -70,8 +136,244 @@ impl std::fmt::Display for RiskLevel {
     }
 }
 
+/// Grid resolution (per axis) of the Pc integral over the hard-body disk
+const PC_INTEGRATION_STEPS: usize = 64;
+
+/// Covariance scale factors searched by `max_pc`, in decades either side of 1
+const MAX_PC_SCALE_DECADES: f64 = 4.0;
+/// Log-spaced samples of the scale factor before refining the maximum
+const MAX_PC_SCAN_STEPS: usize = 80;
+
+/// Allowed relative mismatch between miss components and the miss distance
+const MISS_DECOMPOSITION_TOLERANCE: f64 = 0.01;
+
+/// Default time-to-TCA over which triage urgency halves (hours)
+const TRIAGE_TIME_SCALE_HOURS: f64 = 24.0;
+
+/// Sampling step when screening for close approaches (seconds)
+const SCREENING_STEP_SECONDS: i64 = 10;
+/// Parabolic TCA refinement stops once the fit spacing is below this (seconds)
+const TCA_TOLERANCE_SECONDS: f64 = 1e-3;
+/// Position uncertainty assumed for screened TLE-derived states (km)
+const SCREENING_POSITION_SIGMA_KM: f64 = 1.0;
+/// Default miss distances (km) at or below which a screened approach without
+/// covariance is Critical, High, Medium and Low. Crate defaults, not a
+/// published standard.
+const MISS_DISTANCE_THRESHOLDS_KM: [f64; 4] = [1.0, 5.0, 10.0, 25.0];
+
+/// Encounter angles at or above this are head-on (degrees)
+const HEAD_ON_ANGLE_DEG: f64 = 150.0;
+/// Encounter angles at or below this are overtaking (degrees)
+const OVERTAKING_ANGLE_DEG: f64 = 30.0;
+
+/// Geometry of an encounter, from the angle between the two velocity vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncounterGeometry {
+    /// Near-opposing velocities; highest relative speed and impact energy
+    HeadOn,
+    /// Orbits crossing at an intermediate angle
+    Crossing,
+    /// Near-parallel velocities; a slow encounter where the short-encounter
+    /// Pc assumption breaks down and Monte Carlo is needed
+    Overtaking,
+}
+
+impl EncounterGeometry {
+    /// Classify an encounter angle in degrees.
+    pub fn from_angle_deg(angle_deg: f64) -> Self {
+        if angle_deg >= HEAD_ON_ANGLE_DEG {
+            Self::HeadOn
+        } else if angle_deg <= OVERTAKING_ANGLE_DEG {
+            Self::Overtaking
+        } else {
+            Self::Crossing
+        }
+    }
+}
+
+/// Size class of a space object, used for default hard-body radii.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectClass {
+    /// CubeSat or similar nanosatellite
+    CubeSat,
+    /// Large satellite with deployed arrays
+    LargeSatellite,
+    /// Debris fragment
+    Debris,
+    /// Unknown or unclassified object
+    #[default]
+    Unknown,
+}
+
+impl ObjectClass {
+    /// Default hard-body radius in meters.
+    pub fn default_radius_m(&self) -> f64 {
+        match self {
+            Self::CubeSat => 0.5,
+            Self::LargeSatellite => 5.0,
+            Self::Debris => 1.0,
+            Self::Unknown => 1.0,
+        }
+    }
+}
+
+/// Probability of collision for a spherical position covariance.
+///
+/// Integrates the relative-position Gaussian in the encounter plane over a
+/// disk of the combined hard-body radius centered at the miss vector.
+///
+/// # Arguments
+///
+/// * `miss_distance_km` - Miss distance at TCA
+/// * `position_sigma_km` - Combined 1-sigma position uncertainty
+/// * `hard_body_radius_m` - Combined hard-body radius of both objects
+pub fn compute_pc(miss_distance_km: f64, position_sigma_km: f64, hard_body_radius_m: f64) -> f64 {
+    if position_sigma_km <= 0.0 {
+        return 0.0;
+    }
+    let variance = position_sigma_km * position_sigma_km;
+    disk_probability(
+        miss_distance_km,
+        [variance, 0.0, variance],
+        hard_body_radius_m / 1000.0,
+    )
+}
+
+/// Probability mass of a zero-mean 2D Gaussian inside a disk.
+///
+/// # Arguments
+///
+/// * `offset_km` - Distance of the disk center from the mean, along x
+/// * `covariance` - `[xx, xy, yy]` covariance terms (km²)
+/// * `radius_km` - Disk radius
+fn disk_probability(offset_km: f64, covariance: [f64; 3], radius_km: f64) -> f64 {
+    let [cxx, cxy, cyy] = covariance;
+    let det = cxx * cyy - cxy * cxy;
+    if det <= 0.0 || radius_km <= 0.0 {
+        return 0.0;
+    }
+    let (ixx, ixy, iyy) = (cyy / det, -cxy / det, cxx / det);
+
+    let dr = radius_km / PC_INTEGRATION_STEPS as f64;
+    let dtheta = 2.0 * std::f64::consts::PI / PC_INTEGRATION_STEPS as f64;
+    let mut sum = 0.0;
+    for i in 0..PC_INTEGRATION_STEPS {
+        let r = (i as f64 + 0.5) * dr;
+        for j in 0..PC_INTEGRATION_STEPS {
+            let theta = (j as f64 + 0.5) * dtheta;
+            let x = r * theta.cos() - offset_km;
+            let y = r * theta.sin();
+            sum += (-0.5 * (ixx * x * x + 2.0 * ixy * x * y + iyy * y * y)).exp() * r;
+        }
+    }
+    (sum * dr * dtheta / (2.0 * std::f64::consts::PI * det.sqrt())).min(1.0)
+}
+
+/// Position covariance in a radial/in-track/cross-track (RIC) frame.
+///
+/// Stored as per-axis variances and correlation coefficients, the form
+/// conjunction data messages and most screening tools report.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Covariance {
+    /// Radial variance in km²
+    pub radial_km2: f64,
+    /// In-track variance in km²
+    pub in_track_km2: f64,
+    /// Cross-track variance in km²
+    pub cross_track_km2: f64,
+    /// Radial/in-track correlation coefficient
+    pub radial_in_track: f64,
+    /// Radial/cross-track correlation coefficient
+    pub radial_cross_track: f64,
+    /// In-track/cross-track correlation coefficient
+    pub in_track_cross_track: f64,
+}
+
+impl Covariance {
+    /// Uncorrelated covariance from 1-sigma position uncertainties in km.
+    pub fn from_sigmas(radial_km: f64, in_track_km: f64, cross_track_km: f64) -> Self {
+        Self {
+            radial_km2: radial_km * radial_km,
+            in_track_km2: in_track_km * in_track_km,
+            cross_track_km2: cross_track_km * cross_track_km,
+            radial_in_track: 0.0,
+            radial_cross_track: 0.0,
+            in_track_cross_track: 0.0,
+        }
+    }
+
+    /// Set the correlation coefficients, each in [-1, 1].
+    pub fn with_correlations(
+        mut self,
+        radial_in_track: f64,
+        radial_cross_track: f64,
+        in_track_cross_track: f64,
+    ) -> Self {
+        self.radial_in_track = radial_in_track;
+        self.radial_cross_track = radial_cross_track;
+        self.in_track_cross_track = in_track_cross_track;
+        self
+    }
+
+    /// Build from a symmetric 3x3 RIC covariance matrix in km².
+    ///
+    /// Correlations involving a zero variance are taken as 0.
+    pub fn from_matrix(matrix: [[f64; 3]; 3]) -> Self {
+        let correlation = |i: usize, j: usize| {
+            let scale = (matrix[i][i] * matrix[j][j]).sqrt();
+            if scale > 0.0 {
+                matrix[i][j] / scale
+            } else {
+                0.0
+            }
+        };
+        Self {
+            radial_km2: matrix[0][0],
+            in_track_km2: matrix[1][1],
+            cross_track_km2: matrix[2][2],
+            radial_in_track: correlation(0, 1),
+            radial_cross_track: correlation(0, 2),
+            in_track_cross_track: correlation(1, 2),
+        }
+    }
+
+    /// The covariance as a symmetric 3x3 RIC matrix in km².
+    pub fn to_matrix(&self) -> [[f64; 3]; 3] {
+        let sigma = [
+            self.radial_km2.sqrt(),
+            self.in_track_km2.sqrt(),
+            self.cross_track_km2.sqrt(),
+        ];
+        let ri = self.radial_in_track * sigma[0] * sigma[1];
+        let rc = self.radial_cross_track * sigma[0] * sigma[2];
+        let ic = self.in_track_cross_track * sigma[1] * sigma[2];
+        [
+            [self.radial_km2, ri, rc],
+            [ri, self.in_track_km2, ic],
+            [rc, ic, self.cross_track_km2],
+        ]
+    }
+
+    /// Rotate into the encounter plane (normal to the relative velocity).
+    ///
+    /// Returns the 2x2 covariance in km² with x along the in-plane miss
+    /// vector, the frame [`ConjunctionAnalyzer::compute_pc_2d`] integrates in,
+    /// or `None` if the relative velocity is zero. Both vectors are in this
+    /// covariance's RIC frame.
+    pub fn to_encounter_plane(
+        &self,
+        miss_vector_ric: [f64; 3],
+        relative_velocity_ric: [f64; 3],
+    ) -> Option<[[f64; 2]; 2]> {
+        let (x, y, _) = encounter_frame(miss_vector_ric.into(), relative_velocity_ric.into())?;
+        Some(project_onto_plane(self.to_matrix(), x, y))
+    }
+}
+
 /// A conjunction (close approach) between two space objects.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Conjunction {
     /// Unique conjunction ID
     pub id: String,
@@ -99,9 +401,27 @@ pub struct Conjunction {
     /// Relative velocity at TCA in km/s
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relative_velocity_km_s: Option<f64>,
+    /// Angle between the two velocity vectors at TCA in degrees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encounter_angle_deg: Option<f64>,
     /// Probability of collision
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collision_probability: Option<f64>,
+    /// Hard-body radius of the primary object in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_radius_m: Option<f64>,
+    /// Hard-body radius of the secondary object in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_radius_m: Option<f64>,
+    /// Combined hard-body radius used for `collision_probability`, in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hard_body_radius_m: Option<f64>,
+    /// Primary position covariance at TCA
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_covariance: Option<Covariance>,
+    /// Secondary position covariance at TCA
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_covariance: Option<Covariance>,
     /// Risk classification
     #[serde(default)]
     pub risk_level: RiskLevel,
@@ -138,13 +458,341 @@ impl Conjunction {
             miss_distance_in_track_km: None,
             miss_distance_cross_track_km: None,
             relative_velocity_km_s: None,
+            encounter_angle_deg: None,
             collision_probability: None,
+            primary_radius_m: None,
+            secondary_radius_m: None,
+            hard_body_radius_m: None,
+            primary_covariance: None,
+            secondary_covariance: None,
             risk_level,
             created_at: None,
             updated_at: None,
         }
     }
 
+    /// Parse a CCSDS Conjunction Data Message in keyword = value (KVN) form.
+    ///
+    /// Reads `MESSAGE_ID`, `CREATION_DATE`, `TCA`, `MISS_DISTANCE`,
+    /// `RELATIVE_SPEED`, the RTN `RELATIVE_POSITION_*` components and
+    /// `COLLISION_PROBABILITY` from the header and relative metadata, then each
+    /// object's `OBJECT_DESIGNATOR`, `OBJECT_NAME` and `CR_R`..`CN_N` position
+    /// covariance. Values are in meters unless tagged `[km]` / `[km/s]` /
+    /// `[km**2]` and are converted to km. Each covariance stays in its own
+    /// object's RTN frame, as the CDM reports it. The risk level follows the
+    /// collision probability when one is given.
+    pub fn from_cdm_kvn(text: &str) -> Result<Conjunction, ValidationError> {
+        let mut header: HashMap<&str, &str> = HashMap::new();
+        let mut objects: [HashMap<&str, &str>; 2] = Default::default();
+        let mut current = None;
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with("COMMENT") {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(ValidationError::new(
+                    "cdm",
+                    format!("Expected KEY = value, got '{}'", line),
+                ));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            if key == "OBJECT" {
+                current = match value {
+                    "OBJECT1" => Some(0),
+                    "OBJECT2" => Some(1),
+                    _ => {
+                        return Err(ValidationError::new(
+                            "object",
+                            format!("Unknown object '{}'", value),
+                        ))
+                    }
+                };
+                continue;
+            }
+            match current {
+                Some(i) => objects[i].insert(key, value),
+                None => header.insert(key, value),
+            };
+        }
+
+        let id = cdm_field(&header, "MESSAGE_ID")?;
+        let tca = parse_cdm_time(&header, "TCA")?;
+        let miss_distance_km = cdm_km(&header, "MISS_DISTANCE", 1)?
+            .ok_or_else(|| ValidationError::new("miss_distance", "Missing MISS_DISTANCE"))?;
+        let [primary, secondary] = &objects;
+        let primary_id = cdm_field(primary, "OBJECT_DESIGNATOR")?;
+        let secondary_id = cdm_field(secondary, "OBJECT_DESIGNATOR")?;
+        let collision_probability =
+            cdm_number(&header, "COLLISION_PROBABILITY")?.map(|(value, _)| value);
+
+        let mut conjunction = Conjunction::new(
+            id,
+            primary_id,
+            primary.get("OBJECT_NAME").copied().unwrap_or(primary_id),
+            secondary_id,
+            secondary
+                .get("OBJECT_NAME")
+                .copied()
+                .unwrap_or(secondary_id),
+            tca,
+            miss_distance_km,
+            collision_probability
+                .map(RiskLevel::from_probability)
+                .unwrap_or_default(),
+        );
+        conjunction.miss_distance_radial_km = cdm_km(&header, "RELATIVE_POSITION_R", 1)?;
+        conjunction.miss_distance_in_track_km = cdm_km(&header, "RELATIVE_POSITION_T", 1)?;
+        conjunction.miss_distance_cross_track_km = cdm_km(&header, "RELATIVE_POSITION_N", 1)?;
+        conjunction.relative_velocity_km_s = cdm_km(&header, "RELATIVE_SPEED", 1)?;
+        conjunction.collision_probability = collision_probability;
+        conjunction.primary_covariance = cdm_covariance(primary)?;
+        conjunction.secondary_covariance = cdm_covariance(secondary)?;
+        if header.contains_key("CREATION_DATE") {
+            conjunction.created_at = Some(parse_cdm_time(&header, "CREATION_DATE")?);
+        }
+        Ok(conjunction)
+    }
+
+    /// Write this conjunction as a CCSDS Conjunction Data Message in KVN form.
+    ///
+    /// Every keyword CCSDS 508.0 requires is written. The per-object state,
+    /// 6x6 covariance and international designator come from `primary` and
+    /// `secondary`; the conjunction's own position covariances are not used.
+    /// States are converted to ECEF and written as `ITRF` (polar motion is
+    /// not modeled). `EPHEMERIS_NAME` is `NONE`, since no external ephemeris
+    /// is used. Values are written in meters, the state vector in km. The
+    /// output round-trips through [`from_cdm_kvn`](Self::from_cdm_kvn).
+    ///
+    /// # Arguments
+    ///
+    /// * `creation_date` - Message `CREATION_DATE`, usually the caller's clock
+    /// * `primary` - CDM data for the primary object
+    /// * `secondary` - CDM data for the secondary object
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an object's international designator is empty,
+    /// its state is not at TCA, or its state or covariance is not finite,
+    /// symmetric and non-negative on the diagonal. Such data would give a
+    /// message that is not a valid CDM, so nothing is written rather than
+    /// handing back text other tools would reject.
+    pub fn to_cdm_kvn(
+        &self,
+        creation_date: DateTime<Utc>,
+        primary: &CdmObject,
+        secondary: &CdmObject,
+    ) -> Result<String, ValidationError> {
+        primary.validate(self.tca)?;
+        secondary.validate(self.tca)?;
+
+        let time = |t: DateTime<Utc>| t.format("%Y-%m-%dT%H:%M:%S%.3f").to_string();
+        let mut lines = vec![
+            "CCSDS_CDM_VERS = 1.0".to_string(),
+            format!("CREATION_DATE = {}", time(creation_date)),
+            "ORIGINATOR = ROTASTELLAR".to_string(),
+            format!("MESSAGE_ID = {}", self.id),
+            format!("TCA = {}", time(self.tca)),
+            format!("MISS_DISTANCE = {} [m]", self.miss_distance_km * 1000.0),
+        ];
+        let optional = [
+            ("RELATIVE_SPEED", self.relative_velocity_km_s, "m/s"),
+            ("RELATIVE_POSITION_R", self.miss_distance_radial_km, "m"),
+            ("RELATIVE_POSITION_T", self.miss_distance_in_track_km, "m"),
+            (
+                "RELATIVE_POSITION_N",
+                self.miss_distance_cross_track_km,
+                "m",
+            ),
+        ];
+        for (key, value_km, unit) in optional {
+            if let Some(value_km) = value_km {
+                lines.push(format!("{} = {} [{}]", key, value_km * 1000.0, unit));
+            }
+        }
+        if let Some(pc) = self.collision_probability {
+            lines.push(format!("COLLISION_PROBABILITY = {:e}", pc));
+        }
+
+        let objects = [
+            ("OBJECT1", &self.primary_id, &self.primary_name, primary),
+            (
+                "OBJECT2",
+                &self.secondary_id,
+                &self.secondary_name,
+                secondary,
+            ),
+        ];
+        const AXES: [&str; 6] = ["R", "T", "N", "RDOT", "TDOT", "NDOT"];
+        for (object, designator, name, data) in objects {
+            lines.push(format!("OBJECT = {}", object));
+            lines.push(format!("OBJECT_DESIGNATOR = {}", designator));
+            lines.push("CATALOG_NAME = SATCAT".to_string());
+            lines.push(format!("OBJECT_NAME = {}", name));
+            lines.push(format!(
+                "INTERNATIONAL_DESIGNATOR = {}",
+                data.international_designator
+            ));
+            lines.push("EPHEMERIS_NAME = NONE".to_string());
+            let method = if data.covariance_calculated {
+                "CALCULATED"
+            } else {
+                "DEFAULT"
+            };
+            lines.push(format!("COVARIANCE_METHOD = {}", method));
+            let maneuverable = match data.maneuverable {
+                Some(true) => "YES",
+                Some(false) => "NO",
+                None => "N/A",
+            };
+            lines.push(format!("MANEUVERABLE = {}", maneuverable));
+            lines.push("REF_FRAME = ITRF".to_string());
+
+            let state = data.state.to_frame(Frame::Ecef);
+            for (key, value) in ["X", "Y", "Z"].into_iter().zip(state.position_km) {
+                lines.push(format!("{} = {} [km]", key, value));
+            }
+            for (key, value) in ["X_DOT", "Y_DOT", "Z_DOT"]
+                .into_iter()
+                .zip(state.velocity_km_s)
+            {
+                lines.push(format!("{} = {} [km/s]", key, value));
+            }
+
+            // Lower triangle, row by row: CR_R, CT_R, CT_T, CN_R, ...
+            for (row, row_axis) in AXES.iter().enumerate() {
+                for (column, column_axis) in AXES.iter().enumerate().take(row + 1) {
+                    let unit = match (row >= 3, column >= 3) {
+                        (false, false) => "m**2",
+                        (true, true) => "m**2/s**2",
+                        _ => "m**2/s",
+                    };
+                    let value = data.covariance_rtn[row][column] * 1e6;
+                    lines.push(format!(
+                        "C{}_{} = {:e} [{}]",
+                        row_axis, column_axis, value, unit
+                    ));
+                }
+            }
+        }
+
+        let mut text = lines.join("\n");
+        text.push('\n');
+        Ok(text)
+    }
+
+    /// Set the hard-body radii of both objects in meters.
+    pub fn with_object_radii(mut self, primary_radius_m: f64, secondary_radius_m: f64) -> Self {
+        self.primary_radius_m = Some(primary_radius_m);
+        self.secondary_radius_m = Some(secondary_radius_m);
+        self
+    }
+
+    /// Set the hard-body radii of both objects from their size classes.
+    pub fn with_object_classes(self, primary: ObjectClass, secondary: ObjectClass) -> Self {
+        self.with_object_radii(primary.default_radius_m(), secondary.default_radius_m())
+    }
+
+    /// Combined hard-body radius in meters.
+    ///
+    /// Objects without a radius are treated as [`ObjectClass::Unknown`].
+    pub fn combined_radius_m(&self) -> f64 {
+        let unknown = ObjectClass::Unknown.default_radius_m();
+        self.primary_radius_m.unwrap_or(unknown) + self.secondary_radius_m.unwrap_or(unknown)
+    }
+
+    /// Compute and store the probability of collision.
+    ///
+    /// Uses the sum of both objects' radii as the hard-body radius and records
+    /// it in `hard_body_radius_m`.
+    ///
+    /// # Arguments
+    ///
+    /// * `position_sigma_km` - Combined 1-sigma position uncertainty
+    pub fn compute_pc(&mut self, position_sigma_km: f64) -> f64 {
+        let radius_m = self.combined_radius_m();
+        let pc = compute_pc(self.miss_distance_km, position_sigma_km, radius_m);
+        self.hard_body_radius_m = Some(radius_m);
+        self.collision_probability = Some(pc);
+        pc
+    }
+
+    /// Set `risk_level` from `collision_probability`.
+    ///
+    /// Leaves the risk level unchanged if no probability has been computed.
+    pub fn reclassify_from_pc(&mut self) {
+        if let Some(pc) = self.collision_probability {
+            self.risk_level = RiskLevel::from_probability(pc);
+        }
+    }
+
+    /// Split the miss distance into radial, in-track and cross-track
+    /// components.
+    ///
+    /// `relative_position_ric` (secondary minus primary, in the primary's
+    /// RIC frame, km) is stored as given. Its length must agree with
+    /// `miss_distance_km` to within 1%; otherwise an error is returned and
+    /// the components are left unchanged.
+    pub fn decompose_miss(
+        &mut self,
+        relative_position_ric: [f64; 3],
+    ) -> Result<(), ValidationError> {
+        let length = Coordinate3D::from(relative_position_ric).magnitude();
+        if (length - self.miss_distance_km).abs()
+            > MISS_DECOMPOSITION_TOLERANCE * self.miss_distance_km
+        {
+            return Err(ValidationError::new(
+                "relative_position_ric",
+                format!(
+                    "Length {:.6} km does not match miss distance {:.6} km",
+                    length, self.miss_distance_km
+                ),
+            ));
+        }
+        let [radial, in_track, cross_track] = relative_position_ric;
+        self.miss_distance_radial_km = Some(radial);
+        self.miss_distance_in_track_km = Some(in_track);
+        self.miss_distance_cross_track_km = Some(cross_track);
+        Ok(())
+    }
+
+    /// Set the relative velocity and encounter angle from both objects'
+    /// velocity vectors at TCA (km/s).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the conjunction unchanged, if either
+    /// velocity is zero or not finite, since it has no direction to take an
+    /// angle from.
+    pub fn set_velocities(
+        &mut self,
+        primary_km_s: [f64; 3],
+        secondary_km_s: [f64; 3],
+    ) -> Result<(), ValidationError> {
+        let (primary, secondary) = (
+            Coordinate3D::from(primary_km_s),
+            Coordinate3D::from(secondary_km_s),
+        );
+        for (field, velocity) in [("primary_km_s", primary), ("secondary_km_s", secondary)] {
+            let speed = velocity.magnitude();
+            if !(speed.is_finite() && speed > 0.0) {
+                return Err(ValidationError::new(
+                    field,
+                    "Velocity must be finite and non-zero",
+                ));
+            }
+        }
+        let cos_angle = primary.dot(&secondary) / (primary.magnitude() * secondary.magnitude());
+        self.relative_velocity_km_s = Some((primary - secondary).magnitude());
+        self.encounter_angle_deg = Some(cos_angle.clamp(-1.0, 1.0).acos().to_degrees());
+        Ok(())
+    }
+
+    /// Encounter geometry, if the encounter angle is known.
+    pub fn encounter_geometry(&self) -> Option<EncounterGeometry> {
+        self.encounter_angle_deg
+            .map(EncounterGeometry::from_angle_deg)
+    }
+
     /// Check if this conjunction is critical risk.
     pub fn is_critical(&self) -> bool {
         self.risk_level == RiskLevel::Critical
@@ -157,9 +805,230 @@ impl Conjunction {
 
     /// Get time to TCA in hours (negative if past).
     pub fn time_to_tca_hours(&self) -> f64 {
-        let now = Utc::now();
-        (self.tca - now).num_milliseconds() as f64 / (1000.0 * 3600.0)
+        self.time_to_tca_hours_with(&SystemClock)
+    }
+
+    /// Get time to TCA in hours according to the given clock.
+    pub fn time_to_tca_hours_with(&self, clock: &dyn Clock) -> f64 {
+        (self.tca - clock.now()).num_milliseconds() as f64 / (1000.0 * 3600.0)
+    }
+}
+
+/// Object data a CDM requires that a [`Conjunction`] does not carry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CdmObject {
+    /// International designator (COSPAR ID), e.g. `1998-067A`
+    pub international_designator: String,
+    /// State at TCA, in any frame
+    pub state: StateVector,
+    /// Position/velocity covariance at TCA in the object's RTN frame, rows
+    /// and columns ordered R, T, N, RDOT, TDOT, NDOT, in km and km/s
+    pub covariance_rtn: [[f64; 6]; 6],
+    /// `true` if the covariance comes from orbit determination
+    /// (`CALCULATED`), `false` if it is an assumed default (`DEFAULT`)
+    pub covariance_calculated: bool,
+    /// Whether the object can maneuver; `None` is written as `N/A`
+    pub maneuverable: Option<bool>,
+}
+
+impl CdmObject {
+    /// Create CDM object data with a calculated covariance and unknown
+    /// maneuverability.
+    pub fn new(
+        international_designator: impl Into<String>,
+        state: StateVector,
+        covariance_rtn: [[f64; 6]; 6],
+    ) -> Self {
+        Self {
+            international_designator: international_designator.into(),
+            state,
+            covariance_rtn,
+            covariance_calculated: true,
+            maneuverable: None,
+        }
+    }
+
+    /// Check the data is complete and describes an object at `tca`.
+    fn validate(&self, tca: DateTime<Utc>) -> Result<(), ValidationError> {
+        if self.international_designator.trim().is_empty() {
+            return Err(ValidationError::new(
+                "international_designator",
+                "International designator is empty",
+            ));
+        }
+        if (self.state.time - tca).num_milliseconds() != 0 {
+            return Err(ValidationError::new(
+                "state",
+                format!(
+                    "State is at {}, not TCA {}",
+                    self.state.time.to_rfc3339(),
+                    tca.to_rfc3339()
+                ),
+            ));
+        }
+        if !self
+            .state
+            .position_km
+            .iter()
+            .chain(&self.state.velocity_km_s)
+            .all(|v| v.is_finite())
+        {
+            return Err(ValidationError::new("state", "State vector is not finite"));
+        }
+        let c = &self.covariance_rtn;
+        for (i, row) in c.iter().enumerate() {
+            if !row[i].is_finite() || row[i] < 0.0 {
+                return Err(ValidationError::new(
+                    "covariance_rtn",
+                    "Covariance diagonal must be finite and non-negative",
+                ));
+            }
+            if row[..i]
+                .iter()
+                .enumerate()
+                .any(|(j, &value)| !value.is_finite() || value != c[j][i])
+            {
+                return Err(ValidationError::new(
+                    "covariance_rtn",
+                    "Covariance must be finite and symmetric",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A required text field of a CDM section.
+fn cdm_field<'a>(fields: &HashMap<&str, &'a str>, key: &str) -> Result<&'a str, ValidationError> {
+    fields
+        .get(key)
+        .copied()
+        .ok_or_else(|| ValidationError::new(key.to_lowercase(), format!("Missing {}", key)))
+}
+
+/// An optional numeric CDM field and its `[unit]` suffix, if any.
+fn cdm_number<'a>(
+    fields: &HashMap<&str, &'a str>,
+    key: &str,
+) -> Result<Option<(f64, Option<&'a str>)>, ValidationError> {
+    let Some(raw) = fields.get(key) else {
+        return Ok(None);
+    };
+    let (number, unit) = match raw.split_once('[') {
+        Some((number, unit)) => (number.trim(), Some(unit.trim_end_matches(']').trim())),
+        None => (raw.trim(), None),
+    };
+    let value = number.parse().map_err(|_| {
+        ValidationError::new(key.to_lowercase(), format!("Invalid number '{}'", number))
+    })?;
+    Ok(Some((value, unit)))
+}
+
+/// An optional CDM field in km-based units.
+///
+/// `dimension` is the power of length in the field's unit (1 for m and m/s,
+/// 2 for m**2). Untagged values use the CDM's meter-based default.
+fn cdm_km(
+    fields: &HashMap<&str, &str>,
+    key: &str,
+    dimension: i32,
+) -> Result<Option<f64>, ValidationError> {
+    Ok(cdm_number(fields, key)?.map(|(value, unit)| {
+        if unit.is_some_and(|unit| unit.starts_with("km")) {
+            value
+        } else {
+            value / 1000f64.powi(dimension)
+        }
+    }))
+}
+
+/// A CDM timestamp in calendar or day-of-year form, UTC.
+fn parse_cdm_time(
+    fields: &HashMap<&str, &str>,
+    key: &str,
+) -> Result<DateTime<Utc>, ValidationError> {
+    let raw = cdm_field(fields, key)?.trim_end_matches('Z');
+    NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(raw, "%Y-%jT%H:%M:%S%.f"))
+        .map(|dt| dt.and_utc())
+        .map_err(|_| {
+            ValidationError::new(key.to_lowercase(), format!("Invalid timestamp '{}'", raw))
+        })
+}
+
+/// An object's RTN position covariance, if all six terms are present.
+fn cdm_covariance(fields: &HashMap<&str, &str>) -> Result<Option<Covariance>, ValidationError> {
+    let mut terms = [0.0; 6];
+    for (term, key) in terms
+        .iter_mut()
+        .zip(["CR_R", "CT_R", "CT_T", "CN_R", "CN_T", "CN_N"])
+    {
+        match cdm_km(fields, key, 2)? {
+            Some(value) => *term = value,
+            None => return Ok(None),
+        }
+    }
+    let [rr, tr, tt, nr, nt, nn] = terms;
+    Ok(Some(Covariance::from_matrix([
+        [rr, tr, nr],
+        [tr, tt, nt],
+        [nr, nt, nn],
+    ])))
+}
+
+/// Encounter-plane axes for a miss vector and relative velocity.
+///
+/// x lies along the miss vector's in-plane component and y completes the
+/// right-handed basis with the relative velocity. Returns the axes and the
+/// in-plane miss distance, or `None` if the relative velocity is zero.
+fn encounter_frame(
+    miss_vector: Coordinate3D,
+    relative_velocity: Coordinate3D,
+) -> Option<(Coordinate3D, Coordinate3D, f64)> {
+    if relative_velocity.magnitude() == 0.0 {
+        return None;
+    }
+    let z = relative_velocity.normalize();
+
+    let in_plane = miss_vector - z * miss_vector.dot(&z);
+    let miss_km = in_plane.magnitude();
+    let x = if miss_km > 0.0 {
+        in_plane / miss_km
+    } else {
+        // Direct hit: any in-plane axis will do
+        let axis = if z.x.abs() < 0.9 {
+            Coordinate3D::new(1.0, 0.0, 0.0)
+        } else {
+            Coordinate3D::new(0.0, 1.0, 0.0)
+        };
+        z.cross(&axis).normalize()
+    };
+    Some((x, z.cross(&x), miss_km))
+}
+
+/// Element-wise sum of two 3x3 matrices.
+fn add_matrices(a: [[f64; 3]; 3], b: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut sum = [[0.0; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            sum[i][j] = a[i][j] + b[i][j];
+        }
     }
+    sum
+}
+
+/// Project a 3x3 covariance onto the plane spanned by unit axes `x` and `y`.
+fn project_onto_plane(matrix: [[f64; 3]; 3], x: Coordinate3D, y: Coordinate3D) -> [[f64; 2]; 2] {
+    let project = |a: Coordinate3D, b: Coordinate3D| {
+        let [row_x, row_y, row_z] = matrix.map(Coordinate3D::from);
+        a.dot(&Coordinate3D::new(
+            row_x.dot(&b),
+            row_y.dot(&b),
+            row_z.dot(&b),
+        ))
+    };
+    let xy = project(x, y);
+    [[project(x, x), xy], [xy, project(y, y)]]
 }
 
 /// Recommended maneuver to avoid a conjunction.
@@ -189,17 +1058,23 @@ pub struct ManeuverRecommendation {
 /// # Example
 ///
 /// ```ignore
-/// use rotastellar_intel::{ConjunctionAnalyzer, RiskLevel};
+/// use rotastellar_intel::{ConjunctionAnalyzer, QueryWindow, RiskLevel};
 ///
 /// let analyzer = ConjunctionAnalyzer::new();
 ///
 /// // Analyze risk for a satellite
-/// let analysis = analyzer.analyze_risk("starlink-1234", Some(168.0));
+/// let analysis = analyzer.analyze_risk("starlink-1234", Some(QueryWindow::next_hours(168.0)));
 /// println!("Critical conjunctions: {}", analysis.critical_count);
 /// ```
 pub struct ConjunctionAnalyzer {
     /// Cached conjunctions
     conjunctions: Vec<Conjunction>,
+    /// Time source for time-relative queries
+    clock: Arc<dyn Clock>,
+    /// Time-to-TCA at which triage urgency halves, in hours
+    triage_time_scale_hours: f64,
+    /// Miss distances classifying screened approaches, in km
+    miss_distance_thresholds_km: [f64; 4],
 }
 
 impl Default for ConjunctionAnalyzer {
@@ -213,9 +1088,49 @@ impl ConjunctionAnalyzer {
     pub fn new() -> Self {
         Self {
             conjunctions: Vec::new(),
+            clock: Arc::new(SystemClock),
+            triage_time_scale_hours: TRIAGE_TIME_SCALE_HOURS,
+            miss_distance_thresholds_km: MISS_DISTANCE_THRESHOLDS_KM,
         }
     }
 
+    /// Use a custom time source instead of the system clock.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Set how strongly [`triage`](Self::triage) favours sooner conjunctions.
+    ///
+    /// A conjunction this many hours out counts half as urgent as one at TCA
+    /// now (default: 24). Smaller values weight time more heavily; very large
+    /// values rank by risk level alone.
+    pub fn with_triage_time_scale_hours(mut self, hours: f64) -> Self {
+        self.triage_time_scale_hours = hours;
+        self
+    }
+
+    /// Set the miss distances that classify screened approaches.
+    ///
+    /// [`screen_pair`](Self::screen_pair) has no covariance for Pc, so it
+    /// grades each approach by miss distance: Critical at or below the first
+    /// threshold, then High, Medium and Low (default: 1, 5, 10 and 25 km;
+    /// see [`RiskLevel::from_miss_distance_km`]).
+    pub fn with_miss_distance_thresholds_km(mut self, thresholds_km: [f64; 4]) -> Self {
+        self.miss_distance_thresholds_km = thresholds_km;
+        self
+    }
+
+    /// Get the analyzer's time source.
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    /// Get time to TCA in hours for a conjunction, using the analyzer's clock.
+    pub fn time_to_tca_hours(&self, conjunction: &Conjunction) -> f64 {
+        conjunction.time_to_tca_hours_with(self.clock.as_ref())
+    }
+
     /// Add a conjunction to the analyzer.
     pub fn add_conjunction(&mut self, conjunction: Conjunction) {
         self.conjunctions.push(conjunction);
@@ -226,6 +1141,518 @@ impl ConjunctionAnalyzer {
         &self.conjunctions
     }
 
+    /// Write all conjunctions as JSON Lines.
+    ///
+    /// Each conjunction is serialized on its own line, with timestamps in RFC 3339,
+    /// so the output can be streamed or tailed record by record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or writing fails.
+    pub fn write_jsonl<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        for conjunction in &self.conjunctions {
+            serde_json::to_writer(&mut writer, conjunction)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()
+    }
+
+    /// Probability of collision by Foster's 2D method.
+    ///
+    /// The combined covariance is projected onto the encounter plane (normal
+    /// to the relative velocity), and the resulting 2D Gaussian is integrated
+    /// over the hard-body disk centered at the miss vector. This is the
+    /// short-encounter assumption; it breaks down for slow,
+    /// [`Overtaking`](EncounterGeometry::Overtaking) encounters.
+    ///
+    /// All vectors and matrices must be in the same radial/in-track/
+    /// cross-track (RIC) frame, conventionally the primary's.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary_cov` - Primary position covariance (km²)
+    /// * `secondary_cov` - Secondary position covariance (km²)
+    /// * `miss_vector_ric` - Secondary minus primary position at TCA (km)
+    /// * `relative_velocity_ric` - Secondary minus primary velocity at TCA;
+    ///   only its direction is used
+    /// * `combined_hbr_m` - Combined hard-body radius of both objects in meters
+    pub fn compute_pc_2d(
+        primary_cov: [[f64; 3]; 3],
+        secondary_cov: [[f64; 3]; 3],
+        miss_vector_ric: [f64; 3],
+        relative_velocity_ric: [f64; 3],
+        combined_hbr_m: f64,
+    ) -> f64 {
+        let Some((x, y, miss_km)) =
+            encounter_frame(miss_vector_ric.into(), relative_velocity_ric.into())
+        else {
+            return 0.0;
+        };
+
+        let plane = project_onto_plane(add_matrices(primary_cov, secondary_cov), x, y);
+
+        disk_probability(
+            miss_km,
+            [plane[0][0], plane[0][1], plane[1][1]],
+            combined_hbr_m / 1000.0,
+        )
+    }
+
+    /// Worst-case probability of collision over covariance scaling.
+    ///
+    /// When the covariance is large relative to the miss distance, shrinking
+    /// it would *raise* Pc (the dilution region), so a low nominal Pc may only
+    /// reflect poor tracking. This scales the combined covariance by `k²` for
+    /// `k` across ±4 decades and returns the largest 2D Pc found. Arguments
+    /// are as for [`compute_pc_2d`](Self::compute_pc_2d).
+    pub fn max_pc(
+        primary_cov: [[f64; 3]; 3],
+        secondary_cov: [[f64; 3]; 3],
+        miss_vector_ric: [f64; 3],
+        relative_velocity_ric: [f64; 3],
+        combined_hbr_m: f64,
+    ) -> f64 {
+        let Some((x, y, miss_km)) =
+            encounter_frame(miss_vector_ric.into(), relative_velocity_ric.into())
+        else {
+            return 0.0;
+        };
+        let plane = project_onto_plane(add_matrices(primary_cov, secondary_cov), x, y);
+        let pc_at = |log_k: f64| {
+            let scale = 10f64.powf(2.0 * log_k);
+            let cov = [
+                plane[0][0] * scale,
+                plane[0][1] * scale,
+                plane[1][1] * scale,
+            ];
+            disk_probability(miss_km, cov, combined_hbr_m / 1000.0)
+        };
+
+        // Coarse log-spaced scan (including k = 1), then golden-section refinement
+        let step = 2.0 * MAX_PC_SCALE_DECADES / MAX_PC_SCAN_STEPS as f64;
+        let (best_i, best_pc) = (0..=MAX_PC_SCAN_STEPS)
+            .map(|i| (i, pc_at(-MAX_PC_SCALE_DECADES + i as f64 * step)))
+            .fold(
+                (0, f64::MIN),
+                |best, sample| if sample.1 > best.1 { sample } else { best },
+            );
+        let golden = (5f64.sqrt() - 1.0) / 2.0;
+        let centre = -MAX_PC_SCALE_DECADES + best_i as f64 * step;
+        let (mut a, mut b) = (centre - step, centre + step);
+        while b - a > 1e-4 {
+            let c = b - golden * (b - a);
+            let d = a + golden * (b - a);
+            if pc_at(c) > pc_at(d) {
+                b = d;
+            } else {
+                a = c;
+            }
+        }
+        best_pc.max(pc_at((a + b) / 2.0))
+    }
+
+    /// Screen a planned maneuver for conjunctions it would create.
+    ///
+    /// Applies the maneuver's delta-v to the primary's SGP4 state at the
+    /// maneuver time and reports every close approach with a catalog object
+    /// inside `threshold_km` during the window. The burn is carried as a
+    /// perturbation on the primary's nominal SGP4 track: the difference
+    /// between two-body propagations of the burned and unburned states, so a
+    /// zero delta-v screens exactly the SGP4 track the catalog is compared
+    /// against. Catalog objects are propagated with SGP4, each model
+    /// initialized once. Catalog entries with the primary's
+    /// NORAD ID are skipped, as are objects SGP4 rejects or fails for at a
+    /// sample, so a catalog with decayed element sets can under-report. Pc is
+    /// estimated with a 1 km position uncertainty, typical of TLE-derived
+    /// states.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary` - Primary object's pre-maneuver TLE
+    /// * `maneuver` - Planned maneuver; `direction` is "radial", "in-track",
+    ///   or "cross-track" and a negative delta-v burns the opposite way
+    /// * `catalog` - Objects to screen against
+    /// * `window_hours` - Screening window after the maneuver
+    /// * `threshold_km` - Miss distance below which an approach is reported
+    ///
+    /// # Returns
+    ///
+    /// Post-maneuver conjunctions ordered by TCA.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if SGP4 rejects the primary's element set or fails at
+    /// any sample in the window.
+    pub fn screen_post_maneuver(
+        &self,
+        primary: &TLE,
+        maneuver: &ManeuverRecommendation,
+        catalog: &[TLE],
+        window_hours: f64,
+        threshold_km: f64,
+    ) -> Result<Vec<Conjunction>, ValidationError> {
+        let burn_time = maneuver.maneuver_time;
+        let nominal = primary.sgp4_propagator()?;
+        let (r, v) = nominal.state(burn_time)?;
+        let (r, v) = (Coordinate3D::from(r), Coordinate3D::from(v));
+        let v_post =
+            v + burn_direction(&maneuver.direction, r, v) * (maneuver.delta_v_m_s / 1000.0);
+        // Osculating states, so only their difference is added to the SGP4 track
+        let accuracy = PropagationAccuracy::TwoBody;
+        let coasting = primary.with_eci_state(burn_time, r.into(), v.into());
+        let burned = primary.with_eci_state(burn_time, r.into(), v_post.into());
+        let primary_state = |t: DateTime<Utc>| -> Result<_, ValidationError> {
+            let (r, v) = nominal.state(t)?;
+            let dr = Coordinate3D::from(burned.eci_position_km(t, accuracy)?)
+                - coasting.eci_position_km(t, accuracy)?.into();
+            let dv = Coordinate3D::from(burned.eci_velocity_km_s(t, accuracy)?)
+                - coasting.eci_velocity_km_s(t, accuracy)?.into();
+            Ok((Coordinate3D::from(r) + dr, Coordinate3D::from(v) + dv))
+        };
+
+        let step = Duration::seconds(SCREENING_STEP_SECONDS);
+        let steps = (window_hours.max(0.0) * 3600.0 / SCREENING_STEP_SECONDS as f64).ceil() as i64;
+        let times: Vec<DateTime<Utc>> = (0..=steps).map(|i| burn_time + step * i as i32).collect();
+        let primary_track = times
+            .iter()
+            .map(|&t| Ok(primary_state(t)?.0))
+            .collect::<Result<Vec<Coordinate3D>, ValidationError>>()?;
+
+        let mut conjunctions = Vec::new();
+        for secondary in catalog
+            .iter()
+            .filter(|tle| tle.norad_id != primary.norad_id)
+        {
+            let Ok(model) = secondary.sgp4_propagator() else {
+                continue;
+            };
+            let Ok(distances) = times
+                .iter()
+                .zip(&primary_track)
+                .map(|(&t, &a)| Ok((a - model.state(t)?.0.into()).magnitude()))
+                .collect::<Result<Vec<f64>, ValidationError>>()
+            else {
+                continue;
+            };
+            let distance_at = |t: DateTime<Utc>| match (primary_state(t), model.state(t)) {
+                (Ok((a, _)), Ok((b, _))) => (a - b.into()).magnitude(),
+                _ => f64::INFINITY,
+            };
+
+            for i in 0..distances.len() {
+                let is_local_min = (i == 0 || distances[i] <= distances[i - 1])
+                    && (i + 1 == distances.len() || distances[i] < distances[i + 1]);
+                if !is_local_min {
+                    continue;
+                }
+                let lower = times[i.saturating_sub(1)];
+                let upper = times[(i + 1).min(times.len() - 1)];
+                let tca = refine_minimum(&distance_at, lower, upper);
+                let miss_distance_km = distance_at(tca);
+                if miss_distance_km > threshold_km {
+                    continue;
+                }
+
+                let (Ok((_, va)), Ok((_, vb))) = (primary_state(tca), model.state(tca)) else {
+                    continue;
+                };
+                let mut conjunction = Conjunction::new(
+                    format!(
+                        "screen-{}-{}-{}",
+                        primary.norad_id,
+                        secondary.norad_id,
+                        tca.timestamp()
+                    ),
+                    primary.norad_id.to_string(),
+                    primary.name.clone(),
+                    secondary.norad_id.to_string(),
+                    secondary.name.clone(),
+                    tca,
+                    miss_distance_km,
+                    RiskLevel::default(),
+                );
+                conjunction.set_velocities(va.into(), vb)?;
+                conjunction.created_at = Some(self.clock.now());
+                let pc = conjunction.compute_pc(SCREENING_POSITION_SIGMA_KM);
+                conjunction.risk_level = RiskLevel::from_probability(pc);
+                conjunctions.push(conjunction);
+            }
+        }
+
+        conjunctions.sort_by_key(|c| c.tca);
+        Ok(conjunctions)
+    }
+
+    /// Find the closest approach between two objects in a time window.
+    ///
+    /// Samples the range between both SGP4 positions every `step_seconds`,
+    /// then refines the TCA around the closest sample by repeatedly fitting
+    /// a parabola to the squared range, which is exact for straight-line
+    /// relative motion. Each object's SGP4 model is initialized once. With
+    /// no covariance from TLEs, the risk level comes from the analyzer's
+    /// [miss distance thresholds](Self::with_miss_distance_thresholds_km).
+    ///
+    /// # Returns
+    ///
+    /// The conjunction at the minimum range, or `None` if the window is empty
+    /// or `step_seconds` is not positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if SGP4 rejects either element set or fails at any
+    /// time the search evaluates.
+    pub fn screen_pair(
+        &self,
+        primary: &TLE,
+        secondary: &TLE,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step_seconds: i64,
+    ) -> Result<Option<Conjunction>, ValidationError> {
+        if step_seconds <= 0 || end <= start {
+            return Ok(None);
+        }
+        let models = (primary.sgp4_propagator()?, secondary.sgp4_propagator()?);
+        self.closest_approach(
+            (primary, &models.0),
+            (secondary, &models.1),
+            start,
+            end,
+            step_seconds,
+        )
+        .map(Some)
+    }
+
+    /// [`screen_pair`](Self::screen_pair) with both SGP4 models already
+    /// initialized, for a non-empty window and positive step.
+    fn closest_approach(
+        &self,
+        (primary, primary_model): (&TLE, &Sgp4Propagator),
+        (secondary, secondary_model): (&TLE, &Sgp4Propagator),
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step_seconds: i64,
+    ) -> Result<Conjunction, ValidationError> {
+        let window_s = (end - start).num_milliseconds() as f64 / 1000.0;
+        let at = |offset_s: f64| {
+            start + Duration::milliseconds((offset_s.clamp(0.0, window_s) * 1000.0).round() as i64)
+        };
+        let range_sq = |offset_s: f64| -> Result<f64, ValidationError> {
+            let t = at(offset_s);
+            let d =
+                Coordinate3D::from(primary_model.state(t)?.0) - secondary_model.state(t)?.0.into();
+            Ok(d.dot(&d))
+        };
+
+        let step = step_seconds as f64;
+        let samples = (window_s / step).ceil() as usize;
+        let (mut best, mut best_range_sq) = (0.0, range_sq(0.0)?);
+        for i in 1..=samples {
+            let offset = (i as f64 * step).min(window_s);
+            let candidate = range_sq(offset)?;
+            if candidate < best_range_sq {
+                (best, best_range_sq) = (offset, candidate);
+            }
+        }
+
+        let mut spacing = step;
+        while spacing > TCA_TOLERANCE_SECONDS {
+            let (before, here, after) = (
+                range_sq(best - spacing)?,
+                range_sq(best)?,
+                range_sq(best + spacing)?,
+            );
+            let curvature = before - 2.0 * here + after;
+            if curvature > 0.0 {
+                let vertex = best + 0.5 * spacing * (before - after) / curvature;
+                best = vertex
+                    .clamp(best - spacing, best + spacing)
+                    .clamp(0.0, window_s);
+            }
+            spacing /= 10.0;
+        }
+
+        let tca = at(best);
+        let miss_distance_km = range_sq(best)?.sqrt();
+        let mut conjunction = Conjunction::new(
+            format!(
+                "screen-{}-{}-{}",
+                primary.norad_id,
+                secondary.norad_id,
+                tca.timestamp()
+            ),
+            primary.norad_id.to_string(),
+            primary.name.clone(),
+            secondary.norad_id.to_string(),
+            secondary.name.clone(),
+            tca,
+            miss_distance_km,
+            RiskLevel::from_miss_distance_thresholds(
+                miss_distance_km,
+                self.miss_distance_thresholds_km,
+            ),
+        );
+        conjunction.set_velocities(primary_model.state(tca)?.1, secondary_model.state(tca)?.1)?;
+        conjunction.created_at = Some(self.clock.now());
+        Ok(conjunction)
+    }
+
+    /// Screen every pair in a catalog and store the conjunctions found.
+    ///
+    /// Objects whose perigee-apogee band lies more than
+    /// `screening_distance_km` from every other object's are never
+    /// propagated. The rest are bucketed into a 3D grid at each
+    /// `step_seconds` sample, with cells sized so that any pair that comes
+    /// within `screening_distance_km` between samples shares or neighbours a
+    /// cell at the nearest one. Only those pairs are deep-screened with
+    /// [`screen_pair`](Self::screen_pair) around the flagged samples.
+    /// Each object's SGP4 model is initialized once. Conjunctions use the
+    /// IDs given alongside each TLE.
+    ///
+    /// # Returns
+    ///
+    /// The number of conjunctions added. Objects SGP4 rejects are skipped,
+    /// as are samples where an object fails to propagate and approaches
+    /// whose deep screen hits such a failure, so a catalog with decayed or
+    /// malformed element sets can under-report.
+    pub fn screen_catalog(
+        &mut self,
+        tles: &[(String, TLE)],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step_seconds: i64,
+        screening_distance_km: f64,
+    ) -> usize {
+        if step_seconds <= 0 || end <= start {
+            return 0;
+        }
+        let bands: Vec<(f64, f64)> = tles
+            .iter()
+            .map(|(_, tle)| {
+                (
+                    tle.perigee_km() - screening_distance_km,
+                    tle.apogee_km() + screening_distance_km,
+                )
+            })
+            .collect();
+        let overlaps =
+            |i: usize, j: usize| i != j && bands[i].0 <= bands[j].1 && bands[j].0 <= bands[i].1;
+        let models: Vec<Option<Sgp4Propagator>> = tles
+            .iter()
+            .map(|(_, tle)| tle.sgp4_propagator().ok())
+            .collect();
+        let active: Vec<usize> = (0..tles.len())
+            .filter(|&i| {
+                models[i].is_some()
+                    && (0..tles.len()).any(|j| models[j].is_some() && overlaps(i, j))
+            })
+            .collect();
+        if active.is_empty() {
+            return 0;
+        }
+
+        // A pair closer than `screening_distance_km` at some instant is within
+        // `reach_km` at the nearest sample, half a step away for each object
+        let max_speed = active
+            .iter()
+            .map(|&i| {
+                let (a, e) = (tles[i].1.semi_major_axis_km(), tles[i].1.eccentricity);
+                (EARTH_MU * (1.0 + e) / (a * (1.0 - e))).sqrt()
+            })
+            .fold(0.0, f64::max);
+        let reach_km = screening_distance_km + max_speed * step_seconds as f64;
+
+        let step = Duration::seconds(step_seconds);
+        let samples = ((end - start).num_seconds() + step_seconds - 1) / step_seconds;
+        let times: Vec<DateTime<Utc>> = (0..=samples)
+            .map(|k| (start + step * k as i32).min(end))
+            .collect();
+        let neighbours: Vec<[i64; 3]> = (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| [x, y, z])))
+            .collect();
+
+        // Sample indices at which each candidate pair was within reach
+        let mut flagged: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+        for (k, &t) in times.iter().enumerate() {
+            let mut positions = HashMap::new();
+            let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+            for &i in &active {
+                let Some(Ok((position, _))) = models[i].as_ref().map(|model| model.state(t)) else {
+                    continue;
+                };
+                grid.entry(position.map(|c| (c / reach_km).floor() as i64))
+                    .or_default()
+                    .push(i);
+                positions.insert(i, Coordinate3D::from(position));
+            }
+            for (cell, members) in &grid {
+                for offset in &neighbours {
+                    let Some(others) = grid.get(&[
+                        cell[0] + offset[0],
+                        cell[1] + offset[1],
+                        cell[2] + offset[2],
+                    ]) else {
+                        continue;
+                    };
+                    for &i in members {
+                        for &j in others.iter().filter(|&&j| i < j && overlaps(i, j)) {
+                            if (positions[&i] - positions[&j]).magnitude() <= reach_km {
+                                flagged.entry((i, j)).or_default().push(k);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut found = Vec::new();
+        for ((i, j), ks) in flagged {
+            let ((primary_id, primary), (secondary_id, secondary)) = (&tles[i], &tles[j]);
+            // Each run of consecutive flagged samples holds one approach
+            let mut runs: Vec<(usize, usize)> = Vec::new();
+            for k in ks {
+                match runs.last_mut() {
+                    Some((_, last)) if *last + 1 == k => *last = k,
+                    _ => runs.push((k, k)),
+                }
+            }
+            for (first, last) in runs {
+                let lower = times[first.saturating_sub(1)];
+                let upper = times[(last + 1).min(times.len() - 1)];
+                let (Some(primary_model), Some(secondary_model)) = (&models[i], &models[j]) else {
+                    continue;
+                };
+                let step = SCREENING_STEP_SECONDS.min(step_seconds);
+                let Ok(mut conjunction) = self.closest_approach(
+                    (primary, primary_model),
+                    (secondary, secondary_model),
+                    lower,
+                    upper,
+                    step,
+                ) else {
+                    continue;
+                };
+                if conjunction.miss_distance_km > screening_distance_km {
+                    continue;
+                }
+                conjunction.id = format!(
+                    "screen-{}-{}-{}",
+                    primary_id,
+                    secondary_id,
+                    conjunction.tca.timestamp()
+                );
+                conjunction.primary_id = primary_id.clone();
+                conjunction.secondary_id = secondary_id.clone();
+                found.push(conjunction);
+            }
+        }
+
+        found.sort_by_key(|c| c.tca);
+        let count = found.len();
+        self.conjunctions.extend(found);
+        count
+    }
+
     /// Get conjunctions for a specific satellite.
     pub fn get_conjunctions_for_satellite(&self, satellite_id: &str) -> Vec<&Conjunction> {
         self.conjunctions
@@ -254,18 +1681,48 @@ impl ConjunctionAnalyzer {
         high_risk
     }
 
+    /// Conjunctions ordered by urgency, most urgent first.
+    ///
+    /// Each upcoming conjunction scores its risk level's severity (5 for
+    /// Critical down to 1 for Negligible) divided by `1 + t / scale`, where
+    /// `t` is the time to TCA and `scale` is set by
+    /// [`with_triage_time_scale_hours`](Self::with_triage_time_scale_hours).
+    /// Conjunctions whose TCA has passed come last, most recent first.
+    pub fn triage(&self) -> Vec<&Conjunction> {
+        let urgency = |c: &Conjunction| {
+            let hours = self.time_to_tca_hours(c);
+            if hours < 0.0 {
+                // Past events rank below every upcoming one
+                hours
+            } else {
+                c.risk_level.severity() / (1.0 + hours / self.triage_time_scale_hours)
+            }
+        };
+        let mut ranked: Vec<(f64, &Conjunction)> =
+            self.conjunctions.iter().map(|c| (urgency(c), c)).collect();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+        ranked.into_iter().map(|(_, c)| c).collect()
+    }
+
     /// Analyze risk for a satellite.
     ///
     /// # Arguments
     ///
     /// * `satellite_id` - Satellite to analyze
-    /// * `hours` - Analysis window in hours (default: 168 = 7 days)
+    /// * `window` - Window of closest-approach times to consider
+    ///   (default: next 168 hours = 7 days)
     ///
     /// # Returns
     ///
     /// Risk analysis summary.
-    pub fn analyze_risk(&self, satellite_id: &str, _hours: Option<f64>) -> RiskAnalysis {
-        let conjunctions = self.get_conjunctions_for_satellite(satellite_id);
+    pub fn analyze_risk(&self, satellite_id: &str, window: Option<QueryWindow>) -> RiskAnalysis {
+        let window =
+            window.unwrap_or_else(|| QueryWindow::next_hours_with(168.0, self.clock.as_ref()));
+        let conjunctions: Vec<_> = self
+            .get_conjunctions_for_satellite(satellite_id)
+            .into_iter()
+            .filter(|c| window.contains(c.tca))
+            .collect();
 
         // Count by risk level
         let mut by_risk_level = std::collections::HashMap::new();
@@ -305,6 +1762,41 @@ impl ConjunctionAnalyzer {
     }
 }
 
+/// Unit vector for a maneuver direction given the pre-burn ECI state.
+fn burn_direction(direction: &str, position: Coordinate3D, velocity: Coordinate3D) -> Coordinate3D {
+    let radial = position.normalize();
+    let normal = position.cross(&velocity).normalize();
+    let direction = direction.to_lowercase();
+    if direction.contains("radial") {
+        radial
+    } else if direction.contains("cross") || direction.contains("normal") {
+        normal
+    } else {
+        normal.cross(&radial)
+    }
+}
+
+/// Time of minimum distance within `[lower, upper]` by golden-section search.
+fn refine_minimum(
+    distance_at: &dyn Fn(DateTime<Utc>) -> f64,
+    lower: DateTime<Utc>,
+    upper: DateTime<Utc>,
+) -> DateTime<Utc> {
+    let golden = (5f64.sqrt() - 1.0) / 2.0;
+    let at = |offset_s: f64| lower + Duration::milliseconds((offset_s * 1000.0) as i64);
+    let (mut a, mut b) = (0.0, (upper - lower).num_milliseconds() as f64 / 1000.0);
+    while b - a > 1e-3 {
+        let c = b - golden * (b - a);
+        let d = a + golden * (b - a);
+        if distance_at(at(c)) < distance_at(at(d)) {
+            b = d;
+        } else {
+            a = c;
+        }
+    }
+    at((a + b) / 2.0)
+}
+
 /// Risk analysis summary.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RiskAnalysis {
@@ -327,7 +1819,165 @@ pub struct RiskAnalysis {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Duration;
+    use crate::tle::ISS_TLE;
+    use chrono::TimeZone;
+    use rotastellar::EARTH_RADIUS_KM;
+
+    const SAMPLE_CDM: &str = "\
+CCSDS_CDM_VERS = 1.0
+CREATION_DATE = 2010-03-12T22:31:12.000
+ORIGINATOR = JSPOC
+MESSAGE_ID = 201113719185
+TCA = 2010-03-13T22:37:52.618
+MISS_DISTANCE = 715 [m]
+RELATIVE_SPEED = 14762 [m/s]
+RELATIVE_POSITION_R = 27.4 [m]
+RELATIVE_POSITION_T = -70.2 [m]
+RELATIVE_POSITION_N = 711.8 [m]
+COLLISION_PROBABILITY = 4.835E-05
+COMMENT Screening Option = Covariance
+OBJECT = OBJECT1
+OBJECT_DESIGNATOR = 12345
+OBJECT_NAME = SATELLITE A
+CR_R = 4.142E+01 [m**2]
+CT_R = -8.579E+00 [m**2]
+CT_T = 2.533E+03 [m**2]
+CN_R = -2.313E+01 [m**2]
+CN_T = 1.336E+01 [m**2]
+CN_N = 7.098E+01 [m**2]
+OBJECT = OBJECT2
+OBJECT_DESIGNATOR = 30337
+OBJECT_NAME = FENGYUN 1C DEB
+CR_R = 1.337E+03 [m**2]
+CT_R = -4.806E+04 [m**2]
+CT_T = 2.492E+06 [m**2]
+CN_R = -3.298E+01 [m**2]
+CN_T = -7.5888E+02 [m**2]
+CN_N = 7.105E+01 [m**2]
+";
+
+    /// What `to_cdm_kvn` writes for the bare ISS/DEBRIS conjunction in
+    /// `test_cdm_kvn_round_trip`.
+    const BARE_CDM: &str = "\
+CCSDS_CDM_VERS = 1.0
+CREATION_DATE = 2024-02-29T12:00:00.000
+ORIGINATOR = ROTASTELLAR
+MESSAGE_ID = c-1
+TCA = 2024-03-01T12:00:00.000
+MISS_DISTANCE = 1250 [m]
+OBJECT = OBJECT1
+OBJECT_DESIGNATOR = 25544
+CATALOG_NAME = SATCAT
+OBJECT_NAME = ISS
+INTERNATIONAL_DESIGNATOR = 1998-067A
+EPHEMERIS_NAME = NONE
+COVARIANCE_METHOD = CALCULATED
+MANEUVERABLE = N/A
+REF_FRAME = ITRF
+X = 6778 [km]
+Y = 0 [km]
+Z = 0 [km]
+X_DOT = 0 [km/s]
+Y_DOT = 7.5 [km/s]
+Z_DOT = 0 [km/s]
+CR_R = 0e0 [m**2]
+CT_R = 0e0 [m**2]
+CT_T = 0e0 [m**2]
+CN_R = 0e0 [m**2]
+CN_T = 0e0 [m**2]
+CN_N = 0e0 [m**2]
+CRDOT_R = 0e0 [m**2/s]
+CRDOT_T = 0e0 [m**2/s]
+CRDOT_N = 0e0 [m**2/s]
+CRDOT_RDOT = 1e-2 [m**2/s**2]
+CTDOT_R = 0e0 [m**2/s]
+CTDOT_T = 0e0 [m**2/s]
+CTDOT_N = 0e0 [m**2/s]
+CTDOT_RDOT = 0e0 [m**2/s**2]
+CTDOT_TDOT = 1e-2 [m**2/s**2]
+CNDOT_R = 0e0 [m**2/s]
+CNDOT_T = 0e0 [m**2/s]
+CNDOT_N = 0e0 [m**2/s]
+CNDOT_RDOT = 0e0 [m**2/s**2]
+CNDOT_TDOT = 0e0 [m**2/s**2]
+CNDOT_NDOT = 1e-2 [m**2/s**2]
+OBJECT = OBJECT2
+OBJECT_DESIGNATOR = 99999
+CATALOG_NAME = SATCAT
+OBJECT_NAME = DEBRIS
+INTERNATIONAL_DESIGNATOR = 1998-067A
+EPHEMERIS_NAME = NONE
+COVARIANCE_METHOD = CALCULATED
+MANEUVERABLE = N/A
+REF_FRAME = ITRF
+X = 6778 [km]
+Y = 0 [km]
+Z = 0 [km]
+X_DOT = 0 [km/s]
+Y_DOT = 7.5 [km/s]
+Z_DOT = 0 [km/s]
+CR_R = 0e0 [m**2]
+CT_R = 0e0 [m**2]
+CT_T = 0e0 [m**2]
+CN_R = 0e0 [m**2]
+CN_T = 0e0 [m**2]
+CN_N = 0e0 [m**2]
+CRDOT_R = 0e0 [m**2/s]
+CRDOT_T = 0e0 [m**2/s]
+CRDOT_N = 0e0 [m**2/s]
+CRDOT_RDOT = 1e-2 [m**2/s**2]
+CTDOT_R = 0e0 [m**2/s]
+CTDOT_T = 0e0 [m**2/s]
+CTDOT_N = 0e0 [m**2/s]
+CTDOT_RDOT = 0e0 [m**2/s**2]
+CTDOT_TDOT = 1e-2 [m**2/s**2]
+CNDOT_R = 0e0 [m**2/s]
+CNDOT_T = 0e0 [m**2/s]
+CNDOT_N = 0e0 [m**2/s]
+CNDOT_RDOT = 0e0 [m**2/s**2]
+CNDOT_TDOT = 0e0 [m**2/s**2]
+CNDOT_NDOT = 1e-2 [m**2/s**2]
+";
+
+    #[test]
+    fn test_from_cdm_kvn() {
+        let conj = Conjunction::from_cdm_kvn(SAMPLE_CDM).unwrap();
+        assert_eq!(conj.id, "201113719185");
+        assert_eq!(
+            (conj.primary_id.as_str(), conj.primary_name.as_str()),
+            ("12345", "SATELLITE A")
+        );
+        assert_eq!(
+            (conj.secondary_id.as_str(), conj.secondary_name.as_str()),
+            ("30337", "FENGYUN 1C DEB")
+        );
+        assert_eq!(
+            conj.tca.format("%Y-%m-%dT%H:%M:%S%.3f").to_string(),
+            "2010-03-13T22:37:52.618"
+        );
+        assert!((conj.miss_distance_km - 0.715).abs() < 1e-12);
+        assert!((conj.relative_velocity_km_s.unwrap() - 14.762).abs() < 1e-12);
+        assert_eq!(conj.risk_level, RiskLevel::High);
+
+        // RTN components reassemble the miss distance
+        let ric = Coordinate3D::new(
+            conj.miss_distance_radial_km.unwrap(),
+            conj.miss_distance_in_track_km.unwrap(),
+            conj.miss_distance_cross_track_km.unwrap(),
+        );
+        assert!((ric.magnitude() - conj.miss_distance_km).abs() < 1e-3);
+
+        let cov = conj.secondary_covariance.unwrap().to_matrix();
+        assert!((cov[1][1] - 2.492).abs() < 1e-12);
+        assert!((cov[1][0] + 4.806e-2).abs() < 1e-12);
+        assert!(conj.primary_covariance.is_some());
+
+        let missing_tca = SAMPLE_CDM.replace("TCA = 2010-03-13T22:37:52.618\n", "");
+        assert_eq!(
+            Conjunction::from_cdm_kvn(&missing_tca).unwrap_err().field,
+            "tca"
+        );
+    }
 
     #[test]
     fn test_risk_level_from_str() {
@@ -336,6 +1986,36 @@ mod tests {
         assert_eq!(RiskLevel::from_str("invalid"), Err(()));
     }
 
+    #[test]
+    fn test_risk_level_from_probability_boundaries() {
+        // Each threshold is exclusive: exactly 1e-4 is still High
+        assert_eq!(RiskLevel::from_probability(1.0), RiskLevel::Critical);
+        assert_eq!(RiskLevel::from_probability(1.01e-4), RiskLevel::Critical);
+        assert_eq!(RiskLevel::from_probability(1e-4), RiskLevel::High);
+        assert_eq!(RiskLevel::from_probability(1.01e-5), RiskLevel::High);
+        assert_eq!(RiskLevel::from_probability(1e-5), RiskLevel::Medium);
+        assert_eq!(RiskLevel::from_probability(1.01e-6), RiskLevel::Medium);
+        assert_eq!(RiskLevel::from_probability(1e-6), RiskLevel::Low);
+        assert_eq!(RiskLevel::from_probability(1.01e-7), RiskLevel::Low);
+        assert_eq!(RiskLevel::from_probability(1e-7), RiskLevel::Negligible);
+        assert_eq!(RiskLevel::from_probability(0.0), RiskLevel::Negligible);
+    }
+
+    #[test]
+    fn test_reclassify_from_pc() {
+        let mut conj = Conjunction::new("c", "p", "P", "s", "S", Utc::now(), 0.5, RiskLevel::Low);
+        conj.reclassify_from_pc();
+        assert_eq!(conj.risk_level, RiskLevel::Low);
+
+        conj.collision_probability = Some(3e-4);
+        conj.reclassify_from_pc();
+        assert_eq!(conj.risk_level, RiskLevel::Critical);
+
+        conj.collision_probability = Some(2e-8);
+        conj.reclassify_from_pc();
+        assert_eq!(conj.risk_level, RiskLevel::Negligible);
+    }
+
     #[test]
     fn test_conjunction_is_high_risk() {
         let tca = Utc::now() + Duration::hours(24);
@@ -380,5 +2060,873 @@ mod tests {
         assert_eq!(analysis.critical_count, 1);
         assert!(analysis.requires_attention);
         assert!((analysis.closest_approach_km.unwrap() - 0.5).abs() < 0.01);
+
+        let past = analyzer.analyze_risk("sat-1", Some(QueryWindow::last_hours(24.0)));
+        assert_eq!(past.total_conjunctions, 0);
+        assert!(!past.requires_attention);
+    }
+
+    #[test]
+    fn test_analyze_risk_horizon() {
+        let now = screening_epoch();
+        let clock = crate::clock::FixedClock::new(now);
+        let mut analyzer = ConjunctionAnalyzer::new().with_clock(clock);
+        analyzer.add_conjunction(Conjunction::new(
+            "near",
+            "sat-1",
+            "Satellite 1",
+            "sat-2",
+            "Satellite 2",
+            now + Duration::hours(12),
+            3.0,
+            RiskLevel::Medium,
+        ));
+        analyzer.add_conjunction(Conjunction::new(
+            "far",
+            "sat-1",
+            "Satellite 1",
+            "sat-3",
+            "Satellite 3",
+            now + Duration::hours(200),
+            0.2,
+            RiskLevel::Critical,
+        ));
+
+        // The far conjunction is beyond even the default 168-hour horizon
+        let default = analyzer.analyze_risk("sat-1", None);
+        assert_eq!(default.total_conjunctions, 1);
+        assert!(!default.requires_attention);
+
+        let day = analyzer.analyze_risk("sat-1", Some(QueryWindow::next_hours_with(24.0, &clock)));
+        assert_eq!(day.total_conjunctions, 1);
+        assert_eq!(day.closest_approach_km, Some(3.0));
+
+        let fortnight =
+            analyzer.analyze_risk("sat-1", Some(QueryWindow::next_hours_with(336.0, &clock)));
+        assert_eq!(fortnight.total_conjunctions, 2);
+        assert_eq!(fortnight.critical_count, 1);
+    }
+
+    #[test]
+    fn test_time_to_tca_fixed_clock() {
+        use crate::clock::FixedClock;
+        use chrono::TimeZone;
+
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let analyzer = ConjunctionAnalyzer::new().with_clock(FixedClock::new(now));
+        let conj = Conjunction::new(
+            "conj-1",
+            "sat-1",
+            "Satellite 1",
+            "sat-2",
+            "Satellite 2",
+            now + Duration::minutes(90),
+            0.5,
+            RiskLevel::High,
+        );
+
+        assert_eq!(analyzer.time_to_tca_hours(&conj), 1.5);
+        assert_eq!(
+            conj.time_to_tca_hours_with(&FixedClock::new(now + Duration::hours(3))),
+            -1.5
+        );
+    }
+
+    #[test]
+    fn test_write_jsonl() {
+        let mut analyzer = ConjunctionAnalyzer::new();
+        let tca = Utc::now() + Duration::hours(6);
+        analyzer.add_conjunction(Conjunction::new(
+            "conj-1",
+            "sat-1",
+            "Satellite 1",
+            "sat-2",
+            "Satellite 2",
+            tca,
+            0.5,
+            RiskLevel::Critical,
+        ));
+        let mut second = Conjunction::new(
+            "conj-2",
+            "sat-1",
+            "Satellite 1",
+            "deb-9",
+            "Debris 9",
+            tca,
+            3.0,
+            RiskLevel::Low,
+        );
+        second.collision_probability = Some(1e-6);
+        analyzer.add_conjunction(second);
+
+        let mut buf = Vec::new();
+        analyzer.write_jsonl(&mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), analyzer.get_conjunctions().len());
+        for (line, original) in lines.iter().zip(analyzer.get_conjunctions()) {
+            let parsed: Conjunction = serde_json::from_str(line).unwrap();
+            assert_eq!(&parsed, original);
+        }
+        assert!(lines[0].contains(&tca.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)));
+    }
+
+    #[test]
+    fn test_pc_grows_with_hard_body_radius() {
+        let tca = Utc::now() + Duration::hours(12);
+        let mut small = Conjunction::new(
+            "conj-1",
+            "sat-1",
+            "Satellite 1",
+            "deb-1",
+            "Debris 1",
+            tca,
+            0.2,
+            RiskLevel::Medium,
+        )
+        .with_object_classes(ObjectClass::CubeSat, ObjectClass::Debris);
+        let mut large = small
+            .clone()
+            .with_object_classes(ObjectClass::LargeSatellite, ObjectClass::Debris);
+
+        let pc_small = small.compute_pc(0.1);
+        let pc_large = large.compute_pc(0.1);
+        assert!(pc_large > pc_small);
+        assert_eq!(small.hard_body_radius_m, Some(1.5));
+        assert_eq!(large.hard_body_radius_m, Some(6.0));
+        assert_eq!(large.collision_probability, Some(pc_large));
+
+        // Small-radius limit: R² / (2σ²) · exp(-d² / (2σ²))
+        let expected = 0.0015f64.powi(2) / (2.0 * 0.01) * (-0.04f64 / 0.02).exp();
+        assert!((pc_small / expected - 1.0).abs() < 0.01);
+    }
+
+    /// Primary's state at `t` after an in-track burn at `burn_time`: its SGP4
+    /// state plus the two-body drift the burn adds.
+    fn post_burn_state(
+        primary: &TLE,
+        burn_time: DateTime<Utc>,
+        delta_v_m_s: f64,
+        t: DateTime<Utc>,
+    ) -> (Coordinate3D, Coordinate3D) {
+        let model = primary.sgp4_propagator().unwrap();
+        let (r0, v0) = model.state(burn_time).unwrap();
+        let (r0, v0) = (Coordinate3D::from(r0), Coordinate3D::from(v0));
+        let dv = burn_direction("in-track", r0, v0) * (delta_v_m_s / 1000.0);
+        let coasting = primary.with_eci_state(burn_time, r0.into(), v0.into());
+        let burned = primary.with_eci_state(burn_time, r0.into(), (v0 + dv).into());
+        let two_body = PropagationAccuracy::TwoBody;
+        let position = |tle: &TLE| Coordinate3D::from(tle.eci_position_km(t, two_body).unwrap());
+        let velocity = |tle: &TLE| Coordinate3D::from(tle.eci_velocity_km_s(t, two_body).unwrap());
+        let (r, v) = model.state(t).unwrap();
+        (
+            Coordinate3D::from(r) + position(&burned) - position(&coasting),
+            Coordinate3D::from(v) + velocity(&burned) - velocity(&coasting),
+        )
+    }
+
+    /// Element set whose SGP4 state at `time` is `position`/`velocity`,
+    /// found by correcting the osculating state until SGP4 reproduces it.
+    fn sgp4_object(
+        template: &TLE,
+        time: DateTime<Utc>,
+        position: Coordinate3D,
+        velocity: Coordinate3D,
+        norad_id: u32,
+    ) -> TLE {
+        let (mut r, mut v) = (position, velocity);
+        let mut object = template.with_eci_state(time, r.into(), v.into());
+        for _ in 0..20 {
+            let (r_sgp4, v_sgp4) = object.sgp4_propagator().unwrap().state(time).unwrap();
+            r = r + (position - r_sgp4.into());
+            v = v + (velocity - v_sgp4.into());
+            object = template.with_eci_state(time, r.into(), v.into());
+        }
+        object.norad_id = norad_id;
+        object.name = format!("OBJECT {}", norad_id);
+        object
+    }
+
+    /// Object whose SGP4 track passes through `r` at `time`, in a plane at
+    /// right angles to `v` and with a slightly different period.
+    fn crossing_object(
+        template: &TLE,
+        time: DateTime<Utc>,
+        (r, v): (Coordinate3D, Coordinate3D),
+        norad_id: u32,
+    ) -> TLE {
+        let turned = r.normalize().cross(&v);
+        sgp4_object(template, time, r, turned * 1.02, norad_id)
+    }
+
+    fn in_track_maneuver(burn_time: DateTime<Utc>, delta_v_m_s: f64) -> ManeuverRecommendation {
+        ManeuverRecommendation {
+            conjunction_id: "conj-1".to_string(),
+            maneuver_time: burn_time,
+            delta_v_m_s,
+            direction: "in-track".to_string(),
+            post_maneuver_miss_km: 10.0,
+            post_maneuver_probability: 1e-8,
+            fuel_required_kg: None,
+            confidence: 0.9,
+        }
+    }
+
+    #[test]
+    fn test_screen_post_maneuver() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let primary = TLE::parse(&lines).unwrap();
+        let burn_time = primary.epoch() + Duration::minutes(10);
+
+        // The burn avoids `threat` but puts the primary on a collision course with `bystander`
+        let encounter = burn_time + Duration::hours(20);
+        let analyzer = ConjunctionAnalyzer::new();
+        let threat = crossing_object(
+            &primary,
+            encounter,
+            post_burn_state(&primary, burn_time, 0.0, encounter),
+            90001,
+        );
+        let bystander = crossing_object(
+            &primary,
+            encounter,
+            post_burn_state(&primary, burn_time, 1.0, encounter),
+            90002,
+        );
+        let catalog = vec![primary.clone(), threat, bystander];
+
+        let introduced = analyzer
+            .screen_post_maneuver(
+                &primary,
+                &in_track_maneuver(burn_time, 1.0),
+                &catalog,
+                24.0,
+                2.0,
+            )
+            .unwrap();
+        assert_eq!(introduced.len(), 1);
+        let conjunction = &introduced[0];
+        assert_eq!(conjunction.secondary_id, "90002");
+        assert!(conjunction.miss_distance_km < 0.1);
+        assert!((conjunction.tca - encounter).num_seconds().abs() <= 1);
+        assert!(conjunction.relative_velocity_km_s.unwrap() > 5.0);
+        assert_eq!(
+            conjunction.encounter_geometry(),
+            Some(EncounterGeometry::Crossing)
+        );
+        let pc = conjunction.collision_probability.unwrap();
+        assert_eq!(conjunction.risk_level, RiskLevel::from_probability(pc));
+        assert!(pc > 1e-6);
+
+        // Without the burn only the original threat is found
+        let unmaneuvered = analyzer
+            .screen_post_maneuver(
+                &primary,
+                &in_track_maneuver(burn_time, 0.0),
+                &catalog,
+                24.0,
+                2.0,
+            )
+            .unwrap();
+        assert_eq!(unmaneuvered.len(), 1);
+        assert_eq!(unmaneuvered[0].secondary_id, "90001");
+    }
+
+    #[test]
+    fn test_unburned_screen_matches_catalog_screen() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let primary = TLE::parse(&lines).unwrap();
+        let burn_time = primary.epoch() + Duration::minutes(10);
+        let mut catalog = vec![primary.clone()];
+        for (hours, norad_id) in [(3, 90001), (13, 90002), (23, 90003)] {
+            let encounter = burn_time + Duration::hours(hours) + Duration::seconds(17);
+            let (r, v) = primary.sgp4_propagator().unwrap().state(encounter).unwrap();
+            catalog.push(crossing_object(
+                &primary,
+                encounter,
+                (r.into(), v.into()),
+                norad_id,
+            ));
+        }
+
+        let analyzer = ConjunctionAnalyzer::new();
+        let screened = analyzer
+            .screen_post_maneuver(
+                &primary,
+                &in_track_maneuver(burn_time, 0.0),
+                &catalog,
+                24.0,
+                2.0,
+            )
+            .unwrap();
+
+        let mut reference = ConjunctionAnalyzer::new();
+        let named: Vec<(String, TLE)> = catalog
+            .iter()
+            .map(|tle| (tle.norad_id.to_string(), tle.clone()))
+            .collect();
+        reference.screen_catalog(&named, burn_time, burn_time + Duration::hours(24), 60, 2.0);
+        let expected = reference.get_conjunctions_for_satellite(&primary.norad_id.to_string());
+
+        assert_eq!(screened.len(), 3);
+        assert_eq!(screened.len(), expected.len());
+        for (found, want) in screened.iter().zip(expected) {
+            assert_eq!(found.secondary_id, want.secondary_id);
+            assert!((found.tca - want.tca).num_milliseconds().abs() <= 500);
+            assert!((found.miss_distance_km - want.miss_distance_km).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_head_on_encounter() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let primary = TLE::parse(&lines).unwrap();
+        let encounter = primary.epoch() + Duration::minutes(30);
+        let (r, v) = primary.sgp4_propagator().unwrap().state(encounter).unwrap();
+        let opposing = sgp4_object(
+            &primary,
+            encounter,
+            r.into(),
+            Coordinate3D::from(v) * -1.0,
+            90003,
+        );
+
+        let maneuver = in_track_maneuver(primary.epoch(), 0.0);
+        let found = ConjunctionAnalyzer::new()
+            .screen_post_maneuver(&primary, &maneuver, &[opposing], 1.0, 1.0)
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        let conjunction = &found[0];
+        let relative = conjunction.relative_velocity_km_s.unwrap();
+        assert!(
+            (relative - 2.0 * Coordinate3D::from(v).magnitude()).abs() < 0.05,
+            "{}",
+            relative
+        );
+        assert!(conjunction.encounter_angle_deg.unwrap() > 179.0);
+        assert_eq!(
+            conjunction.encounter_geometry(),
+            Some(EncounterGeometry::HeadOn)
+        );
+    }
+
+    #[test]
+    fn test_compute_pc_2d() {
+        let diag = |r: f64, i: f64, c: f64| [[r, 0.0, 0.0], [0.0, i, 0.0], [0.0, 0.0, c]];
+        let miss = [0.0, 0.0, 0.2];
+        let velocity = [0.0, -14.0, 0.0];
+
+        // Spherical covariance matches the 1D-sigma method
+        let sphere = diag(0.005, 0.005, 0.005);
+        let pc = ConjunctionAnalyzer::compute_pc_2d(sphere, sphere, miss, velocity, 10.0);
+        assert!((pc / compute_pc(0.2, 0.1, 10.0) - 1.0).abs() < 1e-9);
+
+        // Along-velocity (in-track) uncertainty drops out of the encounter plane
+        let stretched = diag(0.005, 4.0, 0.005);
+        let pc_stretched =
+            ConjunctionAnalyzer::compute_pc_2d(stretched, sphere, miss, velocity, 10.0);
+        assert!((pc_stretched / pc - 1.0).abs() < 1e-9);
+
+        // Small-disk limit of an elongated Gaussian: πR²·N(miss; 0, C)
+        let (sigma_r, sigma_c) = (0.05, 0.3);
+        let primary = diag(sigma_r * sigma_r, 0.01, sigma_c * sigma_c);
+        let zero = [[0.0; 3]; 3];
+        let pc = ConjunctionAnalyzer::compute_pc_2d(primary, zero, miss, velocity, 2.0);
+        let density = (-0.5 * (0.2f64 / sigma_c).powi(2)).exp()
+            / (2.0 * std::f64::consts::PI * sigma_r * sigma_c);
+        let expected = std::f64::consts::PI * 0.002f64.powi(2) * density;
+        assert!((pc / expected - 1.0).abs() < 0.01, "{} vs {}", pc, expected);
+
+        // The same miss along the tight radial axis is 4σ out, so far less likely
+        let radial_miss =
+            ConjunctionAnalyzer::compute_pc_2d(primary, zero, [0.2, 0.0, 0.0], velocity, 2.0);
+        assert!(radial_miss < pc * 1e-3);
+
+        // Correlation between radial and cross-track tilts the ellipse toward a diagonal miss
+        let tilted = [
+            [0.0425, 0.0, 0.0375],
+            [0.0, 0.01, 0.0],
+            [0.0375, 0.0, 0.0425],
+        ];
+        let along_major =
+            ConjunctionAnalyzer::compute_pc_2d(tilted, zero, [0.15, 0.0, 0.15], velocity, 2.0);
+        let across_major =
+            ConjunctionAnalyzer::compute_pc_2d(tilted, zero, [0.15, 0.0, -0.15], velocity, 2.0);
+        assert!(along_major > 10.0 * across_major);
+
+        assert_eq!(
+            ConjunctionAnalyzer::compute_pc_2d(sphere, sphere, miss, [0.0; 3], 10.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_compute_pc_2d_cara_benchmark() {
+        // NASA CARA Pc2D_Foster unit-test case: ECI states (km, km/s) and
+        // covariances (km²) with a 20 m hard-body radius, Pc = 2.7060234765697e-05.
+        // The method only needs one common frame, so ECI stands in for RIC.
+        let r1 = [378.39559, 4305.721887, 5752.767554];
+        let v1 = [2.360800244, 5.580331936, -4.322349039];
+        let r2 = [374.5180598, 4307.560983, 5751.130418];
+        let v2 = [-5.388125081, -3.946827739, 3.322820358];
+        let primary = [
+            [44.5757544811362, 81.6751751052616, -67.8687662707124],
+            [81.6751751052616, 158.453402956163, -128.616921644857],
+            [-67.8687662707124, -128.616921644857, 105.490542562701],
+        ];
+        let secondary = [
+            [2.31067077720423, 1.69905293875632, -1.4170164577661],
+            [1.69905293875632, 1.24957388457206, -1.04174164279599],
+            [-1.4170164577661, -1.04174164279599, 0.869260558223714],
+        ];
+        let miss = [r2[0] - r1[0], r2[1] - r1[1], r2[2] - r1[2]];
+        let velocity = [v2[0] - v1[0], v2[1] - v1[1], v2[2] - v1[2]];
+
+        let pc = ConjunctionAnalyzer::compute_pc_2d(primary, secondary, miss, velocity, 20.0);
+        let expected = 2.7060234765697e-05;
+        assert!(
+            (pc / expected - 1.0).abs() < 0.02,
+            "{:e} vs {:e}",
+            pc,
+            expected
+        );
+    }
+
+    fn screening_epoch() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    /// Circular orbit reaching its ascending node (RAAN 0) 15 minutes after
+    /// [`screening_epoch`].
+    fn node_crossing_orbit(norad_id: u32, inclination_deg: f64, altitude_km: f64) -> TLE {
+        let a = EARTH_RADIUS_KM + altitude_km;
+        let period_s = 2.0 * std::f64::consts::PI * (a.powi(3) / EARTH_MU).sqrt();
+        TLE::builder()
+            .norad_id(norad_id)
+            .epoch(screening_epoch())
+            .semi_major_axis_km(a)
+            .inclination_deg(inclination_deg)
+            .mean_anomaly_deg(360.0 - 360.0 * 900.0 / period_s)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_screen_pair() {
+        // Prograde and retrograde orbits sharing an ascending node. Mirrored
+        // inclinations see the same J2 short-period terms, so the orbits truly
+        // intersect.
+        let epoch = screening_epoch();
+        let node_time = epoch + Duration::minutes(15);
+        let prograde = node_crossing_orbit(90001, 45.0, 550.0);
+        let retrograde = node_crossing_orbit(90002, 135.0, 550.0);
+
+        let analyzer = ConjunctionAnalyzer::new();
+        let conj = analyzer
+            .screen_pair(
+                &prograde,
+                &retrograde,
+                epoch,
+                epoch + Duration::hours(1),
+                60,
+            )
+            .unwrap()
+            .unwrap();
+        assert!(
+            (conj.tca - node_time).num_seconds().abs() <= 2,
+            "TCA {}",
+            conj.tca
+        );
+        assert!(
+            conj.miss_distance_km < 1.0,
+            "miss {}",
+            conj.miss_distance_km
+        );
+        // Perpendicular crossing at ~7.6 km/s each
+        assert!((conj.relative_velocity_km_s.unwrap() - 7.59 * 2f64.sqrt()).abs() < 0.2);
+        assert_eq!(
+            conj.risk_level,
+            RiskLevel::from_miss_distance_km(conj.miss_distance_km)
+        );
+
+        // The refined TCA beats every point of a fine scan around it
+        let (a, b) = (
+            prograde.sgp4_propagator().unwrap(),
+            retrograde.sgp4_propagator().unwrap(),
+        );
+        let range_at = |t: DateTime<Utc>| {
+            (Coordinate3D::from(a.state(t).unwrap().0) - b.state(t).unwrap().0.into()).magnitude()
+        };
+        for ms in (-2000..=2000).step_by(100) {
+            assert!(
+                conj.miss_distance_km <= range_at(conj.tca + Duration::milliseconds(ms)) + 1e-6
+            );
+        }
+
+        assert!(analyzer
+            .screen_pair(&prograde, &retrograde, epoch, epoch, 60)
+            .unwrap()
+            .is_none());
+
+        // Risk comes from the analyzer's thresholds, not a fixed scale
+        let strict =
+            ConjunctionAnalyzer::new().with_miss_distance_thresholds_km([0.0, 0.0, 0.0, 0.0]);
+        let graded = strict
+            .screen_pair(
+                &prograde,
+                &retrograde,
+                epoch,
+                epoch + Duration::hours(1),
+                60,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(graded.risk_level, RiskLevel::Negligible);
+
+        // SGP4 failures are reported instead of falling back to another model
+        let mut hyperbolic = retrograde.clone();
+        hyperbolic.eccentricity = 1.2;
+        let error = analyzer
+            .screen_pair(
+                &prograde,
+                &hyperbolic,
+                epoch,
+                epoch + Duration::hours(1),
+                60,
+            )
+            .unwrap_err();
+        assert_eq!(error.field, "eccentricity");
+    }
+
+    #[test]
+    fn test_screen_catalog() {
+        let epoch = screening_epoch();
+        let end = epoch + Duration::minutes(30);
+
+        // Separate altitude shells, both crossing the same node
+        let shells = vec![
+            ("low".to_string(), node_crossing_orbit(90001, 45.0, 400.0)),
+            ("high".to_string(), node_crossing_orbit(90002, 135.0, 800.0)),
+        ];
+        let mut analyzer = ConjunctionAnalyzer::new();
+        assert_eq!(analyzer.screen_catalog(&shells, epoch, end, 60, 10.0), 0);
+        assert!(analyzer.get_conjunctions().is_empty());
+
+        let mut catalog = shells;
+        catalog.push(("pro".to_string(), node_crossing_orbit(90003, 45.0, 550.0)));
+        catalog.push((
+            "retro".to_string(),
+            node_crossing_orbit(90004, 135.0, 550.0),
+        ));
+        assert_eq!(analyzer.screen_catalog(&catalog, epoch, end, 60, 10.0), 1);
+
+        let conj = &analyzer.get_conjunctions()[0];
+        assert_eq!(
+            (conj.primary_id.as_str(), conj.secondary_id.as_str()),
+            ("pro", "retro")
+        );
+        assert!(
+            (conj.tca - (epoch + Duration::minutes(15)))
+                .num_seconds()
+                .abs()
+                <= 2
+        );
+        assert!(conj.miss_distance_km < 1.0);
+    }
+
+    /// CDM data at `tca` with the given position covariance (km²) and
+    /// 0.1 m/s velocity sigmas.
+    fn cdm_object(
+        designator: &str,
+        tca: DateTime<Utc>,
+        position_covariance: [[f64; 3]; 3],
+    ) -> CdmObject {
+        let mut covariance_rtn = [[0.0; 6]; 6];
+        for (row, position_row) in covariance_rtn.iter_mut().zip(position_covariance) {
+            row[..3].copy_from_slice(&position_row);
+        }
+        for (axis, row) in covariance_rtn.iter_mut().enumerate().skip(3) {
+            row[axis] = 1e-8;
+        }
+        let state = StateVector {
+            time: tca,
+            frame: Frame::Ecef,
+            position_km: [6778.0, 0.0, 0.0],
+            velocity_km_s: [0.0, 7.5, 0.0],
+        };
+        CdmObject::new(designator, state, covariance_rtn)
+    }
+
+    #[test]
+    fn test_cdm_kvn_round_trip() {
+        let original = Conjunction::from_cdm_kvn(SAMPLE_CDM).unwrap();
+        let primary = cdm_object(
+            "2005-001A",
+            original.tca,
+            original.primary_covariance.unwrap().to_matrix(),
+        );
+        let secondary = cdm_object(
+            "1999-025AAA",
+            original.tca,
+            original.secondary_covariance.unwrap().to_matrix(),
+        );
+        let text = original
+            .to_cdm_kvn(original.created_at.unwrap(), &primary, &secondary)
+            .unwrap();
+        assert!(text.contains("TCA = 2010-03-13T22:37:52.618\n"));
+        assert!(text.contains("CREATION_DATE = 2010-03-12T22:31:12.000\n"));
+        let required = [
+            "OBJECT_DESIGNATOR",
+            "CATALOG_NAME",
+            "OBJECT_NAME",
+            "INTERNATIONAL_DESIGNATOR",
+            "EPHEMERIS_NAME",
+            "COVARIANCE_METHOD",
+            "MANEUVERABLE",
+            "REF_FRAME",
+            "X",
+            "Y",
+            "Z",
+            "X_DOT",
+            "Y_DOT",
+            "Z_DOT",
+            "CR_R",
+            "CT_R",
+            "CT_T",
+            "CN_R",
+            "CN_T",
+            "CN_N",
+            "CRDOT_R",
+            "CRDOT_T",
+            "CRDOT_N",
+            "CRDOT_RDOT",
+            "CTDOT_R",
+            "CTDOT_T",
+            "CTDOT_N",
+            "CTDOT_RDOT",
+            "CTDOT_TDOT",
+            "CNDOT_R",
+            "CNDOT_T",
+            "CNDOT_N",
+            "CNDOT_RDOT",
+            "CNDOT_TDOT",
+            "CNDOT_NDOT",
+        ];
+        for key in required {
+            let count = text
+                .lines()
+                .filter(|line| line.starts_with(&format!("{} = ", key)))
+                .count();
+            assert_eq!(count, 2, "{} should appear once per object", key);
+        }
+        assert!(text.contains("INTERNATIONAL_DESIGNATOR = 1999-025AAA\n"));
+        assert!(text.contains("REF_FRAME = ITRF\n"));
+        assert!(text.contains("X = 6778 [km]\n"));
+        assert!(text.contains("Y_DOT = 7.5 [km/s]\n"));
+        assert!(text.contains("CNDOT_NDOT = 1e-2 [m**2/s**2]\n"));
+
+        let parsed = Conjunction::from_cdm_kvn(&text).unwrap();
+        assert_eq!(parsed.tca, original.tca);
+        assert!((parsed.miss_distance_km - original.miss_distance_km).abs() < 1e-9);
+        assert!((parsed.relative_velocity_km_s.unwrap() - 14.762).abs() < 1e-9);
+        assert!((parsed.miss_distance_cross_track_km.unwrap() - 0.7118).abs() < 1e-9);
+        assert_eq!(parsed.created_at, original.created_at);
+        assert_eq!(parsed.collision_probability, original.collision_probability);
+        assert_eq!(
+            (parsed.primary_id.as_str(), parsed.secondary_name.as_str()),
+            ("12345", "FENGYUN 1C DEB")
+        );
+        let (a, b) = (
+            original.secondary_covariance.unwrap().to_matrix(),
+            parsed.secondary_covariance.unwrap().to_matrix(),
+        );
+        assert!((a[1][0] - b[1][0]).abs() < 1e-12 && (a[1][1] - b[1][1]).abs() < 1e-12);
+
+        // Missing or inconsistent object data is rejected rather than filled in
+        let tca = screening_epoch();
+        let bare = Conjunction::new(
+            "c-1",
+            "25544",
+            "ISS",
+            "99999",
+            "DEBRIS",
+            tca,
+            1.25,
+            RiskLevel::High,
+        );
+        let known = cdm_object("1998-067A", tca, [[0.0; 3]; 3]);
+        let unnamed = cdm_object(" ", tca, [[0.0; 3]; 3]);
+        assert_eq!(
+            bare.to_cdm_kvn(tca, &unnamed, &known).unwrap_err().field,
+            "international_designator"
+        );
+        let stale = cdm_object("1998-067A", tca - Duration::minutes(1), [[0.0; 3]; 3]);
+        assert_eq!(
+            bare.to_cdm_kvn(tca, &known, &stale).unwrap_err().field,
+            "state"
+        );
+        let mut asymmetric = known.clone();
+        asymmetric.covariance_rtn[3][0] = 1e-6;
+        assert_eq!(
+            bare.to_cdm_kvn(tca, &known, &asymmetric).unwrap_err().field,
+            "covariance_rtn"
+        );
+
+        // A bare conjunction with complete object data writes the mandatory fields, and a zero
+        // covariance is read back as given
+        let text = bare
+            .to_cdm_kvn(tca - Duration::days(1), &known, &known)
+            .unwrap();
+        assert_eq!(text, BARE_CDM);
+        let parsed = Conjunction::from_cdm_kvn(&text).unwrap();
+        assert_eq!(parsed.tca, tca);
+        assert_eq!(parsed.created_at, Some(tca - Duration::days(1)));
+        assert_eq!(
+            parsed.primary_covariance,
+            Some(Covariance::from_sigmas(0.0, 0.0, 0.0))
+        );
+        assert!((parsed.miss_distance_km - 1.25).abs() < 1e-12);
+        assert!(parsed.relative_velocity_km_s.is_none());
+    }
+
+    #[test]
+    fn test_max_pc() {
+        let diag = |r: f64, i: f64, c: f64| [[r, 0.0, 0.0], [0.0, i, 0.0], [0.0, 0.0, c]];
+        let zero = [[0.0; 3]; 3];
+        let miss = [0.0, 0.0, 1.0];
+        let velocity = [0.0, -14.0, 0.0];
+
+        // 50 m sigmas against a 1 km miss: nominal Pc is vanishingly small
+        let tight = diag(0.0025, 0.0025, 0.0025);
+        let nominal = ConjunctionAnalyzer::compute_pc_2d(tight, zero, miss, velocity, 10.0);
+        let worst = ConjunctionAnalyzer::max_pc(tight, zero, miss, velocity, 10.0);
+        assert!(worst > nominal * 1e6, "{} vs {}", worst, nominal);
+
+        // Isotropic small-disk maximum: σ = d/√2 gives R²/(e·d²)
+        let expected = 0.01f64.powi(2) / (std::f64::consts::E * 1.0);
+        assert!(
+            (worst / expected - 1.0).abs() < 0.01,
+            "{} vs {}",
+            worst,
+            expected
+        );
+
+        // Already in the dilution region: shrinking the covariance raises Pc
+        let loose = diag(100.0, 100.0, 100.0);
+        let diluted = ConjunctionAnalyzer::compute_pc_2d(loose, zero, miss, velocity, 10.0);
+        assert!(ConjunctionAnalyzer::max_pc(loose, zero, miss, velocity, 10.0) > diluted * 50.0);
+    }
+
+    #[test]
+    fn test_triage() {
+        let now = screening_epoch();
+        let clock = crate::clock::FixedClock::new(now);
+        let mut analyzer = ConjunctionAnalyzer::new().with_clock(clock);
+        let mut add = |id: &str, hours: i64, risk: RiskLevel| {
+            analyzer.add_conjunction(Conjunction::new(
+                id,
+                "sat-1",
+                "Satellite 1",
+                "sat-2",
+                "Satellite 2",
+                now + Duration::hours(hours),
+                1.0,
+                risk,
+            ));
+        };
+        add("far-critical", 150, RiskLevel::Critical);
+        add("past-critical", -2, RiskLevel::Critical);
+        add("far-negligible", 300, RiskLevel::Negligible);
+        add("soon-high", 2, RiskLevel::High);
+        add("soon-critical", 1, RiskLevel::Critical);
+
+        let ids =
+            |a: &ConjunctionAnalyzer| a.triage().iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            ids(&analyzer),
+            [
+                "soon-critical",
+                "soon-high",
+                "far-critical",
+                "far-negligible",
+                "past-critical"
+            ]
+        );
+
+        // With time barely weighted, risk level dominates
+        let analyzer = analyzer.with_triage_time_scale_hours(1e6);
+        assert_eq!(
+            ids(&analyzer),
+            [
+                "soon-critical",
+                "far-critical",
+                "soon-high",
+                "far-negligible",
+                "past-critical"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_covariance() {
+        let cov = Covariance::from_sigmas(0.1, 2.0, 0.3).with_correlations(0.2, -0.4, 0.5);
+        let matrix = cov.to_matrix();
+        assert!((matrix[1][1] - 4.0).abs() < 1e-12);
+        assert!((matrix[0][2] + 0.4 * 0.1 * 0.3).abs() < 1e-12);
+        assert_eq!(matrix[1][2], matrix[2][1]);
+
+        let round_trip = Covariance::from_matrix(matrix);
+        assert!((round_trip.in_track_cross_track - 0.5).abs() < 1e-12);
+        assert!((round_trip.radial_km2 - 0.01).abs() < 1e-12);
+
+        // In-track velocity: the plane keeps cross-track (along the miss) and radial
+        let plane = Covariance::from_sigmas(0.1, 2.0, 0.3)
+            .to_encounter_plane([0.0, 0.0, 0.2], [0.0, -14.0, 0.0])
+            .unwrap();
+        assert!((plane[0][0] - 0.09).abs() < 1e-12);
+        assert!((plane[1][1] - 0.01).abs() < 1e-12);
+        assert!(plane[0][1].abs() < 1e-12);
+        assert!(cov.to_encounter_plane([0.0, 0.0, 0.2], [0.0; 3]).is_none());
+    }
+
+    #[test]
+    fn test_decompose_miss() {
+        let mut conj = Conjunction::new("c", "p", "P", "s", "S", Utc::now(), 0.715, RiskLevel::Low);
+        // CDM-style components rounded to 0.1 m: norm 0.71577 km
+        let ric = [0.0274, -0.0702, 0.7118];
+        conj.decompose_miss(ric).unwrap();
+
+        let components = [
+            conj.miss_distance_radial_km.unwrap(),
+            conj.miss_distance_in_track_km.unwrap(),
+            conj.miss_distance_cross_track_km.unwrap(),
+        ];
+        assert_eq!(components, ric);
+        assert!((Coordinate3D::from(components).magnitude() - conj.miss_distance_km).abs() < 1e-3);
+
+        // A vector that disagrees with the miss distance is reported, not rescaled
+        let err = conj.decompose_miss([0.2, 0.1, 0.3]).unwrap_err();
+        assert_eq!(err.field, "relative_position_ric");
+        assert!(conj.decompose_miss([0.0; 3]).is_err());
+        assert_eq!(conj.miss_distance_in_track_km, Some(ric[1]));
+    }
+
+    #[test]
+    fn test_set_velocities() {
+        let mut conj = Conjunction::new("c", "p", "P", "s", "S", Utc::now(), 0.715, RiskLevel::Low);
+        conj.set_velocities([7.5, 0.0, 0.0], [0.0, 7.5, 0.0])
+            .unwrap();
+        assert!((conj.relative_velocity_km_s.unwrap() - 7.5 * 2f64.sqrt()).abs() < 1e-12);
+        assert!((conj.encounter_angle_deg.unwrap() - 90.0).abs() < 1e-9);
+
+        // A zero or non-finite velocity has no direction, so nothing is set
+        let err = conj.set_velocities([0.0; 3], [7.5, 0.0, 0.0]).unwrap_err();
+        assert_eq!(err.field, "primary_km_s");
+        let err = conj
+            .set_velocities([7.5, 0.0, 0.0], [f64::NAN, 0.0, 0.0])
+            .unwrap_err();
+        assert_eq!(err.field, "secondary_km_s");
+        assert!((conj.encounter_angle_deg.unwrap() - 90.0).abs() < 1e-9);
     }
 }
@@ -47,20 +47,37 @@
 
 #![warn(missing_docs)]
 
+pub mod atmosphere;
+pub mod clock;
 pub mod conjunctions;
+pub mod decay;
+pub mod link;
 pub mod patterns;
+mod sgp4;
 pub mod tle;
 pub mod tracker;
+pub mod window;
 
 // Re-export commonly used items
+pub use atmosphere::{Atmosphere, ExponentialAtmosphere, Us76Atmosphere};
+pub use clock::{Clock, FixedClock, SystemClock};
 pub use conjunctions::{
-    Conjunction, ConjunctionAnalyzer, ManeuverRecommendation, RiskAnalysis, RiskLevel,
+    compute_pc, CdmObject, Conjunction, ConjunctionAnalyzer, Covariance, EncounterGeometry,
+    ManeuverRecommendation, ObjectClass, RiskAnalysis, RiskLevel,
 };
+pub use link::LinkBudget;
 pub use patterns::{
-    BehaviorAnalysis, ConfidenceLevel, DetectedPattern, PatternDetector, PatternType,
+    delta_v_from_sma_change, BehaviorAnalysis, ConfidenceLevel, DetectedPattern, PatternDetector,
+    PatternType,
 };
-pub use tle::{parse_tle, TLE};
-pub use tracker::{GroundStation, SatellitePass, TrackedSatelliteInfo, Tracker};
+pub use tle::{
+    parse_tle, parse_tle_report, split_ground_track, Frame, PropagationAccuracy, PropagationError,
+    StateVector, TleBuilder, TleDiff, TLE,
+};
+pub use tracker::{
+    max_azimuth_rate_deg_s, GroundStation, LookAngle, SatellitePass, TrackedSatelliteInfo, Tracker,
+};
+pub use window::{QueryWindow, MAX_WINDOW_HOURS};
 
 /// Current version of the crate.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,133 @@
+//! RotaStellar Intel - Link Budgets
+//!
+//! Space-to-ground link budgets for estimating achievable downlink rates.
+//!
+//! The rate at each point of a pass follows from the carrier-to-noise density
+//! `C/N0 = EIRP + G/T - FSPL - L_atm + 228.6` and the Eb/N0 the modem needs,
+//! capped at the modem's maximum rate.
+
+use serde::{Deserialize, Serialize};
+
+use crate::tracker::LookAngle;
+
+/// Boltzmann's constant in dBW/(K·Hz), negated
+const BOLTZMANN_DB: f64 = 228.6;
+
+/// Downlink budget between a satellite transmitter and a ground receiver.
+///
+/// # Example
+///
+/// ```
+/// use rotastellar_intel::LinkBudget;
+///
+/// let budget = LinkBudget::new(10.0, 30.0, 8.2).with_max_data_rate(300.0);
+/// assert!(budget.data_rate_mbps(1000.0, 45.0) > budget.data_rate_mbps(2500.0, 10.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LinkBudget {
+    /// Satellite EIRP in dBW
+    pub eirp_dbw: f64,
+    /// Ground receiver figure of merit G/T in dB/K
+    pub gt_db_k: f64,
+    /// Carrier frequency in GHz
+    pub frequency_ghz: f64,
+    /// Eb/N0 required by the modulation and coding in dB
+    pub required_ebn0_db: f64,
+    /// Link margin held in reserve in dB
+    pub margin_db: f64,
+    /// Atmospheric and rain loss at zenith in dB
+    pub zenith_atmospheric_loss_db: f64,
+    /// Maximum modem data rate in Mbps
+    pub max_data_rate_mbps: f64,
+}
+
+impl LinkBudget {
+    /// Create a link budget with typical X-band modem defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `eirp_dbw` - Satellite EIRP
+    /// * `gt_db_k` - Ground receiver G/T
+    /// * `frequency_ghz` - Carrier frequency
+    pub fn new(eirp_dbw: f64, gt_db_k: f64, frequency_ghz: f64) -> Self {
+        Self {
+            eirp_dbw,
+            gt_db_k,
+            frequency_ghz,
+            required_ebn0_db: 4.5,
+            margin_db: 3.0,
+            zenith_atmospheric_loss_db: 0.3,
+            max_data_rate_mbps: 800.0,
+        }
+    }
+
+    /// Set the Eb/N0 required by the modulation and coding.
+    pub fn with_required_ebn0(mut self, required_ebn0_db: f64) -> Self {
+        self.required_ebn0_db = required_ebn0_db;
+        self
+    }
+
+    /// Set the link margin held in reserve.
+    pub fn with_margin(mut self, margin_db: f64) -> Self {
+        self.margin_db = margin_db;
+        self
+    }
+
+    /// Set the maximum modem data rate in Mbps.
+    pub fn with_max_data_rate(mut self, max_data_rate_mbps: f64) -> Self {
+        self.max_data_rate_mbps = max_data_rate_mbps;
+        self
+    }
+
+    /// Free-space path loss over a slant range in dB.
+    pub fn free_space_loss_db(&self, range_km: f64) -> f64 {
+        92.45 + 20.0 * self.frequency_ghz.log10() + 20.0 * range_km.max(1e-3).log10()
+    }
+
+    /// Atmospheric loss at an elevation in dB, scaled by air mass.
+    ///
+    /// The air mass is capped at the value for 5° to keep it finite near the
+    /// horizon.
+    pub fn atmospheric_loss_db(&self, elevation_deg: f64) -> f64 {
+        self.zenith_atmospheric_loss_db / elevation_deg.max(5.0).to_radians().sin()
+    }
+
+    /// Carrier-to-noise density ratio in dB-Hz.
+    pub fn cn0_dbhz(&self, range_km: f64, elevation_deg: f64) -> f64 {
+        self.eirp_dbw + self.gt_db_k
+            - self.free_space_loss_db(range_km)
+            - self.atmospheric_loss_db(elevation_deg)
+            + BOLTZMANN_DB
+    }
+
+    /// Achievable data rate in Mbps at a slant range and elevation.
+    pub fn data_rate_mbps(&self, range_km: f64, elevation_deg: f64) -> f64 {
+        let rate_db_bps =
+            self.cn0_dbhz(range_km, elevation_deg) - self.required_ebn0_db - self.margin_db;
+        (10f64.powf(rate_db_bps / 10.0) / 1e6).min(self.max_data_rate_mbps)
+    }
+
+    /// Achievable data rate in Mbps along a look angle.
+    pub fn data_rate_at(&self, look: &LookAngle) -> f64 {
+        self.data_rate_mbps(look.range_km, look.elevation_deg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_rate_falls_with_range() {
+        let budget = LinkBudget::new(10.0, 30.0, 8.2).with_max_data_rate(f64::INFINITY);
+        assert!((budget.free_space_loss_db(1000.0) - 170.73).abs() < 0.01);
+
+        // Doubling range costs 6 dB, a factor of four in rate
+        let near = budget.data_rate_mbps(1000.0, 90.0);
+        let far = budget.data_rate_mbps(2000.0, 90.0);
+        assert!((near / far - 4.0).abs() < 0.01);
+
+        let capped = budget.with_max_data_rate(300.0);
+        assert_eq!(capped.data_rate_mbps(1000.0, 90.0), 300.0);
+    }
+}
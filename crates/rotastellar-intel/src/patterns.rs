@@ -4,11 +4,92 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write;
 use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::atmosphere::Us76Atmosphere;
+use crate::clock::{Clock, SystemClock};
+use crate::decay::{decay_rate_km_s, decayed_altitude_km, seconds_to_reentry};
+use crate::tle::{DECAY_PERIGEE_THRESHOLD_KM, MIN_DECAY_BSTAR, REENTRY_ALTITUDE_KM};
+use crate::window::QueryWindow;
+use crate::TLE;
+use rotastellar::{EARTH_MU, EARTH_RADIUS_KM};
+
+/// BSTAR ratio between consecutive element sets treated as an abrupt drag change
+const BSTAR_JUMP_RATIO: f64 = 3.0;
+/// Smallest BSTAR ratio treated as a step in area-to-mass (e.g. panel deploy)
+const BSTAR_STEP_RATIO: f64 = 1.5;
+/// BSTAR ratio at which a drag change is considered likely rather than possible
+const BSTAR_LIKELY_RATIO: f64 = 10.0;
+/// Eccentricity change between consecutive element sets treated as a spike
+const ECCENTRICITY_STEP: f64 = 0.001;
+/// Eccentricity change considered likely rather than possible
+const ECCENTRICITY_LIKELY_STEP: f64 = 0.01;
+/// Semi-major axis change between consecutive element sets treated as a burn (km)
+const SMA_STEP_KM: f64 = 1.0;
+/// Semi-major axis change considered likely rather than possible (km)
+const SMA_LIKELY_STEP_KM: f64 = 5.0;
+/// Inclination change between consecutive element sets treated as a burn (degrees)
+const INCLINATION_STEP_DEG: f64 = 0.02;
+/// Inclination change considered likely rather than possible (degrees)
+const INCLINATION_LIKELY_STEP_DEG: f64 = 0.1;
+/// BSTAR magnitude below which drag is too small to compare
+const MIN_COMPARABLE_BSTAR: f64 = 1e-8;
+/// Trailing element sets used for the mean-motion trend
+const MEAN_MOTION_WINDOW: usize = 6;
+/// Fewest trailing element sets needed before a point is scored
+const MEAN_MOTION_MIN_POINTS: usize = 4;
+/// Consecutive outliers read as a level shift, after which the trend
+/// restarts from them instead of flagging every later set
+const MEAN_MOTION_SHIFT_RUN: usize = 3;
+/// Multiple of the z threshold from which an outlier is possible rather
+/// than uncertain
+const Z_POSSIBLE_MULTIPLE: f64 = 1.5;
+/// Multiple of the z threshold from which an outlier is likely
+const Z_LIKELY_MULTIPLE: f64 = 2.0;
+/// Lower bound on mean-motion scatter, so a perfectly smooth trend doesn't
+/// turn rounding into huge z-scores (revs/day)
+const MEAN_MOTION_NOISE_FLOOR: f64 = 1e-6;
+/// Latest element sets fitted for the observed decay rate
+const DECAY_FIT_SETS: usize = 5;
+/// Reentries predicted within this many days are reported as patterns
+const REENTRY_ALERT_DAYS: i64 = 30;
+/// Reentries predicted within this many days are likely rather than possible
+const REENTRY_LIKELY_DAYS: i64 = 7;
+/// Largest coefficient of variation of station-keeping intervals still
+/// considered a regular cadence
+const CADENCE_MAX_CV: f64 = 0.25;
+/// Fewest station-keeping intervals needed to call a cadence
+const CADENCE_MIN_INTERVALS: usize = 2;
+/// Column header of [`PatternDetector::to_csv`]
+const CSV_HEADER: &str =
+    "id,satellite_id,satellite_name,pattern_type,confidence,detected_at,start_time,end_time,\
+delta_v_m_s,altitude_change_km,inclination_change_deg,description";
+/// Weight of an anomaly relative to other patterns in the anomaly score
+const ANOMALY_SCORE_WEIGHT: f64 = 3.0;
+
+/// Delta-v in m/s to move between two circular orbits by a Hohmann transfer.
+///
+/// Sums both in-track burns from vis-viva. For drifts of a few km this is
+/// close to `v·Δa / 2a`, a lower bound on what a real maneuver spent.
+///
+/// # Arguments
+///
+/// * `a_before_km` - Semi-major axis before the maneuver
+/// * `a_after_km` - Semi-major axis after the maneuver
+pub fn delta_v_from_sma_change(a_before_km: f64, a_after_km: f64) -> f64 {
+    let transfer_a = (a_before_km + a_after_km) / 2.0;
+    let circular = |r: f64| (EARTH_MU / r).sqrt();
+    let transfer = |r: f64| (EARTH_MU * (2.0 / r - 1.0 / transfer_a)).sqrt();
+    let departure = (transfer(a_before_km) - circular(a_before_km)).abs();
+    let arrival = (circular(a_after_km) - transfer(a_after_km)).abs();
+    (departure + arrival) * 1000.0
+}
 
 /// Types of detected patterns/anomalies.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PatternType {
     /// Orbital maneuver detected
@@ -30,6 +111,7 @@ pub enum PatternType {
     /// Collision avoidance maneuver
     DebrisAvoidance,
     /// Unexpected behavior
+    #[default]
     Anomaly,
     /// Loss of attitude control
     Tumbling,
@@ -41,12 +123,6 @@ pub enum PatternType {
     Reentry,
 }
 
-impl Default for PatternType {
-    fn default() -> Self {
-        Self::Anomaly
-    }
-}
-
 impl FromStr for PatternType {
     type Err = ();
 
@@ -115,10 +191,13 @@ impl PatternType {
 }
 
 /// Confidence level of pattern detection.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum ConfidenceLevel {
     /// Low confidence, needs more data
+    #[default]
     Uncertain,
     /// Moderate confidence
     Possible,
@@ -128,12 +207,6 @@ pub enum ConfidenceLevel {
     Confirmed,
 }
 
-impl Default for ConfidenceLevel {
-    fn default() -> Self {
-        Self::Uncertain
-    }
-}
-
 impl FromStr for ConfidenceLevel {
     type Err = ();
 
@@ -148,6 +221,18 @@ impl FromStr for ConfidenceLevel {
     }
 }
 
+impl ConfidenceLevel {
+    /// Weight of this confidence level in scores, from 0.25 to 1.
+    pub fn weight(&self) -> f64 {
+        match self {
+            Self::Uncertain => 0.25,
+            Self::Possible => 0.5,
+            Self::Likely => 0.75,
+            Self::Confirmed => 1.0,
+        }
+    }
+}
+
 impl std::fmt::Display for ConfidenceLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -161,7 +246,7 @@ impl std::fmt::Display for ConfidenceLevel {
 }
 
 /// A detected pattern or anomaly in satellite behavior.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DetectedPattern {
     /// Pattern ID
     pub id: String,
@@ -262,19 +347,21 @@ impl DetectedPattern {
 /// # Example
 ///
 /// ```ignore
-/// use rotastellar_intel::{PatternDetector, PatternType, ConfidenceLevel};
+/// use rotastellar_intel::{PatternDetector, PatternType, ConfidenceLevel, QueryWindow};
 ///
 /// let detector = PatternDetector::new();
 ///
 /// // Get maneuvers
-/// let maneuvers = detector.get_maneuvers(Some("starlink-1234"), Some(168.0));
+/// let maneuvers = detector.get_maneuvers(Some("starlink-1234"), Some(QueryWindow::last_hours(168.0)));
 ///
 /// // Get anomalies
-/// let anomalies = detector.get_anomalies(None, Some(24.0));
+/// let anomalies = detector.get_anomalies(None, None);
 /// ```
 pub struct PatternDetector {
     /// Detected patterns
     patterns: Vec<DetectedPattern>,
+    /// Time source for time-window filtering
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for PatternDetector {
@@ -288,9 +375,21 @@ impl PatternDetector {
     pub fn new() -> Self {
         Self {
             patterns: Vec::new(),
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Use a custom time source instead of the system clock.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Get the detector's time source.
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
     /// Add a detected pattern.
     pub fn add_pattern(&mut self, pattern: DetectedPattern) {
         self.patterns.push(pattern);
@@ -301,6 +400,561 @@ impl PatternDetector {
         &self.patterns
     }
 
+    /// Detect drag and eccentricity signatures in a TLE history.
+    ///
+    /// Consecutive element sets are compared for abrupt changes that don't
+    /// come from thrusting:
+    ///
+    /// - a ≥3× jump in `bstar` (area-to-mass) with an eccentricity step is
+    ///   flagged as [`PatternType::Fragmentation`];
+    /// - a ≥3× `bstar` jump alone, or an eccentricity step alone, as
+    ///   [`PatternType::Anomaly`];
+    /// - a smaller (1.5-3×) `bstar` increase that persists in the next element
+    ///   set as [`PatternType::Deployment`], so an increase into the last set
+    ///   is not flagged.
+    ///
+    /// Confidence scales with the size of the change. Detected patterns are
+    /// stored in the detector and also returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite the history belongs to
+    /// * `history` - Element sets in any order
+    pub fn detect_from_tle_history(
+        &mut self,
+        satellite_id: &str,
+        history: &[TLE],
+    ) -> Vec<DetectedPattern> {
+        let mut sorted: Vec<&TLE> = history.iter().collect();
+        sorted.sort_by_key(|tle| tle.epoch());
+
+        let now = self.clock.now();
+        let mut detected = Vec::new();
+        for (i, pair) in sorted.windows(2).enumerate() {
+            let (prev, cur) = (pair[0], pair[1]);
+            let bstar_ratio = if prev.bstar.abs() > MIN_COMPARABLE_BSTAR {
+                cur.bstar / prev.bstar
+            } else {
+                1.0
+            };
+            let eccentricity_change = (cur.eccentricity - prev.eccentricity).abs();
+            let eccentricity_spike = eccentricity_change >= ECCENTRICITY_STEP;
+            // Only a following element set can show the increase persisted
+            let persists = sorted
+                .get(i + 2)
+                .is_some_and(|next| next.bstar >= cur.bstar / BSTAR_STEP_RATIO);
+
+            let (pattern_type, confidence, description) = if bstar_ratio >= BSTAR_JUMP_RATIO {
+                let confidence = if bstar_ratio >= BSTAR_LIKELY_RATIO || eccentricity_spike {
+                    ConfidenceLevel::Likely
+                } else {
+                    ConfidenceLevel::Possible
+                };
+                if eccentricity_spike {
+                    (
+                        PatternType::Fragmentation,
+                        confidence,
+                        format!(
+                            "Drag increased {:.1}x with eccentricity change of {:.4}",
+                            bstar_ratio, eccentricity_change
+                        ),
+                    )
+                } else {
+                    (
+                        PatternType::Anomaly,
+                        confidence,
+                        format!("Drag increased {:.1}x between element sets", bstar_ratio),
+                    )
+                }
+            } else if eccentricity_spike {
+                let confidence = if eccentricity_change >= ECCENTRICITY_LIKELY_STEP {
+                    ConfidenceLevel::Likely
+                } else {
+                    ConfidenceLevel::Possible
+                };
+                (
+                    PatternType::Anomaly,
+                    confidence,
+                    format!(
+                        "Eccentricity changed by {:.4} without a drag change",
+                        eccentricity_change
+                    ),
+                )
+            } else if bstar_ratio >= BSTAR_STEP_RATIO && persists {
+                (
+                    PatternType::Deployment,
+                    ConfidenceLevel::Uncertain,
+                    format!("Sustained {:.1}x drag increase", bstar_ratio),
+                )
+            } else {
+                continue;
+            };
+
+            let mut pattern = DetectedPattern::new(
+                format!(
+                    "{}-{}-{}",
+                    satellite_id,
+                    pattern_type,
+                    cur.epoch().timestamp()
+                ),
+                satellite_id,
+                cur.name.clone(),
+                pattern_type,
+                now,
+                cur.epoch(),
+                confidence,
+                description,
+            );
+            pattern.details = Some(serde_json::json!({
+                "bstar_ratio": bstar_ratio,
+                "eccentricity_change": eccentricity_change,
+            }));
+            detected.push(pattern);
+        }
+
+        self.patterns.extend(detected.iter().cloned());
+        detected
+    }
+
+    /// Detect orbit-raise, orbit-lower and plane-change maneuvers in a TLE
+    /// history.
+    ///
+    /// Consecutive element sets are compared after sorting by epoch. The
+    /// semi-major axis change is first corrected for the drag decay the
+    /// earlier set's `bstar` predicts over the gap (circular orbit at perigee
+    /// through the [`Us76Atmosphere`]), so ordinary decay isn't mistaken for
+    /// a burn. A remaining change of at least 1 km is reported as
+    /// [`PatternType::OrbitRaise`] or [`PatternType::OrbitLower`], and an
+    /// inclination change of at least 0.02° as [`PatternType::PlaneChange`];
+    /// smaller changes are within TLE fitting noise. A step of 5 km or 0.1°
+    /// is `Likely`, anything smaller `Possible`. Raises and lowerings carry
+    /// the observed altitude change and the
+    /// [Hohmann delta-v](delta_v_from_sma_change) of the part drag doesn't
+    /// explain. Detected patterns are stored in the detector and also
+    /// returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite the history belongs to
+    /// * `name` - Satellite name recorded on the patterns
+    /// * `tles` - Element sets in any order
+    pub fn detect_from_history(
+        &mut self,
+        satellite_id: &str,
+        name: &str,
+        tles: &[TLE],
+    ) -> Vec<DetectedPattern> {
+        let mut sorted: Vec<&TLE> = tles.iter().collect();
+        sorted.sort_by_key(|tle| tle.epoch());
+
+        let now = self.clock.now();
+        let mut detected = Vec::new();
+        for pair in sorted.windows(2) {
+            let (prev, cur) = (pair[0], pair[1]);
+            let sma_change_km = cur.semi_major_axis_km() - prev.semi_major_axis_km();
+            let drag_change_km = -drag_decay_km(
+                prev,
+                (cur.epoch() - prev.epoch()).num_milliseconds() as f64 / 1000.0,
+            );
+            let maneuver_change_km = sma_change_km - drag_change_km;
+            let inclination_change_deg = cur.inclination - prev.inclination;
+
+            let mut found = Vec::new();
+            if maneuver_change_km.abs() >= SMA_STEP_KM {
+                let confidence = if maneuver_change_km.abs() >= SMA_LIKELY_STEP_KM {
+                    ConfidenceLevel::Likely
+                } else {
+                    ConfidenceLevel::Possible
+                };
+                let (pattern_type, verb) = if maneuver_change_km > 0.0 {
+                    (PatternType::OrbitRaise, "raised")
+                } else {
+                    (PatternType::OrbitLower, "lowered")
+                };
+                found.push((
+                    pattern_type,
+                    confidence,
+                    format!(
+                        "Semi-major axis {} by {:.2} km beyond drag",
+                        verb,
+                        maneuver_change_km.abs()
+                    ),
+                ));
+            }
+            if inclination_change_deg.abs() >= INCLINATION_STEP_DEG {
+                let confidence = if inclination_change_deg.abs() >= INCLINATION_LIKELY_STEP_DEG {
+                    ConfidenceLevel::Likely
+                } else {
+                    ConfidenceLevel::Possible
+                };
+                found.push((
+                    PatternType::PlaneChange,
+                    confidence,
+                    format!("Inclination changed by {:+.3}°", inclination_change_deg),
+                ));
+            }
+
+            for (pattern_type, confidence, description) in found {
+                let mut pattern = DetectedPattern::new(
+                    format!(
+                        "{}-{}-{}",
+                        satellite_id,
+                        pattern_type,
+                        cur.epoch().timestamp()
+                    ),
+                    satellite_id,
+                    name,
+                    pattern_type,
+                    now,
+                    prev.epoch(),
+                    confidence,
+                    description,
+                )
+                .with_end_time(cur.epoch());
+                if pattern_type == PatternType::PlaneChange {
+                    pattern.inclination_change_deg = Some(inclination_change_deg);
+                } else {
+                    let after_drag_km = prev.semi_major_axis_km() + drag_change_km;
+                    let delta_v = delta_v_from_sma_change(after_drag_km, cur.semi_major_axis_km());
+                    pattern = pattern
+                        .with_altitude_change(sma_change_km)
+                        .with_delta_v(delta_v);
+                }
+                pattern.details = Some(serde_json::json!({
+                    "semi_major_axis_change_km": sma_change_km,
+                    "drag_change_km": drag_change_km,
+                    "inclination_change_deg": inclination_change_deg,
+                }));
+                detected.push(pattern);
+            }
+        }
+
+        self.patterns.extend(detected.iter().cloned());
+        detected
+    }
+
+    /// Detect statistical outliers in a mean-motion time series.
+    ///
+    /// Each element set is compared with a linear trend fitted to the
+    /// preceding six accepted sets (at least four are needed), so steady drag
+    /// decay doesn't register. A deviation of at least `z_threshold` times the
+    /// trend's residual scatter is flagged as [`PatternType::Anomaly`], with
+    /// confidence rising with the z-score: `Uncertain` below 1.5 times the
+    /// threshold, `Possible` below twice it, `Likely` from there. Flagged sets
+    /// are left out of later trends.
+    ///
+    /// Three consecutive outliers are read as a level shift rather than
+    /// spikes: only the first is reported, and the trend restarts from the
+    /// outlying sets so the rest of the series is scored against the new
+    /// level. Outliers in a shorter run are reported individually, including
+    /// a run at the end of the series that could still become a shift.
+    /// Detected patterns are stored in the detector and also returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite the history belongs to
+    /// * `name` - Satellite name recorded on the patterns
+    /// * `tles` - Element sets in any order
+    /// * `z_threshold` - Deviation, in standard deviations, to flag
+    pub fn detect_anomalies_statistical(
+        &mut self,
+        satellite_id: &str,
+        name: &str,
+        tles: &[TLE],
+        z_threshold: f64,
+    ) -> Vec<DetectedPattern> {
+        let mut sorted: Vec<&TLE> = tles.iter().collect();
+        sorted.sort_by_key(|tle| tle.epoch());
+        let Some(first) = sorted.first() else {
+            return Vec::new();
+        };
+        let origin = first.epoch();
+        let days = |tle: &TLE| (tle.epoch() - origin).num_milliseconds() as f64 / 86_400_000.0;
+
+        let now = self.clock.now();
+        let mut accepted: Vec<(f64, f64)> = Vec::new();
+        let mut outliers: Vec<((f64, f64), DetectedPattern)> = Vec::new();
+        let mut detected = Vec::new();
+        for tle in sorted {
+            let point = (days(tle), tle.mean_motion);
+            let window = &accepted[accepted.len().saturating_sub(MEAN_MOTION_WINDOW)..];
+            if window.len() < MEAN_MOTION_MIN_POINTS {
+                accepted.push(point);
+                continue;
+            }
+
+            let (slope, intercept) = linear_fit(window);
+            let residual_var = window
+                .iter()
+                .map(|(t, n)| (n - (intercept + slope * t)).powi(2))
+                .sum::<f64>()
+                / (window.len() - 2) as f64;
+            let sigma = residual_var.sqrt().max(MEAN_MOTION_NOISE_FLOOR);
+            let expected = intercept + slope * point.0;
+            let z_score = (point.1 - expected) / sigma;
+            if z_score.abs() < z_threshold {
+                // The outliers before this set were isolated spikes
+                detected.extend(outliers.drain(..).map(|(_, pattern)| pattern));
+                accepted.push(point);
+                continue;
+            }
+
+            let confidence = if z_score.abs() >= Z_LIKELY_MULTIPLE * z_threshold {
+                ConfidenceLevel::Likely
+            } else if z_score.abs() >= Z_POSSIBLE_MULTIPLE * z_threshold {
+                ConfidenceLevel::Possible
+            } else {
+                ConfidenceLevel::Uncertain
+            };
+            let mut pattern = DetectedPattern::new(
+                format!(
+                    "{}-{}-{}",
+                    satellite_id,
+                    PatternType::Anomaly,
+                    tle.epoch().timestamp()
+                ),
+                satellite_id,
+                name,
+                PatternType::Anomaly,
+                now,
+                tle.epoch(),
+                confidence,
+                format!("Mean motion {:+.1} sigma from trend", z_score),
+            );
+            pattern.details = Some(serde_json::json!({
+                "z_score": z_score,
+                "mean_motion": point.1,
+                "expected_mean_motion": expected,
+            }));
+            outliers.push((point, pattern));
+
+            if outliers.len() == MEAN_MOTION_SHIFT_RUN {
+                let mut run = outliers.drain(..);
+                let (first_point, mut shift) = run.next().expect("run is non-empty");
+                shift.description = format!("Mean motion level shift, {}", shift.description);
+                if let Some(details) = shift.details.as_mut() {
+                    details["level_shift"] = serde_json::Value::Bool(true);
+                }
+                detected.push(shift);
+                accepted = std::iter::once(first_point)
+                    .chain(run.map(|(point, _)| point))
+                    .collect();
+            }
+        }
+        detected.extend(outliers.into_iter().map(|(_, pattern)| pattern));
+
+        self.patterns.extend(detected.iter().cloned());
+        detected
+    }
+
+    /// Estimate reentry from the decay observed in a TLE series.
+    ///
+    /// A line fitted to the semi-major axis of the latest five element sets
+    /// gives the current decay rate, which calibrates an effective ballistic
+    /// coefficient. The orbit is then decayed as circular through the
+    /// [`Us76Atmosphere`] from the fitted altitude at the latest epoch down to
+    /// 120 km. Unlike [`TLE::estimated_reentry`], this follows the decay
+    /// actually seen, so it reflects current solar activity rather than the
+    /// `bstar` of one fit; faster recent decay moves the estimate earlier.
+    ///
+    /// The fit and the threshold use the semi-major axis, not perigee. For an
+    /// eccentric orbit drag acts near perigee, well below the mean altitude,
+    /// so the estimate comes late or is skipped as above the threshold.
+    ///
+    /// # Returns
+    ///
+    /// Estimated reentry time, or `None` with fewer than two element sets, if
+    /// the orbit isn't decaying, or if the fitted altitude is at or above the
+    /// 200 km drag threshold [`TLE::estimated_reentry`] also uses. Higher up, a
+    /// falling trend is more likely fit noise or a maneuver than drag.
+    pub fn estimate_reentry(&self, tles: &[TLE]) -> Option<DateTime<Utc>> {
+        let mut sorted: Vec<&TLE> = tles.iter().collect();
+        sorted.sort_by_key(|tle| tle.epoch());
+        let latest = sorted.last()?.epoch();
+        let recent: Vec<(f64, f64)> = sorted[sorted.len().saturating_sub(DECAY_FIT_SETS)..]
+            .iter()
+            .map(|tle| {
+                (
+                    (tle.epoch() - latest).num_milliseconds() as f64 / 86_400_000.0,
+                    tle.semi_major_axis_km(),
+                )
+            })
+            .collect();
+        if recent.len() < 2 {
+            return None;
+        }
+
+        // Time is measured from the latest epoch, so the intercept is the current fit
+        let (slope_km_per_day, a_now_km) = linear_fit(&recent);
+        if slope_km_per_day >= 0.0 {
+            return None;
+        }
+        let altitude_km = a_now_km - EARTH_RADIUS_KM;
+        if altitude_km >= DECAY_PERIGEE_THRESHOLD_KM {
+            return None;
+        }
+        if altitude_km <= REENTRY_ALTITUDE_KM {
+            return Some(latest);
+        }
+
+        let atmosphere = Us76Atmosphere;
+        let ballistic_coefficient =
+            -slope_km_per_day / 86_400.0 / decay_rate_km_s(&atmosphere, 1.0, altitude_km);
+        let seconds = seconds_to_reentry(&atmosphere, ballistic_coefficient, altitude_km);
+        latest.checked_add_signed(chrono::Duration::milliseconds((seconds * 1000.0) as i64))
+    }
+
+    /// Report an imminent reentry seen in a TLE series.
+    ///
+    /// Uses [`estimate_reentry`](Self::estimate_reentry) and records a
+    /// [`PatternType::Reentry`] pattern if reentry is expected within 30
+    /// days of now: `Likely` within 7 days, `Possible` otherwise. The pattern
+    /// starts at the latest epoch and ends at the estimated reentry.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite the history belongs to
+    /// * `name` - Satellite name recorded on the pattern
+    /// * `tles` - Element sets in any order
+    pub fn detect_reentry(
+        &mut self,
+        satellite_id: &str,
+        name: &str,
+        tles: &[TLE],
+    ) -> Option<DetectedPattern> {
+        let reentry = self.estimate_reentry(tles)?;
+        let now = self.clock.now();
+        let remaining = reentry - now;
+        if remaining > chrono::Duration::days(REENTRY_ALERT_DAYS) {
+            return None;
+        }
+
+        let latest = tles.iter().map(|tle| tle.epoch()).max()?;
+        let confidence = if remaining <= chrono::Duration::days(REENTRY_LIKELY_DAYS) {
+            ConfidenceLevel::Likely
+        } else {
+            ConfidenceLevel::Possible
+        };
+        let mut pattern = DetectedPattern::new(
+            format!(
+                "{}-{}-{}",
+                satellite_id,
+                PatternType::Reentry,
+                latest.timestamp()
+            ),
+            satellite_id,
+            name,
+            PatternType::Reentry,
+            now,
+            latest,
+            confidence,
+            format!("Reentry expected around {}", reentry.to_rfc3339()),
+        )
+        .with_end_time(reentry);
+        pattern.details = Some(serde_json::json!({
+            "estimated_reentry": reentry.to_rfc3339(),
+            "days_remaining": remaining.num_seconds() as f64 / 86_400.0,
+        }));
+        self.patterns.push(pattern.clone());
+        Some(pattern)
+    }
+
+    /// Regular interval between a satellite's station-keeping burns.
+    ///
+    /// Looks at the gaps between consecutive [`PatternType::StationKeeping`]
+    /// start times. If there are at least two and their coefficient of
+    /// variation (standard deviation over mean) is at most 0.25, the median
+    /// gap is returned.
+    ///
+    /// # Returns
+    ///
+    /// The median interval, or `None` if there are too few burns or they are
+    /// too irregular to call a cadence.
+    pub fn station_keeping_cadence(&self, satellite_id: &str) -> Option<chrono::Duration> {
+        let mut starts: Vec<DateTime<Utc>> = self
+            .patterns
+            .iter()
+            .filter(|p| {
+                p.satellite_id == satellite_id && p.pattern_type == PatternType::StationKeeping
+            })
+            .map(|p| p.start_time)
+            .collect();
+        starts.sort();
+        let mut intervals: Vec<f64> = starts
+            .windows(2)
+            .map(|w| (w[1] - w[0]).num_seconds() as f64)
+            .collect();
+        if intervals.len() < CADENCE_MIN_INTERVALS {
+            return None;
+        }
+
+        let count = intervals.len() as f64;
+        let mean = intervals.iter().sum::<f64>() / count;
+        let std_dev = (intervals.iter().map(|i| (i - mean).powi(2)).sum::<f64>() / count).sqrt();
+        if mean <= 0.0 || std_dev / mean > CADENCE_MAX_CV {
+            return None;
+        }
+
+        intervals.sort_by(f64::total_cmp);
+        let n = intervals.len();
+        let median = (intervals[(n - 1) / 2] + intervals[n / 2]) / 2.0;
+        Some(chrono::Duration::seconds(median.round() as i64))
+    }
+
+    /// Write all patterns as JSON Lines.
+    ///
+    /// Each pattern is serialized on its own line, with timestamps in RFC 3339,
+    /// so the output can be streamed or tailed record by record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or writing fails.
+    pub fn write_jsonl<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        for pattern in &self.patterns {
+            serde_json::to_writer(&mut writer, pattern)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()
+    }
+
+    /// All patterns as a JSON array, with timestamps in RFC 3339.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.patterns).expect("patterns serialize to JSON")
+    }
+
+    /// All patterns as CSV, one row per pattern under a fixed header row.
+    ///
+    /// Columns are `id, satellite_id, satellite_name, pattern_type,
+    /// confidence, detected_at, start_time, end_time, delta_v_m_s,
+    /// altitude_change_km, inclination_change_deg, description`. Times are
+    /// RFC 3339; unknown values are empty. Fields containing commas, quotes
+    /// or newlines are quoted per RFC 4180. `details` is omitted.
+    pub fn to_csv(&self) -> String {
+        let optional = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+        for p in &self.patterns {
+            let fields = [
+                p.id.clone(),
+                p.satellite_id.clone(),
+                p.satellite_name.clone(),
+                p.pattern_type.to_string(),
+                p.confidence.to_string(),
+                p.detected_at.to_rfc3339(),
+                p.start_time.to_rfc3339(),
+                p.end_time.map(|t| t.to_rfc3339()).unwrap_or_default(),
+                optional(p.delta_v_m_s),
+                optional(p.altitude_change_km),
+                optional(p.inclination_change_deg),
+                p.description.clone(),
+            ];
+            let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
     /// Get patterns with filtering.
     ///
     /// # Arguments
@@ -348,12 +1002,14 @@ impl PatternDetector {
     /// # Arguments
     ///
     /// * `satellite_id` - Filter by satellite
-    /// * `_hours` - Time window in hours (default: 168 = 7 days)
+    /// * `window` - Time window (default: last 168 hours = 7 days)
     pub fn get_maneuvers(
         &self,
         satellite_id: Option<&str>,
-        _hours: Option<f64>,
+        window: Option<QueryWindow>,
     ) -> Vec<&DetectedPattern> {
+        let window =
+            window.unwrap_or_else(|| QueryWindow::last_hours_with(168.0, self.clock.as_ref()));
         let maneuver_types = [
             PatternType::Maneuver,
             PatternType::OrbitRaise,
@@ -364,7 +1020,7 @@ impl PatternDetector {
             PatternType::DebrisAvoidance,
         ];
 
-        self.get_filtered_patterns(satellite_id, Some(&maneuver_types), None)
+        self.get_windowed_patterns(satellite_id, &maneuver_types, &window)
     }
 
     /// Get anomalies.
@@ -372,19 +1028,21 @@ impl PatternDetector {
     /// # Arguments
     ///
     /// * `satellite_id` - Filter by satellite
-    /// * `_hours` - Time window in hours (default: 24)
+    /// * `window` - Time window (default: last 24 hours)
     pub fn get_anomalies(
         &self,
         satellite_id: Option<&str>,
-        _hours: Option<f64>,
+        window: Option<QueryWindow>,
     ) -> Vec<&DetectedPattern> {
+        let window =
+            window.unwrap_or_else(|| QueryWindow::last_hours_with(24.0, self.clock.as_ref()));
         let anomaly_types = [
             PatternType::Anomaly,
             PatternType::Tumbling,
             PatternType::Fragmentation,
         ];
 
-        self.get_filtered_patterns(satellite_id, Some(&anomaly_types), None)
+        self.get_windowed_patterns(satellite_id, &anomaly_types, &window)
     }
 
     /// Get proximity events.
@@ -392,15 +1050,30 @@ impl PatternDetector {
     /// # Arguments
     ///
     /// * `satellite_id` - Filter by satellite
-    /// * `_hours` - Time window in hours (default: 168 = 7 days)
+    /// * `window` - Time window (default: last 168 hours = 7 days)
     pub fn get_proximity_events(
         &self,
         satellite_id: Option<&str>,
-        _hours: Option<f64>,
+        window: Option<QueryWindow>,
     ) -> Vec<&DetectedPattern> {
+        let window =
+            window.unwrap_or_else(|| QueryWindow::last_hours_with(168.0, self.clock.as_ref()));
         let proximity_types = [PatternType::ProximityOps, PatternType::Rendezvous];
 
-        self.get_filtered_patterns(satellite_id, Some(&proximity_types), None)
+        self.get_windowed_patterns(satellite_id, &proximity_types, &window)
+    }
+
+    /// Patterns of the given types whose start time falls inside the window.
+    fn get_windowed_patterns(
+        &self,
+        satellite_id: Option<&str>,
+        pattern_types: &[PatternType],
+        window: &QueryWindow,
+    ) -> Vec<&DetectedPattern> {
+        self.get_filtered_patterns(satellite_id, Some(pattern_types), None)
+            .into_iter()
+            .filter(|p| window.contains(p.start_time))
+            .collect()
     }
 
     /// Analyze satellite behavior.
@@ -408,12 +1081,18 @@ impl PatternDetector {
     /// # Arguments
     ///
     /// * `satellite_id` - Satellite to analyze
-    /// * `_hours` - Analysis window in hours (default: 720 = 30 days)
-    pub fn analyze_behavior(&self, satellite_id: &str, _hours: Option<f64>) -> BehaviorAnalysis {
+    /// * `window` - Analysis window (default: last 720 hours = 30 days)
+    pub fn analyze_behavior(
+        &self,
+        satellite_id: &str,
+        window: Option<QueryWindow>,
+    ) -> BehaviorAnalysis {
+        let window =
+            window.unwrap_or_else(|| QueryWindow::last_hours_with(720.0, self.clock.as_ref()));
         let patterns: Vec<_> = self
             .patterns
             .iter()
-            .filter(|p| p.satellite_id == satellite_id)
+            .filter(|p| p.satellite_id == satellite_id && window.contains(p.start_time))
             .collect();
 
         // Categorize patterns
@@ -432,6 +1111,19 @@ impl PatternDetector {
             .filter_map(|p| p.delta_v_m_s)
             .sum();
 
+        // Confidence-weighted, with anomalies counting most
+        let anomaly_score: f64 = patterns
+            .iter()
+            .map(|p| {
+                p.confidence.weight()
+                    * if p.is_anomaly() {
+                        ANOMALY_SCORE_WEIGHT
+                    } else {
+                        1.0
+                    }
+            })
+            .sum();
+
         BehaviorAnalysis {
             satellite_id: satellite_id.to_string(),
             total_patterns: patterns.len(),
@@ -443,8 +1135,116 @@ impl PatternDetector {
             anomaly_count: anomalies.len(),
             total_delta_v_m_s: total_delta_v,
             has_anomalies: !anomalies.is_empty(),
+            anomaly_score,
         }
     }
+
+    /// Analyze the behavior of every satellite with patterns in the window.
+    ///
+    /// Satellites are analyzed in parallel when the `parallel` feature is
+    /// enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Analysis window (default: last 720 hours = 30 days)
+    ///
+    /// # Returns
+    ///
+    /// One analysis per satellite, highest `anomaly_score` first.
+    pub fn analyze_all(&self, window: Option<QueryWindow>) -> Vec<BehaviorAnalysis> {
+        let window =
+            window.unwrap_or_else(|| QueryWindow::last_hours_with(720.0, self.clock.as_ref()));
+        let satellite_ids: Vec<&str> = self
+            .patterns
+            .iter()
+            .filter(|p| window.contains(p.start_time))
+            .map(|p| p.satellite_id.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        #[cfg(feature = "parallel")]
+        let mut analyses: Vec<BehaviorAnalysis> = {
+            use rayon::prelude::*;
+            satellite_ids
+                .par_iter()
+                .map(|id| self.analyze_behavior(id, Some(window)))
+                .collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let mut analyses: Vec<BehaviorAnalysis> = satellite_ids
+            .iter()
+            .map(|id| self.analyze_behavior(id, Some(window)))
+            .collect();
+
+        // Stable sort keeps satellite ID order among ties
+        analyses.sort_by(|a, b| {
+            b.anomaly_score
+                .partial_cmp(&a.anomaly_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        analyses
+    }
+
+    /// Top `n` satellites by number of maneuvers in the last 30 days.
+    ///
+    /// Ties are broken by total delta-v. Satellites without maneuvers are
+    /// excluded.
+    pub fn most_active(&self, n: usize) -> Vec<BehaviorAnalysis> {
+        let mut analyses: Vec<BehaviorAnalysis> = self
+            .analyze_all(None)
+            .into_iter()
+            .filter(|a| a.maneuver_count > 0)
+            .collect();
+        analyses.sort_by(|a, b| {
+            b.maneuver_count.cmp(&a.maneuver_count).then_with(|| {
+                b.total_delta_v_m_s
+                    .partial_cmp(&a.total_delta_v_m_s)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+        });
+        analyses.truncate(n);
+        analyses
+    }
+}
+
+/// Semi-major axis lost to drag over `seconds` (km).
+///
+/// The orbit is treated as circular at perigee and integrated in sub-steps,
+/// as in [`crate::decay`], so multi-day gaps near 200 km don't overshoot.
+/// Objects without significant drag lose nothing.
+fn drag_decay_km(tle: &TLE, seconds: f64) -> f64 {
+    if tle.bstar < MIN_DECAY_BSTAR || seconds <= 0.0 {
+        return 0.0;
+    }
+    let altitude = tle.perigee_km();
+    altitude
+        - decayed_altitude_km(
+            &Us76Atmosphere,
+            tle.ballistic_coefficient(),
+            altitude,
+            seconds,
+        )
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Least-squares line `(slope, intercept)` through `(x, y)` points.
+fn linear_fit(points: &[(f64, f64)]) -> (f64, f64) {
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / count;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / count;
+    let sxx: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    let sxy: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let slope = if sxx > 0.0 { sxy / sxx } else { 0.0 };
+    (slope, mean_y - slope * mean_x)
 }
 
 /// Behavior analysis summary.
@@ -464,11 +1264,15 @@ pub struct BehaviorAnalysis {
     pub total_delta_v_m_s: f64,
     /// Whether anomalies were detected
     pub has_anomalies: bool,
+    /// Confidence-weighted pattern score; anomalies weigh most
+    #[serde(default)]
+    pub anomaly_score: f64,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tle::ISS_TLE;
     use chrono::Duration;
 
     #[test]
@@ -529,5 +1333,683 @@ mod tests {
         assert_eq!(analysis.anomaly_count, 1);
         assert!((analysis.total_delta_v_m_s - 10.5).abs() < 0.01);
         assert!(analysis.has_anomalies);
+
+        assert_eq!(detector.get_anomalies(Some("sat-1"), None).len(), 1);
+        let recent = QueryWindow::between(now - Duration::minutes(90), now);
+        assert!(detector
+            .get_anomalies(Some("sat-1"), Some(recent))
+            .is_empty());
+        assert_eq!(detector.get_maneuvers(None, Some(recent)).len(), 1);
+        assert_eq!(
+            detector
+                .analyze_behavior("sat-1", Some(recent))
+                .total_patterns,
+            1
+        );
+    }
+
+    #[test]
+    fn test_default_windows() {
+        let now = Utc::now();
+        let mut detector = PatternDetector::new().with_clock(crate::clock::FixedClock::new(now));
+        let mut add = |pattern_type: PatternType, hours_ago: i64| {
+            detector.add_pattern(DetectedPattern::new(
+                format!("{}-{}", pattern_type, hours_ago),
+                "sat-1",
+                "Satellite 1",
+                pattern_type,
+                now,
+                now - Duration::hours(hours_ago),
+                ConfidenceLevel::Likely,
+                "",
+            ));
+        };
+        // Each type just inside and just outside its default window
+        add(PatternType::OrbitRaise, 167);
+        add(PatternType::OrbitRaise, 169);
+        add(PatternType::Anomaly, 23);
+        add(PatternType::Anomaly, 25);
+        add(PatternType::ProximityOps, 167);
+        add(PatternType::ProximityOps, 169);
+        add(PatternType::Deployment, 719);
+        add(PatternType::Deployment, 721);
+
+        let ids = |patterns: Vec<&DetectedPattern>| {
+            patterns.iter().map(|p| p.id.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(detector.get_maneuvers(None, None)), ["orbit_raise-167"]);
+        assert_eq!(ids(detector.get_anomalies(None, None)), ["anomaly-23"]);
+        assert_eq!(
+            ids(detector.get_proximity_events(None, None)),
+            ["proximity_ops-167"]
+        );
+        // 720 hours covers everything but the oldest deployment
+        assert_eq!(detector.analyze_behavior("sat-1", None).total_patterns, 7);
+
+        let month = QueryWindow::last_hours_with(720.0, detector.clock());
+        assert_eq!(detector.get_maneuvers(None, Some(month)).len(), 2);
+        assert_eq!(detector.get_anomalies(None, Some(month)).len(), 2);
+    }
+
+    #[test]
+    fn test_analyze_all_ranks_anomalous_first() {
+        let mut detector = PatternDetector::new();
+        let now = Utc::now();
+        let mut add = |id: &str,
+                       satellite_id: &str,
+                       pattern_type: PatternType,
+                       confidence: ConfidenceLevel,
+                       delta_v: f64| {
+            detector.add_pattern(
+                DetectedPattern::new(
+                    id,
+                    satellite_id,
+                    satellite_id,
+                    pattern_type,
+                    now,
+                    now - Duration::hours(2),
+                    confidence,
+                    "",
+                )
+                .with_delta_v(delta_v),
+            );
+        };
+
+        add(
+            "p1",
+            "sat-a",
+            PatternType::StationKeeping,
+            ConfidenceLevel::Confirmed,
+            0.5,
+        );
+        add(
+            "p2",
+            "sat-a",
+            PatternType::StationKeeping,
+            ConfidenceLevel::Confirmed,
+            0.5,
+        );
+        add(
+            "p3",
+            "sat-a",
+            PatternType::OrbitRaise,
+            ConfidenceLevel::Likely,
+            2.0,
+        );
+        add(
+            "p4",
+            "sat-b",
+            PatternType::Anomaly,
+            ConfidenceLevel::Possible,
+            0.0,
+        );
+        add(
+            "p5",
+            "sat-c",
+            PatternType::Fragmentation,
+            ConfidenceLevel::Confirmed,
+            0.0,
+        );
+        add(
+            "p6",
+            "sat-c",
+            PatternType::Tumbling,
+            ConfidenceLevel::Likely,
+            0.0,
+        );
+        add(
+            "p7",
+            "sat-d",
+            PatternType::OrbitLower,
+            ConfidenceLevel::Confirmed,
+            5.0,
+        );
+
+        let ranked: Vec<String> = detector
+            .analyze_all(None)
+            .into_iter()
+            .map(|a| a.satellite_id)
+            .collect();
+        assert_eq!(ranked, vec!["sat-c", "sat-a", "sat-b", "sat-d"]);
+
+        let active: Vec<String> = detector
+            .most_active(2)
+            .into_iter()
+            .map(|a| a.satellite_id)
+            .collect();
+        assert_eq!(active, vec!["sat-a", "sat-d"]);
+
+        let old = QueryWindow::between(now - Duration::days(60), now - Duration::days(30));
+        assert!(detector.analyze_all(Some(old)).is_empty());
+    }
+
+    #[test]
+    fn test_write_jsonl() {
+        let mut detector = PatternDetector::new();
+        let now = Utc::now();
+        detector.add_pattern(
+            DetectedPattern::new(
+                "pattern-1",
+                "sat-1",
+                "Satellite 1",
+                PatternType::OrbitRaise,
+                now,
+                now - Duration::hours(1),
+                ConfidenceLevel::Confirmed,
+                "Orbit raise",
+            )
+            .with_delta_v(10.5)
+            .with_end_time(now),
+        );
+        detector.add_pattern(DetectedPattern::new(
+            "pattern-2",
+            "sat-2",
+            "Satellite 2",
+            PatternType::Anomaly,
+            now,
+            now - Duration::hours(2),
+            ConfidenceLevel::Likely,
+            "Anomaly",
+        ));
+
+        let mut buf = Vec::new();
+        detector.write_jsonl(&mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), detector.get_patterns().len());
+        for (line, original) in lines.iter().zip(detector.get_patterns()) {
+            let parsed: DetectedPattern = serde_json::from_str(line).unwrap();
+            assert_eq!(&parsed, original);
+        }
+    }
+
+    #[test]
+    fn test_export_csv_and_json() {
+        let now = Utc::now();
+        let mut detector = PatternDetector::new();
+        detector.add_pattern(
+            DetectedPattern::new(
+                "p-1",
+                "sat-1",
+                "Satellite 1",
+                PatternType::OrbitRaise,
+                now,
+                now - Duration::hours(3),
+                ConfidenceLevel::Likely,
+                "Raised by 5 km",
+            )
+            .with_delta_v(2.7)
+            .with_end_time(now - Duration::hours(2)),
+        );
+        detector.add_pattern(DetectedPattern::new(
+            "p-2",
+            "sat-2",
+            "DEB, \"FRAGMENT\"",
+            PatternType::Anomaly,
+            now,
+            now - Duration::hours(1),
+            ConfidenceLevel::Possible,
+            "Line one\nline two",
+        ));
+
+        let csv = detector.to_csv();
+        // The quoted multi-line description keeps the row count off by one line
+        assert_eq!(csv.lines().count(), 1 + detector.get_patterns().len() + 1);
+        assert_eq!(csv.lines().next().unwrap(), CSV_HEADER);
+        assert_eq!(CSV_HEADER.split(',').count(), 12);
+        let first = csv.lines().nth(1).unwrap();
+        assert!(first.starts_with("p-1,sat-1,Satellite 1,orbit_raise,likely,"));
+        assert!(first.contains(",2.7,,,Raised by 5 km"));
+        assert!(csv.contains("\"DEB, \"\"FRAGMENT\"\"\""));
+        assert!(csv.contains("\"Line one\nline two\""));
+
+        let parsed: Vec<DetectedPattern> = serde_json::from_str(&detector.to_json()).unwrap();
+        assert_eq!(parsed, detector.get_patterns());
+        assert_eq!(PatternDetector::new().to_csv(), format!("{}\n", CSV_HEADER));
+    }
+
+    #[test]
+    fn test_detect_drag_jump() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let base = TLE::parse(&lines).unwrap();
+        let history: Vec<TLE> = (0..5)
+            .map(|day| {
+                let mut tle = base.clone();
+                tle.epoch_day += day as f64;
+                if day >= 3 {
+                    tle.bstar *= 5.0;
+                }
+                tle
+            })
+            .collect();
+
+        let mut detector = PatternDetector::new();
+        let detected = detector.detect_from_tle_history("25544", &history);
+        assert_eq!(detected.len(), 1);
+        assert!(matches!(
+            detected[0].pattern_type,
+            PatternType::Anomaly | PatternType::Fragmentation
+        ));
+        assert_eq!(detected[0].start_time, history[3].epoch());
+        assert_eq!(detector.get_patterns().len(), 1);
+
+        // Adding an eccentricity step makes it a breakup
+        let mut fragmented = history.clone();
+        for tle in &mut fragmented[3..] {
+            tle.eccentricity += 0.005;
+        }
+        let detected = PatternDetector::new().detect_from_tle_history("25544", &fragmented);
+        assert_eq!(detected[0].pattern_type, PatternType::Fragmentation);
+        assert_eq!(detected[0].confidence, ConfidenceLevel::Likely);
+
+        // A smaller increase is a deployment only once a later set confirms it
+        let stepped = |from: usize, to: usize| -> Vec<TLE> {
+            let mut history = history.clone();
+            for (day, tle) in history.iter_mut().enumerate() {
+                tle.bstar = base.bstar * if (from..to).contains(&day) { 2.0 } else { 1.0 };
+            }
+            history
+        };
+        let detected = PatternDetector::new().detect_from_tle_history("25544", &stepped(3, 5));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].pattern_type, PatternType::Deployment);
+        assert_eq!(detected[0].start_time, history[3].epoch());
+        // Reverting in the next set, or no next set at all
+        assert!(PatternDetector::new()
+            .detect_from_tle_history("25544", &stepped(3, 4))
+            .is_empty());
+        assert!(PatternDetector::new()
+            .detect_from_tle_history("25544", &stepped(4, 5))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_detect_from_history() {
+        use chrono::{Duration, TimeZone};
+
+        let epoch = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let history: Vec<TLE> = (0..7)
+            .map(|day| {
+                let altitude_km = if day >= 4 { 555.0 } else { 550.0 } - 0.05 * day as f64;
+                TLE::builder()
+                    .norad_id(90001)
+                    .epoch(epoch + Duration::days(day))
+                    .semi_major_axis_km(EARTH_RADIUS_KM + altitude_km)
+                    .inclination_deg(53.0)
+                    .build()
+                    .unwrap()
+            })
+            .rev()
+            .collect();
+
+        let mut detector = PatternDetector::new();
+        let detected = detector.detect_from_history("sat-1", "Satellite 1", &history);
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].pattern_type, PatternType::OrbitRaise);
+        assert_eq!(detected[0].satellite_name, "Satellite 1");
+        assert_eq!(detected[0].start_time, epoch + Duration::days(3));
+        assert_eq!(detected[0].end_time, Some(epoch + Duration::days(4)));
+        assert_eq!(detected[0].confidence, ConfidenceLevel::Possible);
+        assert!((detected[0].altitude_change_km.unwrap() - 4.95).abs() < 1e-6);
+        assert!((detected[0].delta_v_m_s.unwrap() - 2.71).abs() < 0.01);
+        assert_eq!(detector.get_patterns(), &detected[..]);
+
+        // A plane change alongside a lowering yields both patterns
+        let mut lowered = history[0].clone();
+        lowered.epoch_day += 1.0;
+        lowered.mean_motion += 0.05;
+        lowered.inclination += 0.2;
+        let detected = PatternDetector::new().detect_from_history(
+            "sat-1",
+            "Satellite 1",
+            &[history[0].clone(), lowered],
+        );
+        let types: Vec<_> = detected.iter().map(|p| p.pattern_type).collect();
+        assert_eq!(types, [PatternType::OrbitLower, PatternType::PlaneChange]);
+        assert_eq!(detected[1].confidence, ConfidenceLevel::Likely);
+        assert!((detected[1].inclination_change_deg.unwrap() - 0.2).abs() < 1e-9);
+        assert!(detected[0].altitude_change_km.unwrap() < 0.0);
+        assert!(detected[1].delta_v_m_s.is_none());
+    }
+
+    #[test]
+    fn test_delta_v_from_sma_change() {
+        // 10 km raise from 550 km: about v·Δa/2a ≈ 5.5 m/s
+        let a = EARTH_RADIUS_KM + 550.0;
+        let dv = delta_v_from_sma_change(a, a + 10.0);
+        let approx = (EARTH_MU / a).sqrt() * 1000.0 * 10.0 / (2.0 * a);
+        assert!((dv - approx).abs() < 0.05, "{} vs {}", dv, approx);
+        assert!(dv > 5.0 && dv < 6.0);
+
+        assert!((delta_v_from_sma_change(a + 10.0, a) - dv).abs() < 0.01);
+        assert_eq!(delta_v_from_sma_change(a, a), 0.0);
+    }
+
+    #[test]
+    fn test_detect_anomalies_statistical() {
+        use chrono::TimeZone;
+
+        // Daily sets decaying ~1e-4 rev/day per day with ~2e-6 of fitting noise
+        let epoch = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let base = TLE::builder()
+            .norad_id(90001)
+            .epoch(epoch)
+            .semi_major_axis_km(EARTH_RADIUS_KM + 400.0)
+            .inclination_deg(51.6)
+            .build()
+            .unwrap();
+        let clean: Vec<TLE> = (0..20)
+            .map(|day| {
+                let mut tle = base.clone();
+                tle.epoch_day += day as f64;
+                tle.mean_motion += 1e-4 * day as f64 + 2e-6 * (day as f64 * 1.7).sin();
+                tle
+            })
+            .collect();
+
+        let mut detector = PatternDetector::new();
+        assert!(detector
+            .detect_anomalies_statistical("sat-1", "Satellite 1", &clean, 5.0)
+            .is_empty());
+
+        let mut spiked = clean.clone();
+        spiked[12].mean_motion += 5e-4;
+        let detected = detector.detect_anomalies_statistical("sat-1", "Satellite 1", &spiked, 5.0);
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].pattern_type, PatternType::Anomaly);
+        assert_eq!(detected[0].start_time, spiked[12].epoch());
+        assert_eq!(detected[0].confidence, ConfidenceLevel::Likely);
+        assert!(
+            detected[0].details.as_ref().unwrap()["z_score"]
+                .as_f64()
+                .unwrap()
+                > 10.0
+        );
+        assert_eq!(detector.get_patterns().len(), 1);
+
+        // Confidence follows the z-score relative to the threshold (z ≈ 320)
+        let confidence_at = |z_threshold: f64| {
+            PatternDetector::new().detect_anomalies_statistical(
+                "sat-1",
+                "Satellite 1",
+                &spiked,
+                z_threshold,
+            )[0]
+            .confidence
+        };
+        assert_eq!(confidence_at(300.0), ConfidenceLevel::Uncertain);
+        assert_eq!(confidence_at(180.0), ConfidenceLevel::Possible);
+        assert_eq!(confidence_at(100.0), ConfidenceLevel::Likely);
+    }
+
+    #[test]
+    fn test_detect_anomalies_statistical_level_shift() {
+        use chrono::TimeZone;
+
+        let epoch = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let base = TLE::builder()
+            .norad_id(90001)
+            .epoch(epoch)
+            .semi_major_axis_km(EARTH_RADIUS_KM + 400.0)
+            .inclination_deg(51.6)
+            .build()
+            .unwrap();
+        let shifted: Vec<TLE> = (0..30)
+            .map(|day| {
+                let mut tle = base.clone();
+                tle.epoch_day += day as f64;
+                tle.mean_motion += 1e-4 * day as f64 + 2e-6 * (day as f64 * 1.7).sin();
+                if day >= 10 {
+                    tle.mean_motion += 5e-4;
+                }
+                tle
+            })
+            .collect();
+
+        // Only the onset is reported; later sets are scored against the new level
+        let mut detector = PatternDetector::new();
+        let detected = detector.detect_anomalies_statistical("sat-1", "Satellite 1", &shifted, 5.0);
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].start_time, shifted[10].epoch());
+        assert_eq!(detected[0].details.as_ref().unwrap()["level_shift"], true);
+
+        // A later spike is still caught after re-baselining
+        let mut spiked = shifted.clone();
+        spiked[22].mean_motion += 5e-4;
+        let detected = detector.detect_anomalies_statistical("sat-1", "Satellite 1", &spiked, 5.0);
+        assert_eq!(detected.len(), 2);
+        assert_eq!(detected[1].start_time, spiked[22].epoch());
+        assert!(detected[1]
+            .details
+            .as_ref()
+            .unwrap()
+            .get("level_shift")
+            .is_none());
+
+        // A run cut off by the end of the series is reported point by point
+        let detected =
+            detector.detect_anomalies_statistical("sat-1", "Satellite 1", &shifted[..12], 5.0);
+        assert_eq!(detected.len(), 2);
+        assert!(detected.iter().all(|pattern| pattern
+            .details
+            .as_ref()
+            .unwrap()
+            .get("level_shift")
+            .is_none()));
+    }
+
+    #[test]
+    fn test_estimate_reentry() {
+        use chrono::{Duration, TimeZone};
+
+        let epoch = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let series = |altitudes: &[f64]| -> Vec<TLE> {
+            altitudes
+                .iter()
+                .enumerate()
+                .map(|(day, altitude_km)| {
+                    TLE::builder()
+                        .norad_id(90001)
+                        .epoch(epoch + Duration::days(day as i64))
+                        .semi_major_axis_km(EARTH_RADIUS_KM + altitude_km)
+                        .inclination_deg(51.6)
+                        .build()
+                        .unwrap()
+                })
+                .collect()
+        };
+        let steady: Vec<f64> = (0..8).map(|day| 250.0 - 0.5 * day as f64).collect();
+        // Same start, but decay speeds up over the last few days
+        let accelerating: Vec<f64> = (0..8)
+            .map(|day| {
+                if day < 4 {
+                    250.0 - 0.5 * day as f64
+                } else {
+                    248.5 - 1.5 * (day - 3) as f64
+                }
+            })
+            .collect();
+
+        // Above the 200 km drag threshold a falling trend is not a reentry
+        let detector = PatternDetector::new();
+        assert!(detector.estimate_reentry(&series(&steady)).is_none());
+        assert!(detector.estimate_reentry(&series(&accelerating)).is_none());
+
+        // The same decay profiles below the threshold
+        let low_steady: Vec<f64> = steady
+            .iter()
+            .map(|altitude_km| altitude_km - 55.0)
+            .collect();
+        let low_accelerating: Vec<f64> = accelerating
+            .iter()
+            .map(|altitude_km| altitude_km - 55.0)
+            .collect();
+        let last_epoch = epoch + Duration::days(7);
+        let steady_reentry = detector.estimate_reentry(&series(&low_steady)).unwrap();
+        let accelerating_reentry = detector
+            .estimate_reentry(&series(&low_accelerating))
+            .unwrap();
+        assert!(steady_reentry > last_epoch);
+        assert!(accelerating_reentry > last_epoch);
+        assert!(accelerating_reentry < steady_reentry);
+
+        // Decay speeds up as the orbit drops, so reentry comes well before a
+        // linear extrapolation of 0.5 km/day over 71.5 km (143 days)
+        assert!(steady_reentry < last_epoch + Duration::days(143));
+
+        // Losing 10 m/day far above the drag regime is not a reentry
+        let geo: Vec<f64> = (0..5).map(|day| 35_786.0 - 0.01 * day as f64).collect();
+        assert!(detector.estimate_reentry(&series(&geo)).is_none());
+        let high_leo: Vec<f64> = (0..5).map(|day| 1500.0 - 0.01 * day as f64).collect();
+        assert!(detector.estimate_reentry(&series(&high_leo)).is_none());
+
+        let flat = series(&[400.0; 5]);
+        assert!(detector.estimate_reentry(&flat).is_none());
+        assert!(detector.estimate_reentry(&flat[..1]).is_none());
+        assert!(detector.estimate_reentry(&series(&[190.0; 5])).is_none());
+
+        let mut detector =
+            PatternDetector::new().with_clock(crate::clock::FixedClock::new(last_epoch));
+        assert!(detector
+            .detect_reentry("sat-1", "Satellite 1", &series(&steady))
+            .is_none());
+        assert!(detector
+            .detect_reentry("sat-1", "Satellite 1", &series(&low_steady))
+            .is_none());
+        let falling: Vec<f64> = (0..6).map(|day| 170.0 - 3.0 * day as f64).collect();
+        let pattern = detector
+            .detect_reentry("sat-1", "Satellite 1", &series(&falling))
+            .unwrap();
+        assert_eq!(pattern.pattern_type, PatternType::Reentry);
+        assert_eq!(pattern.confidence, ConfidenceLevel::Likely);
+        assert_eq!(pattern.start_time, epoch + Duration::days(5));
+        assert!(pattern.end_time.unwrap() > pattern.start_time);
+        assert_eq!(detector.get_patterns().len(), 1);
+    }
+
+    #[test]
+    fn test_station_keeping_cadence() {
+        let start = Utc::now() - Duration::days(60);
+        let burns = |satellite_id: &str, days: &[f64]| -> Vec<DetectedPattern> {
+            days.iter()
+                .map(|&day| {
+                    let time = start + Duration::minutes((day * 1440.0) as i64);
+                    DetectedPattern::new(
+                        format!("{}-{}", satellite_id, day),
+                        satellite_id,
+                        satellite_id,
+                        PatternType::StationKeeping,
+                        time,
+                        time,
+                        ConfidenceLevel::Likely,
+                        "",
+                    )
+                })
+                .collect()
+        };
+
+        let mut detector = PatternDetector::new();
+        // Out of order, roughly weekly, with an unrelated maneuver mixed in
+        for pattern in burns("geo-1", &[14.0, 0.0, 6.8, 21.2, 28.0, 35.1]) {
+            detector.add_pattern(pattern);
+        }
+        let mut raise = burns("geo-1", &[3.0]).remove(0);
+        raise.pattern_type = PatternType::OrbitRaise;
+        detector.add_pattern(raise);
+        for pattern in burns("irregular", &[0.0, 1.0, 9.0, 10.0, 25.0]) {
+            detector.add_pattern(pattern);
+        }
+        for pattern in burns("sparse", &[0.0, 7.0]) {
+            detector.add_pattern(pattern);
+        }
+
+        let cadence = detector.station_keeping_cadence("geo-1").unwrap();
+        assert!(
+            (cadence.num_minutes() as f64 / 1440.0 - 7.0).abs() < 0.1,
+            "{}",
+            cadence
+        );
+        assert!(detector.station_keeping_cadence("irregular").is_none());
+        assert!(detector.station_keeping_cadence("sparse").is_none());
+        assert!(detector.station_keeping_cadence("unknown").is_none());
+    }
+
+    #[test]
+    fn test_detect_from_history_ignores_drag() {
+        use crate::atmosphere::{Atmosphere, ExponentialAtmosphere};
+        use chrono::{Duration, TimeZone};
+        use rotastellar::EARTH_MU;
+
+        let epoch = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let at_altitude = |day: i64, altitude_km: f64| {
+            TLE::builder()
+                .norad_id(90001)
+                .epoch(epoch + Duration::days(day))
+                .semi_major_axis_km(EARTH_RADIUS_KM + altitude_km)
+                .inclination_deg(51.6)
+                .bstar(5e-4)
+                .build()
+                .unwrap()
+        };
+
+        // Truth the detector doesn't share: one-minute Euler steps through a
+        // single exponential layer, 3-7% thinner than US76 from 250 km down
+        let truth = ExponentialAtmosphere::new(250.0, 7.0e-11, 40.0);
+        let ballistic_coefficient = at_altitude(0, 250.0).ballistic_coefficient();
+        let days = [0, 1, 2, 4, 5, 8];
+        let mut altitudes = vec![250.0];
+        for pair in days.windows(2) {
+            let mut altitude = *altitudes.last().unwrap();
+            for _ in 0..(pair[1] - pair[0]) * 1440 {
+                let density = truth.density_kg_m3(altitude);
+                altitude -= density
+                    * ballistic_coefficient
+                    * 1000.0
+                    * (EARTH_MU * (EARTH_RADIUS_KM + altitude)).sqrt()
+                    * 60.0;
+            }
+            altitudes.push(altitude);
+        }
+        let decaying: Vec<TLE> = days
+            .iter()
+            .zip(&altitudes)
+            .map(|(&day, &altitude)| at_altitude(day, altitude))
+            .collect();
+
+        // Every step loses more than the 1 km a naive comparison would flag,
+        // and the drag model explains all but less than that of it
+        for pair in decaying.windows(2) {
+            let sma_change_km = pair[1].semi_major_axis_km() - pair[0].semi_major_axis_km();
+            let seconds = (pair[1].epoch() - pair[0].epoch()).num_seconds() as f64;
+            let residual_km = sma_change_km + drag_decay_km(&pair[0], seconds);
+            assert!(sma_change_km < -SMA_STEP_KM);
+            assert!(
+                residual_km.abs() < SMA_STEP_KM,
+                "{} km unexplained",
+                residual_km
+            );
+        }
+
+        let mut detector = PatternDetector::new();
+        assert!(detector
+            .detect_from_history("sat-1", "Satellite 1", &decaying)
+            .is_empty());
+
+        // A 5 km raise on top of the decay is still found
+        let mut raised = decaying.clone();
+        raised[5] = at_altitude(days[5], altitudes[5] + 5.0);
+        let detected = detector.detect_from_history("sat-1", "Satellite 1", &raised);
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].pattern_type, PatternType::OrbitRaise);
+        assert!(
+            (detected[0].details.as_ref().unwrap()["semi_major_axis_change_km"]
+                .as_f64()
+                .unwrap()
+                - (raised[5].semi_major_axis_km() - raised[4].semi_major_axis_km()))
+            .abs()
+                < 1e-9
+        );
+        assert!(detected[0].delta_v_m_s.unwrap() > 2.5);
     }
 }
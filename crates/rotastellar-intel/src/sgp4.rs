@@ -0,0 +1,1198 @@
+//! RotaStellar Intel - SGP4/SDP4 Propagator
+//!
+//! Port of the Vallado et al. (2006) "Revisiting Spacetrack Report #3"
+//! reference implementation in AFSPC compatibility mode, with WGS-72
+//! constants as used to generate TLEs.
+//!
+//! Objects with a period of 225 minutes or more use the deep-space (SDP4)
+//! branch: lunar-solar perturbations plus the 12-hour and 24-hour geopotential
+//! resonances.
+//!
+//! Internal units follow the reference code: distances in Earth radii, time
+//! in minutes, angles in radians.
+
+use rotastellar::ValidationError;
+use std::f64::consts::PI;
+
+use crate::tle::TLE;
+
+const TWO_PI: f64 = 2.0 * PI;
+const X2O3: f64 = 2.0 / 3.0;
+
+/// WGS-72 equatorial radius (km)
+const RADIUS_EARTH_KM: f64 = 6378.135;
+/// WGS-72 gravitational parameter (km³/s²)
+const MU: f64 = 398600.8;
+/// WGS-72 zonal harmonics
+const J2: f64 = 0.001082616;
+const J3: f64 = -0.00000253881;
+const J4: f64 = -0.00000165597;
+const J3OJ2: f64 = J3 / J2;
+
+/// Period at and above which the deep-space branch is used (minutes)
+const DEEP_SPACE_PERIOD_MINUTES: f64 = 225.0;
+/// Divisor guarding the `1 + cos(i)` singularity at 180° inclination
+const TEMP4: f64 = 1.5e-12;
+/// Earth rotation rate in rad/min
+const RPTIM: f64 = 4.3752690880113e-3;
+/// Lunar and solar mean motions (rad/min) and orbit eccentricities
+const ZNS: f64 = 1.19459e-5;
+const ZES: f64 = 0.01675;
+const ZNL: f64 = 1.5835218e-4;
+const ZEL: f64 = 0.05490;
+
+/// sqrt(μ) in Earth radii³ per minute²
+fn xke() -> f64 {
+    60.0 / (RADIUS_EARTH_KM * RADIUS_EARTH_KM * RADIUS_EARTH_KM / MU).sqrt()
+}
+
+/// Initialized SGP4 model for one element set.
+#[derive(Debug, Clone)]
+pub(crate) struct Sgp4 {
+    bstar: f64,
+    ecco: f64,
+    argpo: f64,
+    inclo: f64,
+    mo: f64,
+    no_unkozai: f64,
+    nodeo: f64,
+
+    isimp: bool,
+    aycof: f64,
+    con41: f64,
+    cc1: f64,
+    cc4: f64,
+    cc5: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    delmo: f64,
+    eta: f64,
+    argpdot: f64,
+    omgcof: f64,
+    sinmao: f64,
+    t2cof: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    mdot: f64,
+    nodedot: f64,
+    xlcof: f64,
+    xmcof: f64,
+    nodecf: f64,
+
+    deep_space: Option<Box<DeepSpace>>,
+}
+
+/// Lunar-solar and resonance terms for the deep-space branch.
+#[derive(Debug, Clone, Default)]
+struct DeepSpace {
+    gsto: f64,
+    irez: u8,
+
+    // Periodic lunar-solar coefficients
+    e3: f64,
+    ee2: f64,
+    se2: f64,
+    se3: f64,
+    sgh2: f64,
+    sgh3: f64,
+    sgh4: f64,
+    sh2: f64,
+    sh3: f64,
+    si2: f64,
+    si3: f64,
+    sl2: f64,
+    sl3: f64,
+    sl4: f64,
+    xgh2: f64,
+    xgh3: f64,
+    xgh4: f64,
+    xh2: f64,
+    xh3: f64,
+    xi2: f64,
+    xi3: f64,
+    xl2: f64,
+    xl3: f64,
+    xl4: f64,
+    zmol: f64,
+    zmos: f64,
+
+    // Secular rates
+    dedt: f64,
+    didt: f64,
+    dmdt: f64,
+    dnodt: f64,
+    domdt: f64,
+
+    // Resonance terms
+    d2201: f64,
+    d2211: f64,
+    d3210: f64,
+    d3222: f64,
+    d4410: f64,
+    d4422: f64,
+    d5220: f64,
+    d5232: f64,
+    d5421: f64,
+    d5433: f64,
+    del1: f64,
+    del2: f64,
+    del3: f64,
+    xfact: f64,
+    xlamo: f64,
+}
+
+/// Intermediate terms shared by the deep-space initialization steps.
+#[derive(Default)]
+struct DeepSpaceCommon {
+    sinim: f64,
+    cosim: f64,
+    emsq: f64,
+    s1: f64,
+    s2: f64,
+    s3: f64,
+    s4: f64,
+    s5: f64,
+    ss1: f64,
+    ss2: f64,
+    ss3: f64,
+    ss4: f64,
+    ss5: f64,
+    sz1: f64,
+    sz3: f64,
+    sz11: f64,
+    sz13: f64,
+    sz21: f64,
+    sz23: f64,
+    sz31: f64,
+    sz33: f64,
+    z1: f64,
+    z3: f64,
+    z11: f64,
+    z13: f64,
+    z21: f64,
+    z23: f64,
+    z31: f64,
+    z33: f64,
+}
+
+impl Sgp4 {
+    /// Initialize the model from a TLE's mean elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the eccentricity or mean motion is out of range.
+    pub(crate) fn new(tle: &TLE) -> Result<Self, ValidationError> {
+        if !(0.0..1.0).contains(&tle.eccentricity) {
+            return Err(ValidationError::new(
+                "eccentricity",
+                "Eccentricity must be in [0, 1)",
+            ));
+        }
+        if tle.mean_motion <= 0.0 {
+            return Err(ValidationError::new(
+                "mean_motion",
+                "Mean motion must be positive",
+            ));
+        }
+
+        let xke = xke();
+        let ecco = tle.eccentricity;
+        let inclo = tle.inclination_rad();
+        let argpo = tle.arg_perigee_rad();
+        let nodeo = tle.raan_rad();
+        let mo = tle.mean_anomaly_rad();
+        let bstar = tle.bstar;
+
+        let eccsq = ecco * ecco;
+        let omeosq = 1.0 - eccsq;
+        let rteosq = omeosq.sqrt();
+        let cosio = inclo.cos();
+        let cosio2 = cosio * cosio;
+        let no_unkozai = unkozai_mean_motion(tle);
+        let ao = (xke / no_unkozai).powf(X2O3);
+        let sinio = inclo.sin();
+        let po = ao * omeosq;
+        let con42 = 1.0 - 5.0 * cosio2;
+        let con41 = -con42 - cosio2 - cosio2;
+        let posq = po * po;
+        let rp = ao * (1.0 - ecco);
+
+        // Drag terms, with the atmosphere floor lowered for low perigees
+        let ss = 78.0 / RADIUS_EARTH_KM + 1.0;
+        let qzms2t = ((120.0 - 78.0) / RADIUS_EARTH_KM).powi(4);
+        let mut isimp = rp < 220.0 / RADIUS_EARTH_KM + 1.0;
+        let mut sfour = ss;
+        let mut qzms24 = qzms2t;
+        let perige = (rp - 1.0) * RADIUS_EARTH_KM;
+        if perige < 156.0 {
+            sfour = if perige < 98.0 { 20.0 } else { perige - 78.0 };
+            qzms24 = ((120.0 - sfour) / RADIUS_EARTH_KM).powi(4);
+            sfour = sfour / RADIUS_EARTH_KM + 1.0;
+        }
+        let pinvsq = 1.0 / posq;
+
+        let tsi = 1.0 / (ao - sfour);
+        let eta = ao * ecco * tsi;
+        let etasq = eta * eta;
+        let eeta = ecco * eta;
+        let psisq = (1.0 - etasq).abs();
+        let coef = qzms24 * tsi.powi(4);
+        let coef1 = coef / psisq.powf(3.5);
+        let cc2 = coef1
+            * no_unkozai
+            * (ao * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+                + 0.375 * J2 * tsi / psisq * con41 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+        let cc1 = bstar * cc2;
+        let cc3 = if ecco > 1.0e-4 {
+            -2.0 * coef * tsi * J3OJ2 * no_unkozai * sinio / ecco
+        } else {
+            0.0
+        };
+        let x1mth2 = 1.0 - cosio2;
+        let cc4 = 2.0
+            * no_unkozai
+            * coef1
+            * ao
+            * omeosq
+            * (eta * (2.0 + 0.5 * etasq) + ecco * (0.5 + 2.0 * etasq)
+                - J2 * tsi / (ao * psisq)
+                    * (-3.0 * con41 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                        + 0.75
+                            * x1mth2
+                            * (2.0 * etasq - eeta * (1.0 + etasq))
+                            * (2.0 * argpo).cos()));
+        let cc5 = 2.0 * coef1 * ao * omeosq * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+        // Secular gravity rates
+        let cosio4 = cosio2 * cosio2;
+        let temp1 = 1.5 * J2 * pinvsq * no_unkozai;
+        let temp2 = 0.5 * temp1 * J2 * pinvsq;
+        let temp3 = -0.46875 * J4 * pinvsq * pinvsq * no_unkozai;
+        let mdot = no_unkozai
+            + 0.5 * temp1 * rteosq * con41
+            + 0.0625 * temp2 * rteosq * (13.0 - 78.0 * cosio2 + 137.0 * cosio4);
+        let argpdot = -0.5 * temp1 * con42
+            + 0.0625 * temp2 * (7.0 - 114.0 * cosio2 + 395.0 * cosio4)
+            + temp3 * (3.0 - 36.0 * cosio2 + 49.0 * cosio4);
+        let xhdot1 = -temp1 * cosio;
+        let nodedot = xhdot1
+            + (0.5 * temp2 * (4.0 - 19.0 * cosio2) + 2.0 * temp3 * (3.0 - 7.0 * cosio2)) * cosio;
+        let xpidot = argpdot + nodedot;
+        let omgcof = bstar * cc3 * argpo.cos();
+        let xmcof = if ecco > 1.0e-4 {
+            -X2O3 * coef * bstar / eeta
+        } else {
+            0.0
+        };
+        let nodecf = 3.5 * omeosq * xhdot1 * cc1;
+        let t2cof = 1.5 * cc1;
+        let xlcof = long_period_xlcof(sinio, cosio);
+        let aycof = -0.5 * J3OJ2 * sinio;
+        let delmo = (1.0 + eta * mo.cos()).powi(3);
+        let sinmao = mo.sin();
+        let x7thm1 = 7.0 * cosio2 - 1.0;
+
+        let mut model = Sgp4 {
+            bstar,
+            ecco,
+            argpo,
+            inclo,
+            mo,
+            no_unkozai,
+            nodeo,
+            isimp,
+            aycof,
+            con41,
+            cc1,
+            cc4,
+            cc5,
+            d2: 0.0,
+            d3: 0.0,
+            d4: 0.0,
+            delmo,
+            eta,
+            argpdot,
+            omgcof,
+            sinmao,
+            t2cof,
+            t3cof: 0.0,
+            t4cof: 0.0,
+            t5cof: 0.0,
+            x1mth2,
+            x7thm1,
+            mdot,
+            nodedot,
+            xlcof,
+            xmcof,
+            nodecf,
+            deep_space: None,
+        };
+
+        if is_deep_space(tle) {
+            isimp = true;
+            let epoch = epoch_days_since_1950(tle);
+            let (mut ds, common) = DeepSpace::common(epoch, ecco, argpo, inclo, nodeo, no_unkozai);
+            ds.gsto = afspc_gsto(epoch);
+            ds.init(&model, &common, xpidot, eccsq);
+            model.deep_space = Some(Box::new(ds));
+        }
+        model.isimp = isimp;
+
+        if !isimp {
+            let cc1sq = cc1 * cc1;
+            let d2 = 4.0 * ao * tsi * cc1sq;
+            let temp = d2 * tsi * cc1 / 3.0;
+            let d3 = (17.0 * ao + sfour) * temp;
+            let d4 = 0.5 * temp * ao * tsi * (221.0 * ao + 31.0 * sfour) * cc1;
+            model.d2 = d2;
+            model.d3 = d3;
+            model.d4 = d4;
+            model.t3cof = d2 + 2.0 * cc1sq;
+            model.t4cof = 0.25 * (3.0 * d3 + cc1 * (12.0 * d2 + 10.0 * cc1sq));
+            model.t5cof = 0.2
+                * (3.0 * d4 + 12.0 * cc1 * d3 + 6.0 * d2 * d2 + 15.0 * cc1sq * (2.0 * d2 + cc1sq));
+        }
+
+        Ok(model)
+    }
+
+    /// Propagate to `tsince` minutes from epoch.
+    ///
+    /// # Returns
+    ///
+    /// TEME position (km) and velocity (km/s).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drag or perturbations drive the elements out of
+    /// range, or the orbit has decayed below the surface.
+    pub(crate) fn propagate(&self, tsince: f64) -> Result<([f64; 3], [f64; 3]), ValidationError> {
+        let xke = xke();
+        let t = tsince;
+
+        // Secular gravity and drag
+        let xmdf = self.mo + self.mdot * t;
+        let argpdf = self.argpo + self.argpdot * t;
+        let nodedf = self.nodeo + self.nodedot * t;
+        let mut argpm = argpdf;
+        let mut mm = xmdf;
+        let t2 = t * t;
+        let mut nodem = nodedf + self.nodecf * t2;
+        let mut tempa = 1.0 - self.cc1 * t;
+        let mut tempe = self.bstar * self.cc4 * t;
+        let mut templ = self.t2cof * t2;
+
+        if !self.isimp {
+            let delomg = self.omgcof * t;
+            let delm = self.xmcof * ((1.0 + self.eta * xmdf.cos()).powi(3) - self.delmo);
+            let temp = delomg + delm;
+            mm = xmdf + temp;
+            argpm = argpdf - temp;
+            let t3 = t2 * t;
+            let t4 = t3 * t;
+            tempa = tempa - self.d2 * t2 - self.d3 * t3 - self.d4 * t4;
+            tempe += self.bstar * self.cc5 * (mm.sin() - self.sinmao);
+            templ += self.t3cof * t3 + t4 * (self.t4cof + t * self.t5cof);
+        }
+
+        let mut nm = self.no_unkozai;
+        let mut em = self.ecco;
+        let mut inclm = self.inclo;
+        if let Some(ds) = &self.deep_space {
+            ds.secular(
+                self, t, &mut em, &mut argpm, &mut inclm, &mut mm, &mut nodem, &mut nm,
+            );
+        }
+
+        if nm <= 0.0 {
+            return Err(ValidationError::new(
+                "mean_motion",
+                "Propagated mean motion is not positive",
+            ));
+        }
+        let am = (xke / nm).powf(X2O3) * tempa * tempa;
+        nm = xke / am.powf(1.5);
+        em -= tempe;
+        if !(-0.001..1.0).contains(&em) {
+            return Err(ValidationError::new(
+                "eccentricity",
+                "Propagated eccentricity is out of range",
+            ));
+        }
+        em = em.max(1.0e-6);
+        mm += self.no_unkozai * templ;
+        let xlm = mm + argpm + nodem;
+        nodem %= TWO_PI;
+        argpm %= TWO_PI;
+        let xlm = xlm % TWO_PI;
+        mm = (xlm - argpm - nodem) % TWO_PI;
+
+        // Lunar-solar periodics
+        let mut ep = em;
+        let mut xincp = inclm;
+        let mut argpp = argpm;
+        let mut nodep = nodem;
+        let mut mp = mm;
+        let mut aycof = self.aycof;
+        let mut xlcof = self.xlcof;
+        if let Some(ds) = &self.deep_space {
+            ds.periodics(t, &mut ep, &mut xincp, &mut nodep, &mut argpp, &mut mp);
+            if xincp < 0.0 {
+                xincp = -xincp;
+                nodep += PI;
+                argpp -= PI;
+            }
+            if !(0.0..=1.0).contains(&ep) {
+                return Err(ValidationError::new(
+                    "eccentricity",
+                    "Perturbed eccentricity is out of range",
+                ));
+            }
+            aycof = -0.5 * J3OJ2 * xincp.sin();
+            xlcof = long_period_xlcof(xincp.sin(), xincp.cos());
+        }
+        let (sinip, cosip) = xincp.sin_cos();
+
+        // Long-period periodics
+        let axnl = ep * argpp.cos();
+        let temp = 1.0 / (am * (1.0 - ep * ep));
+        let aynl = ep * argpp.sin() + temp * aycof;
+        let xl = mp + argpp + nodep + temp * xlcof * axnl;
+
+        // Kepler's equation in equinoctial form
+        let u = (xl - nodep) % TWO_PI;
+        let mut eo1 = u;
+        let (mut sineo1, mut coseo1) = (0.0, 0.0);
+        for _ in 0..10 {
+            (sineo1, coseo1) = eo1.sin_cos();
+            let mut tem5 =
+                (u - aynl * coseo1 + axnl * sineo1 - eo1) / (1.0 - coseo1 * axnl - sineo1 * aynl);
+            tem5 = tem5.clamp(-0.95, 0.95);
+            eo1 += tem5;
+            if tem5.abs() < 1.0e-12 {
+                break;
+            }
+        }
+
+        // Short-period periodics
+        let ecose = axnl * coseo1 + aynl * sineo1;
+        let esine = axnl * sineo1 - aynl * coseo1;
+        let el2 = axnl * axnl + aynl * aynl;
+        let pl = am * (1.0 - el2);
+        if pl < 0.0 {
+            return Err(ValidationError::new(
+                "eccentricity",
+                "Semi-latus rectum is negative",
+            ));
+        }
+        let rl = am * (1.0 - ecose);
+        let rdotl = am.sqrt() * esine / rl;
+        let rvdotl = pl.sqrt() / rl;
+        let betal = (1.0 - el2).sqrt();
+        let temp = esine / (1.0 + betal);
+        let sinu = am / rl * (sineo1 - aynl - axnl * temp);
+        let cosu = am / rl * (coseo1 - axnl + aynl * temp);
+        let mut su = sinu.atan2(cosu);
+        let sin2u = (cosu + cosu) * sinu;
+        let cos2u = 1.0 - 2.0 * sinu * sinu;
+        let temp = 1.0 / pl;
+        let temp1 = 0.5 * J2 * temp;
+        let temp2 = temp1 * temp;
+
+        let (con41, x1mth2, x7thm1) = if self.deep_space.is_some() {
+            let cosisq = cosip * cosip;
+            (3.0 * cosisq - 1.0, 1.0 - cosisq, 7.0 * cosisq - 1.0)
+        } else {
+            (self.con41, self.x1mth2, self.x7thm1)
+        };
+        let mrt = rl * (1.0 - 1.5 * temp2 * betal * con41) + 0.5 * temp1 * x1mth2 * cos2u;
+        su -= 0.25 * temp2 * x7thm1 * sin2u;
+        let xnode = nodep + 1.5 * temp2 * cosip * sin2u;
+        let xinc = xincp + 1.5 * temp2 * cosip * sinip * cos2u;
+        let mvt = rdotl - nm * temp1 * x1mth2 * sin2u / xke;
+        let rvdot = rvdotl + nm * temp1 * (x1mth2 * cos2u + 1.5 * con41) / xke;
+
+        // Orientation vectors
+        let (sinsu, cossu) = su.sin_cos();
+        let (snod, cnod) = xnode.sin_cos();
+        let (sini, cosi) = xinc.sin_cos();
+        let xmx = -snod * cosi;
+        let xmy = cnod * cosi;
+        let ux = xmx * sinsu + cnod * cossu;
+        let uy = xmy * sinsu + snod * cossu;
+        let uz = sini * sinsu;
+        let vx = xmx * cossu - cnod * sinsu;
+        let vy = xmy * cossu - snod * sinsu;
+        let vz = sini * cossu;
+
+        if mrt < 1.0 {
+            return Err(ValidationError::new(
+                "altitude_km",
+                "Satellite has decayed below the surface",
+            ));
+        }
+
+        let vkmpersec = RADIUS_EARTH_KM * xke / 60.0;
+        Ok((
+            [
+                mrt * ux * RADIUS_EARTH_KM,
+                mrt * uy * RADIUS_EARTH_KM,
+                mrt * uz * RADIUS_EARTH_KM,
+            ],
+            [
+                (mvt * ux + rvdot * vx) * vkmpersec,
+                (mvt * uy + rvdot * vy) * vkmpersec,
+                (mvt * uz + rvdot * vz) * vkmpersec,
+            ],
+        ))
+    }
+}
+
+impl DeepSpace {
+    /// Lunar-solar terms that depend only on the epoch elements (`dscom`).
+    fn common(
+        epoch: f64,
+        ep: f64,
+        argpp: f64,
+        inclp: f64,
+        nodep: f64,
+        np: f64,
+    ) -> (DeepSpace, DeepSpaceCommon) {
+        const C1SS: f64 = 2.9864797e-6;
+        const C1L: f64 = 4.7968065e-7;
+        const ZSINIS: f64 = 0.39785416;
+        const ZCOSIS: f64 = 0.91744867;
+        const ZCOSGS: f64 = 0.1945905;
+        const ZSINGS: f64 = -0.98152470;
+
+        let mut ds = DeepSpace::default();
+        let mut c = DeepSpaceCommon::default();
+
+        let em = ep;
+        let (snodm, cnodm) = nodep.sin_cos();
+        let (sinomm, cosomm) = argpp.sin_cos();
+        let (sinim, cosim) = inclp.sin_cos();
+        let emsq = em * em;
+        let betasq = 1.0 - emsq;
+        let rtemsq = betasq.sqrt();
+        c.sinim = sinim;
+        c.cosim = cosim;
+        c.emsq = emsq;
+
+        // Lunar orbit orientation at epoch
+        let day = epoch + 18261.5;
+        let xnodce = (4.5236020 - 9.2422029e-4 * day) % TWO_PI;
+        let (stem, ctem) = xnodce.sin_cos();
+        let zcosil = 0.91375164 - 0.03568096 * ctem;
+        let zsinil = (1.0 - zcosil * zcosil).sqrt();
+        let zsinhl = 0.089683511 * stem / zsinil;
+        let zcoshl = (1.0 - zsinhl * zsinhl).sqrt();
+        let gam = 5.8351514 + 0.0019443680 * day;
+        let zx = 0.39785416 * stem / zsinil;
+        let zy = zcoshl * ctem + 0.91744867 * zsinhl * stem;
+        let zx = gam + zx.atan2(zy) - xnodce;
+        let (zsingl, zcosgl) = zx.sin_cos();
+
+        // First pass is the sun, second the moon
+        let (mut zcosg, mut zsing, mut zcosi, mut zsini) = (ZCOSGS, ZSINGS, ZCOSIS, ZSINIS);
+        let (mut zcosh, mut zsinh) = (cnodm, snodm);
+        let mut cc = C1SS;
+        let xnoi = 1.0 / np;
+        let (mut z2, mut z12, mut z22, mut z32, mut s6, mut s7) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let (mut sz2, mut sz12, mut sz22, mut sz32, mut ss6, mut ss7) =
+            (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        for sun in [true, false] {
+            let a1 = zcosg * zcosh + zsing * zcosi * zsinh;
+            let a3 = -zsing * zcosh + zcosg * zcosi * zsinh;
+            let a7 = -zcosg * zsinh + zsing * zcosi * zcosh;
+            let a8 = zsing * zsini;
+            let a9 = zsing * zsinh + zcosg * zcosi * zcosh;
+            let a10 = zcosg * zsini;
+            let a2 = cosim * a7 + sinim * a8;
+            let a4 = cosim * a9 + sinim * a10;
+            let a5 = -sinim * a7 + cosim * a8;
+            let a6 = -sinim * a9 + cosim * a10;
+
+            let x1 = a1 * cosomm + a2 * sinomm;
+            let x2 = a3 * cosomm + a4 * sinomm;
+            let x3 = -a1 * sinomm + a2 * cosomm;
+            let x4 = -a3 * sinomm + a4 * cosomm;
+            let x5 = a5 * sinomm;
+            let x6 = a6 * sinomm;
+            let x7 = a5 * cosomm;
+            let x8 = a6 * cosomm;
+
+            c.z31 = 12.0 * x1 * x1 - 3.0 * x3 * x3;
+            z32 = 24.0 * x1 * x2 - 6.0 * x3 * x4;
+            c.z33 = 12.0 * x2 * x2 - 3.0 * x4 * x4;
+            c.z1 = 3.0 * (a1 * a1 + a2 * a2) + c.z31 * emsq;
+            z2 = 6.0 * (a1 * a3 + a2 * a4) + z32 * emsq;
+            c.z3 = 3.0 * (a3 * a3 + a4 * a4) + c.z33 * emsq;
+            c.z11 = -6.0 * a1 * a5 + emsq * (-24.0 * x1 * x7 - 6.0 * x3 * x5);
+            z12 = -6.0 * (a1 * a6 + a3 * a5)
+                + emsq * (-24.0 * (x2 * x7 + x1 * x8) - 6.0 * (x3 * x6 + x4 * x5));
+            c.z13 = -6.0 * a3 * a6 + emsq * (-24.0 * x2 * x8 - 6.0 * x4 * x6);
+            c.z21 = 6.0 * a2 * a5 + emsq * (24.0 * x1 * x5 - 6.0 * x3 * x7);
+            z22 = 6.0 * (a4 * a5 + a2 * a6)
+                + emsq * (24.0 * (x2 * x5 + x1 * x6) - 6.0 * (x4 * x7 + x3 * x8));
+            c.z23 = 6.0 * a4 * a6 + emsq * (24.0 * x2 * x6 - 6.0 * x4 * x8);
+            c.z1 = c.z1 + c.z1 + betasq * c.z31;
+            z2 = z2 + z2 + betasq * z32;
+            c.z3 = c.z3 + c.z3 + betasq * c.z33;
+            c.s3 = cc * xnoi;
+            c.s2 = -0.5 * c.s3 / rtemsq;
+            c.s4 = c.s3 * rtemsq;
+            c.s1 = -15.0 * em * c.s4;
+            c.s5 = x1 * x3 + x2 * x4;
+            s6 = x2 * x3 + x1 * x4;
+            s7 = x2 * x4 - x1 * x3;
+
+            if sun {
+                c.ss1 = c.s1;
+                c.ss2 = c.s2;
+                c.ss3 = c.s3;
+                c.ss4 = c.s4;
+                c.ss5 = c.s5;
+                ss6 = s6;
+                ss7 = s7;
+                c.sz1 = c.z1;
+                sz2 = z2;
+                c.sz3 = c.z3;
+                c.sz11 = c.z11;
+                sz12 = z12;
+                c.sz13 = c.z13;
+                c.sz21 = c.z21;
+                sz22 = z22;
+                c.sz23 = c.z23;
+                c.sz31 = c.z31;
+                sz32 = z32;
+                c.sz33 = c.z33;
+                zcosg = zcosgl;
+                zsing = zsingl;
+                zcosi = zcosil;
+                zsini = zsinil;
+                zcosh = zcoshl * cnodm + zsinhl * snodm;
+                zsinh = snodm * zcoshl - cnodm * zsinhl;
+                cc = C1L;
+            }
+        }
+
+        ds.zmol = (4.7199672 + 0.22997150 * day - gam) % TWO_PI;
+        ds.zmos = (6.2565837 + 0.017201977 * day) % TWO_PI;
+
+        // Solar periodic coefficients
+        ds.se2 = 2.0 * c.ss1 * ss6;
+        ds.se3 = 2.0 * c.ss1 * ss7;
+        ds.si2 = 2.0 * c.ss2 * sz12;
+        ds.si3 = 2.0 * c.ss2 * (c.sz13 - c.sz11);
+        ds.sl2 = -2.0 * c.ss3 * sz2;
+        ds.sl3 = -2.0 * c.ss3 * (c.sz3 - c.sz1);
+        ds.sl4 = -2.0 * c.ss3 * (-21.0 - 9.0 * emsq) * ZES;
+        ds.sgh2 = 2.0 * c.ss4 * sz32;
+        ds.sgh3 = 2.0 * c.ss4 * (c.sz33 - c.sz31);
+        ds.sgh4 = -18.0 * c.ss4 * ZES;
+        ds.sh2 = -2.0 * c.ss2 * sz22;
+        ds.sh3 = -2.0 * c.ss2 * (c.sz23 - c.sz21);
+
+        // Lunar periodic coefficients
+        ds.ee2 = 2.0 * c.s1 * s6;
+        ds.e3 = 2.0 * c.s1 * s7;
+        ds.xi2 = 2.0 * c.s2 * z12;
+        ds.xi3 = 2.0 * c.s2 * (c.z13 - c.z11);
+        ds.xl2 = -2.0 * c.s3 * z2;
+        ds.xl3 = -2.0 * c.s3 * (c.z3 - c.z1);
+        ds.xl4 = -2.0 * c.s3 * (-21.0 - 9.0 * emsq) * ZEL;
+        ds.xgh2 = 2.0 * c.s4 * z32;
+        ds.xgh3 = 2.0 * c.s4 * (c.z33 - c.z31);
+        ds.xgh4 = -18.0 * c.s4 * ZEL;
+        ds.xh2 = -2.0 * c.s2 * z22;
+        ds.xh3 = -2.0 * c.s2 * (c.z23 - c.z21);
+
+        (ds, c)
+    }
+
+    /// Secular lunar-solar rates and resonance coefficients (`dsinit`).
+    fn init(&mut self, model: &Sgp4, c: &DeepSpaceCommon, xpidot: f64, eccsq: f64) {
+        const Q22: f64 = 1.7891679e-6;
+        const Q31: f64 = 2.1460748e-6;
+        const Q33: f64 = 2.2123015e-7;
+        const ROOT22: f64 = 1.7891679e-6;
+        const ROOT44: f64 = 7.3636953e-9;
+        const ROOT54: f64 = 2.1765803e-9;
+        const ROOT32: f64 = 3.7393792e-7;
+        const ROOT52: f64 = 1.1428639e-7;
+
+        let nm = model.no_unkozai;
+        let em = model.ecco;
+        let inclm = model.inclo;
+        let (sinim, cosim, emsq) = (c.sinim, c.cosim, c.emsq);
+
+        self.irez = if nm < 0.0052359877 && nm > 0.0034906585 {
+            1
+        } else if (8.26e-3..=9.24e-3).contains(&nm) && em >= 0.5 {
+            2
+        } else {
+            0
+        };
+
+        // Solar secular terms
+        let ses = c.ss1 * ZNS * c.ss5;
+        let sis = c.ss2 * ZNS * (c.sz11 + c.sz13);
+        let sls = -ZNS * c.ss3 * (c.sz1 + c.sz3 - 14.0 - 6.0 * emsq);
+        let sghs = c.ss4 * ZNS * (c.sz31 + c.sz33 - 6.0);
+        let near_equatorial = !(5.2359877e-2..=PI - 5.2359877e-2).contains(&inclm);
+        let mut shs = if near_equatorial {
+            0.0
+        } else {
+            -ZNS * c.ss2 * (c.sz21 + c.sz23)
+        };
+        if sinim != 0.0 {
+            shs /= sinim;
+        }
+        let sgs = sghs - cosim * shs;
+
+        // Lunar secular terms
+        self.dedt = ses + c.s1 * ZNL * c.s5;
+        self.didt = sis + c.s2 * ZNL * (c.z11 + c.z13);
+        self.dmdt = sls - ZNL * c.s3 * (c.z1 + c.z3 - 14.0 - 6.0 * emsq);
+        let sghl = c.s4 * ZNL * (c.z31 + c.z33 - 6.0);
+        let shll = if near_equatorial {
+            0.0
+        } else {
+            -ZNL * c.s2 * (c.z21 + c.z23)
+        };
+        self.domdt = sgs + sghl;
+        self.dnodt = shs;
+        if sinim != 0.0 {
+            self.domdt -= cosim / sinim * shll;
+            self.dnodt += shll / sinim;
+        }
+
+        if self.irez == 0 {
+            return;
+        }
+        let theta = self.gsto % TWO_PI;
+        let aonv = (nm / xke()).powf(X2O3);
+
+        if self.irez == 2 {
+            // Geopotential resonance for 12-hour orbits, evaluated at the
+            // epoch eccentricity
+            let cosisq = cosim * cosim;
+            let em = model.ecco;
+            let emsq = eccsq;
+            let eoc = em * emsq;
+            let g201 = -0.306 - (em - 0.64) * 0.440;
+            let (g211, g310, g322, g410, g422, g520);
+            if em <= 0.65 {
+                g211 = 3.616 - 13.2470 * em + 16.2900 * emsq;
+                g310 = -19.302 + 117.3900 * em - 228.4190 * emsq + 156.5910 * eoc;
+                g322 = -18.9068 + 109.7927 * em - 214.6334 * emsq + 146.5816 * eoc;
+                g410 = -41.122 + 242.6940 * em - 471.0940 * emsq + 313.9530 * eoc;
+                g422 = -146.407 + 841.8800 * em - 1629.014 * emsq + 1083.4350 * eoc;
+                g520 = -532.114 + 3017.977 * em - 5740.032 * emsq + 3708.2760 * eoc;
+            } else {
+                g211 = -72.099 + 331.819 * em - 508.738 * emsq + 266.724 * eoc;
+                g310 = -346.844 + 1582.851 * em - 2415.925 * emsq + 1246.113 * eoc;
+                g322 = -342.585 + 1554.908 * em - 2366.899 * emsq + 1215.972 * eoc;
+                g410 = -1052.797 + 4758.686 * em - 7193.992 * emsq + 3651.957 * eoc;
+                g422 = -3581.690 + 16178.110 * em - 24462.770 * emsq + 12422.520 * eoc;
+                g520 = if em > 0.715 {
+                    -5149.66 + 29936.92 * em - 54087.36 * emsq + 31324.56 * eoc
+                } else {
+                    1464.74 - 4664.75 * em + 3763.64 * emsq
+                };
+            }
+            let (g533, g521, g532) = if em < 0.7 {
+                (
+                    -919.22770 + 4988.6100 * em - 9064.7700 * emsq + 5542.21 * eoc,
+                    -822.71072 + 4568.6173 * em - 8491.4146 * emsq + 5337.524 * eoc,
+                    -853.66600 + 4690.2500 * em - 8624.7700 * emsq + 5341.4 * eoc,
+                )
+            } else {
+                (
+                    -37995.780 + 161616.52 * em - 229838.20 * emsq + 109377.94 * eoc,
+                    -51752.104 + 218913.95 * em - 309468.16 * emsq + 146349.42 * eoc,
+                    -40023.880 + 170470.89 * em - 242699.48 * emsq + 115605.82 * eoc,
+                )
+            };
+
+            let sini2 = sinim * sinim;
+            let f220 = 0.75 * (1.0 + 2.0 * cosim + cosisq);
+            let f221 = 1.5 * sini2;
+            let f321 = 1.875 * sinim * (1.0 - 2.0 * cosim - 3.0 * cosisq);
+            let f322 = -1.875 * sinim * (1.0 + 2.0 * cosim - 3.0 * cosisq);
+            let f441 = 35.0 * sini2 * f220;
+            let f442 = 39.3750 * sini2 * sini2;
+            let f522 = 9.84375
+                * sinim
+                * (sini2 * (1.0 - 2.0 * cosim - 5.0 * cosisq)
+                    + 0.33333333 * (-2.0 + 4.0 * cosim + 6.0 * cosisq));
+            let f523 = sinim
+                * (4.92187512 * sini2 * (-2.0 - 4.0 * cosim + 10.0 * cosisq)
+                    + 6.56250012 * (1.0 + 2.0 * cosim - 3.0 * cosisq));
+            let f542 = 29.53125
+                * sinim
+                * (2.0 - 8.0 * cosim + cosisq * (-12.0 + 8.0 * cosim + 10.0 * cosisq));
+            let f543 = 29.53125
+                * sinim
+                * (-2.0 - 8.0 * cosim + cosisq * (12.0 + 8.0 * cosim - 10.0 * cosisq));
+
+            let xno2 = nm * nm;
+            let ainv2 = aonv * aonv;
+            let mut temp1 = 3.0 * xno2 * ainv2;
+            let mut temp = temp1 * ROOT22;
+            self.d2201 = temp * f220 * g201;
+            self.d2211 = temp * f221 * g211;
+            temp1 *= aonv;
+            temp = temp1 * ROOT32;
+            self.d3210 = temp * f321 * g310;
+            self.d3222 = temp * f322 * g322;
+            temp1 *= aonv;
+            temp = 2.0 * temp1 * ROOT44;
+            self.d4410 = temp * f441 * g410;
+            self.d4422 = temp * f442 * g422;
+            temp1 *= aonv;
+            temp = temp1 * ROOT52;
+            self.d5220 = temp * f522 * g520;
+            self.d5232 = temp * f523 * g532;
+            temp = 2.0 * temp1 * ROOT54;
+            self.d5421 = temp * f542 * g521;
+            self.d5433 = temp * f543 * g533;
+            self.xlamo = (model.mo + model.nodeo + model.nodeo - theta - theta) % TWO_PI;
+            self.xfact = model.mdot + self.dmdt + 2.0 * (model.nodedot + self.dnodt - RPTIM) - nm;
+        } else {
+            // Synchronous resonance for 24-hour orbits
+            let g200 = 1.0 + emsq * (-2.5 + 0.8125 * emsq);
+            let g310 = 1.0 + 2.0 * emsq;
+            let g300 = 1.0 + emsq * (-6.0 + 6.60937 * emsq);
+            let f220 = 0.75 * (1.0 + cosim) * (1.0 + cosim);
+            let f311 = 0.9375 * sinim * sinim * (1.0 + 3.0 * cosim) - 0.75 * (1.0 + cosim);
+            let f330 = 1.875 * (1.0 + cosim).powi(3);
+            let del1 = 3.0 * nm * nm * aonv * aonv;
+            self.del2 = 2.0 * del1 * f220 * g200 * Q22;
+            self.del3 = 3.0 * del1 * f330 * g300 * Q33 * aonv;
+            self.del1 = del1 * f311 * g310 * Q31 * aonv;
+            self.xlamo = (model.mo + model.nodeo + model.argpo - theta) % TWO_PI;
+            self.xfact = model.mdot + xpidot - RPTIM + self.dmdt + self.domdt + self.dnodt - nm;
+        }
+    }
+
+    /// Apply secular lunar-solar drift and integrate resonance effects (`dspace`).
+    #[allow(clippy::too_many_arguments)]
+    fn secular(
+        &self,
+        model: &Sgp4,
+        t: f64,
+        em: &mut f64,
+        argpm: &mut f64,
+        inclm: &mut f64,
+        mm: &mut f64,
+        nodem: &mut f64,
+        nm: &mut f64,
+    ) {
+        const FASX2: f64 = 0.13130908;
+        const FASX4: f64 = 2.8843198;
+        const FASX6: f64 = 0.37448087;
+        const G22: f64 = 5.7686396;
+        const G32: f64 = 0.95240898;
+        const G44: f64 = 1.8014998;
+        const G52: f64 = 1.0508330;
+        const G54: f64 = 4.4108898;
+        const STEPP: f64 = 720.0;
+        const STEP2: f64 = 259200.0;
+
+        let theta = (self.gsto + t * RPTIM) % TWO_PI;
+        *em += self.dedt * t;
+        *inclm += self.didt * t;
+        *argpm += self.domdt * t;
+        *nodem += self.dnodt * t;
+        *mm += self.dmdt * t;
+
+        if self.irez == 0 {
+            return;
+        }
+
+        // Integrate from epoch in fixed half-day steps, then Taylor-expand
+        // over the remainder
+        let delt = if t > 0.0 { STEPP } else { -STEPP };
+        let mut atime = 0.0;
+        let mut xni = model.no_unkozai;
+        let mut xli = self.xlamo;
+        let (xndt, xldot, xnddt, ft) = loop {
+            let (xndt, xnddt);
+            let xldot = xni + self.xfact;
+            if self.irez != 2 {
+                xndt = self.del1 * (xli - FASX2).sin()
+                    + self.del2 * (2.0 * (xli - FASX4)).sin()
+                    + self.del3 * (3.0 * (xli - FASX6)).sin();
+                xnddt = (self.del1 * (xli - FASX2).cos()
+                    + 2.0 * self.del2 * (2.0 * (xli - FASX4)).cos()
+                    + 3.0 * self.del3 * (3.0 * (xli - FASX6)).cos())
+                    * xldot;
+            } else {
+                let xomi = model.argpo + model.argpdot * atime;
+                let x2omi = xomi + xomi;
+                let x2li = xli + xli;
+                xndt = self.d2201 * (x2omi + xli - G22).sin()
+                    + self.d2211 * (xli - G22).sin()
+                    + self.d3210 * (xomi + xli - G32).sin()
+                    + self.d3222 * (-xomi + xli - G32).sin()
+                    + self.d4410 * (x2omi + x2li - G44).sin()
+                    + self.d4422 * (x2li - G44).sin()
+                    + self.d5220 * (xomi + xli - G52).sin()
+                    + self.d5232 * (-xomi + xli - G52).sin()
+                    + self.d5421 * (xomi + x2li - G54).sin()
+                    + self.d5433 * (-xomi + x2li - G54).sin();
+                xnddt = (self.d2201 * (x2omi + xli - G22).cos()
+                    + self.d2211 * (xli - G22).cos()
+                    + self.d3210 * (xomi + xli - G32).cos()
+                    + self.d3222 * (-xomi + xli - G32).cos()
+                    + self.d5220 * (xomi + xli - G52).cos()
+                    + self.d5232 * (-xomi + xli - G52).cos()
+                    + 2.0
+                        * (self.d4410 * (x2omi + x2li - G44).cos()
+                            + self.d4422 * (x2li - G44).cos()
+                            + self.d5421 * (xomi + x2li - G54).cos()
+                            + self.d5433 * (-xomi + x2li - G54).cos()))
+                    * xldot;
+            }
+
+            if (t - atime).abs() < STEPP {
+                break (xndt, xldot, xnddt, t - atime);
+            }
+            xli += xldot * delt + xndt * STEP2;
+            xni += xndt * delt + xnddt * STEP2;
+            atime += delt;
+        };
+
+        *nm = xni + xndt * ft + xnddt * ft * ft * 0.5;
+        let xl = xli + xldot * ft + xndt * ft * ft * 0.5;
+        *mm = if self.irez != 1 {
+            xl - 2.0 * *nodem + 2.0 * theta
+        } else {
+            xl - *nodem - *argpm + theta
+        };
+    }
+
+    /// Apply lunar-solar periodic perturbations (`dpper`).
+    ///
+    /// As in the reference code, the periodics are not offset by their epoch
+    /// values.
+    fn periodics(
+        &self,
+        t: f64,
+        ep: &mut f64,
+        inclp: &mut f64,
+        nodep: &mut f64,
+        argpp: &mut f64,
+        mp: &mut f64,
+    ) {
+        let terms = |zm: f64, ecc: f64| {
+            let zf = zm + 2.0 * ecc * zm.sin();
+            let sinzf = zf.sin();
+            (sinzf, 0.5 * sinzf * sinzf - 0.25, -0.5 * sinzf * zf.cos())
+        };
+
+        let (sinzf, f2, f3) = terms(self.zmos + ZNS * t, ZES);
+        let ses = self.se2 * f2 + self.se3 * f3;
+        let sis = self.si2 * f2 + self.si3 * f3;
+        let sls = self.sl2 * f2 + self.sl3 * f3 + self.sl4 * sinzf;
+        let sghs = self.sgh2 * f2 + self.sgh3 * f3 + self.sgh4 * sinzf;
+        let shs = self.sh2 * f2 + self.sh3 * f3;
+
+        let (sinzf, f2, f3) = terms(self.zmol + ZNL * t, ZEL);
+        let sel = self.ee2 * f2 + self.e3 * f3;
+        let sil = self.xi2 * f2 + self.xi3 * f3;
+        let sll = self.xl2 * f2 + self.xl3 * f3 + self.xl4 * sinzf;
+        let sghl = self.xgh2 * f2 + self.xgh3 * f3 + self.xgh4 * sinzf;
+        let shll = self.xh2 * f2 + self.xh3 * f3;
+
+        let pe = ses + sel;
+        let pinc = sis + sil;
+        let pl = sls + sll;
+        let mut pgh = sghs + sghl;
+        let mut ph = shs + shll;
+
+        *inclp += pinc;
+        *ep += pe;
+        let (sinip, cosip) = inclp.sin_cos();
+        if *inclp >= 0.2 {
+            ph /= sinip;
+            pgh -= cosip * ph;
+            *argpp += pgh;
+            *nodep += ph;
+            *mp += pl;
+        } else {
+            // Lyddane modification for low inclinations
+            let (sinop, cosop) = nodep.sin_cos();
+            let alfdp = sinip * sinop + ph * cosop + pinc * cosip * sinop;
+            let betdp = sinip * cosop - ph * sinop + pinc * cosip * cosop;
+            *nodep %= TWO_PI;
+            if *nodep < 0.0 {
+                *nodep += TWO_PI;
+            }
+            let dls = pl + pgh - pinc * *nodep * sinip;
+            let xls = *mp + *argpp + cosip * *nodep + dls;
+            let xnoh = *nodep;
+            *nodep = alfdp.atan2(betdp);
+            if *nodep < 0.0 {
+                *nodep += TWO_PI;
+            }
+            if (xnoh - *nodep).abs() > PI {
+                if *nodep < xnoh {
+                    *nodep += TWO_PI;
+                } else {
+                    *nodep -= TWO_PI;
+                }
+            }
+            *mp += pl;
+            *argpp = xls - *mp - cosip * *nodep;
+        }
+    }
+}
+
+/// Original (un-Kozai) mean motion in rad/min, recovered from the TLE's
+/// Kozai mean motion.
+fn unkozai_mean_motion(tle: &TLE) -> f64 {
+    let no_kozai = tle.mean_motion * TWO_PI / 1440.0;
+    let omeosq = 1.0 - tle.eccentricity * tle.eccentricity;
+    let cosio = tle.inclination_rad().cos();
+    let ak = (xke() / no_kozai).powf(X2O3);
+    let d1 = 0.75 * J2 * (3.0 * cosio * cosio - 1.0) / (omeosq.sqrt() * omeosq);
+    let del = d1 / (ak * ak);
+    let adel = ak * (1.0 - del * del - del * (1.0 / 3.0 + 134.0 * del * del / 81.0));
+    no_kozai / (1.0 + d1 / (adel * adel))
+}
+
+/// Whether the element set takes the deep-space (SDP4) branch.
+pub(crate) fn is_deep_space(tle: &TLE) -> bool {
+    TWO_PI / unkozai_mean_motion(tle) >= DEEP_SPACE_PERIOD_MINUTES
+}
+
+/// J3 long-period coefficient, guarded against division by zero at 180°.
+fn long_period_xlcof(sinio: f64, cosio: f64) -> f64 {
+    let denom = if (cosio + 1.0).abs() > TEMP4 {
+        1.0 + cosio
+    } else {
+        TEMP4
+    };
+    -0.25 * J3OJ2 * sinio * (3.0 + 5.0 * cosio) / denom
+}
+
+/// TLE epoch in days since 1949 December 31 00:00 UT.
+fn epoch_days_since_1950(tle: &TLE) -> f64 {
+    let year = if tle.epoch_year < 57 {
+        2000 + tle.epoch_year as i32
+    } else {
+        1900 + tle.epoch_year as i32
+    };
+    // Whole days from 1950-01-01 to January 1 of the epoch year
+    let days_before: i32 = (1950..year)
+        .map(|y| {
+            if (y % 4 == 0 && y % 100 != 0) || y % 400 == 0 {
+                366
+            } else {
+                365
+            }
+        })
+        .sum();
+    days_before as f64 + tle.epoch_day
+}
+
+/// Greenwich sidereal angle at epoch, in the AFSPC formulation.
+fn afspc_gsto(epoch: f64) -> f64 {
+    const C1: f64 = 1.7202791694070362e-2;
+    const THGR70: f64 = 1.7321343856509374;
+    const FK5R: f64 = 5.075514194322695e-15;
+
+    let ts70 = epoch - 7305.0;
+    let ds70 = (ts70 + 1.0e-8).floor();
+    let tfrac = ts70 - ds70;
+    (THGR70 + C1 * ds70 + (C1 + TWO_PI) * tfrac + ts70 * ts70 * FK5R).rem_euclid(TWO_PI)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tle(text: &str) -> TLE {
+        let lines: Vec<String> = text.lines().map(|s| s.to_string()).collect();
+        TLE::parse(&lines).unwrap()
+    }
+
+    fn assert_close(actual: [f64; 3], expected: [f64; 3], tolerance_km: f64) {
+        let error = ((actual[0] - expected[0]).powi(2)
+            + (actual[1] - expected[1]).powi(2)
+            + (actual[2] - expected[2]).powi(2))
+        .sqrt();
+        assert!(
+            error < tolerance_km,
+            "{:?} vs {:?} ({:.3} km)",
+            actual,
+            expected,
+            error
+        );
+    }
+
+    // Expected values from the CelesTrak SGP4 verification set (tcppver.out)
+
+    #[test]
+    fn test_near_earth_vectors() {
+        let vanguard = Sgp4::new(&tle("VANGUARD 1
+1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753
+2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667"))
+        .unwrap();
+        let (r, v) = vanguard.propagate(0.0).unwrap();
+        assert_close(r, [7022.46529266, -1400.08296755, 0.03995155], 1e-3);
+        assert_close(v, [1.893841015, 6.405893759, 4.534807250], 1e-6);
+        let (r, _) = vanguard.propagate(360.0).unwrap();
+        assert_close(r, [-7154.03120202, -3783.17682504, -3536.19412294], 1e-3);
+
+        // Low perigee with significant drag, like the ISS
+        let drag = Sgp4::new(&tle("06251
+1 06251U 62025E   06176.82412014  .00008885  00000-0  12808-3 0  3985
+2 06251  58.0579  54.0425 0030035 139.1568 221.1854 15.56387291  6774"))
+        .unwrap();
+        let (r, _) = drag.propagate(0.0).unwrap();
+        assert_close(r, [3988.31022699, 5498.96657235, 0.90055879], 1e-3);
+    }
+
+    #[test]
+    fn test_deep_space_vectors() {
+        // Molniya: 12-hour resonance plus lunar-solar perturbations
+        let molniya = Sgp4::new(&tle("MOLNIYA 2-14
+1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656"))
+        .unwrap();
+        assert!(molniya.deep_space.as_ref().is_some_and(|ds| ds.irez == 2));
+        let (r, _) = molniya.propagate(0.0).unwrap();
+        assert_close(r, [2349.89483350, -14785.93811562, 0.02119378], 0.1);
+        let (r, _) = molniya.propagate(120.0).unwrap();
+        assert_close(r, [15223.91713658, -17852.95542318, 25280.39363346], 0.1);
+    }
+
+    #[test]
+    fn test_deep_space_regression_over_a_day() {
+        // Not reference data: these are this propagator's own outputs, recorded so changes to
+        // the resonance and lunar-solar terms show up. tcppver.out publishes 08195 states out
+        // to 1440 min, but they aren't vendored here yet.
+        // TODO: assert against the published tcppver.out states to within a few km.
+        let molniya = Sgp4::new(&tle("MOLNIYA 2-14
+1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656"))
+        .unwrap();
+        let recorded = [
+            (360.0, [19089.277, 3107.913, 39958.143]),
+            (720.0, [2622.084, -15125.125, 474.453]),
+            (1080.0, [19048.542, 3260.450, 39923.387]),
+            (1440.0, [2890.757, -15446.411, 948.711]),
+        ];
+        for (minutes, position) in recorded {
+            assert_close(molniya.propagate(minutes).unwrap().0, position, 0.01);
+        }
+    }
+}
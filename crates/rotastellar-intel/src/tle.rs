@@ -10,19 +10,372 @@
 //!
 //! For precision work (rendezvous, formation flying), use ephemeris data instead.
 
-use chrono::{DateTime, TimeZone, Utc};
-use rotastellar::{Orbit, Position, ValidationError, EARTH_MU, EARTH_RADIUS_KM};
+use chrono::{DateTime, Datelike, NaiveDateTime, TimeZone, Timelike, Utc};
+use rotastellar::constants::{EARTH_J2, EARTH_ROTATION_RATE, WGS84_FLATTENING};
+use rotastellar::{Coordinate3D, Orbit, Position, ValidationError, EARTH_MU, EARTH_RADIUS_KM};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::f64::consts::PI;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+use crate::atmosphere::{Atmosphere, ExponentialAtmosphere};
+use crate::decay::seconds_to_reentry;
+use crate::sgp4::{self, Sgp4};
 
-// TODO(subhadipmitra): Add support for OMM (CCSDS Orbit Mean-elements Message) format
-// TODO: Implement SDP4 for deep space objects (period > 225 min)
 // NOTE: Using AFSPC compatibility mode for SGP4 constants
 
 /// Minutes per day
 const MINUTES_PER_DAY: f64 = 1440.0;
 /// Seconds per day
 const SECONDS_PER_DAY: f64 = 86400.0;
+/// SGP4 reference atmospheric density used to define BSTAR (kg/m²/earth radii)
+const BSTAR_REFERENCE_DENSITY: f64 = 0.15696615;
+/// Perigee altitude below which drag decay is modeled for reentry (km)
+pub(crate) const DECAY_PERIGEE_THRESHOLD_KM: f64 = 200.0;
+/// Perigee altitude treated as reentry (km)
+pub(crate) const REENTRY_ALTITUDE_KM: f64 = 120.0;
+/// Minimum BSTAR for drag to be considered significant
+pub(crate) const MIN_DECAY_BSTAR: f64 = 1e-6;
+/// Typical SGP4 position error growth in LEO (km/day)
+const LEO_ERROR_KM_PER_DAY: f64 = 2.0;
+/// Typical SGP4 position error growth above LEO, where drag is negligible (km/day)
+const HIGH_ORBIT_ERROR_KM_PER_DAY: f64 = 0.5;
+/// Perigee altitude above which drag no longer drives TLE error growth (km)
+const LEO_CEILING_KM: f64 = 2000.0;
+/// BSTAR at which drag doubles the LEO error growth
+const DRAG_ERROR_BSTAR: f64 = 1e-4;
+/// Plausible mean motion range for Earth-orbiting objects (revolutions/day)
+const MEAN_MOTION_RANGE: std::ops::RangeInclusive<f64> = 0.5..=20.0;
+
+/// Propagation model used to turn TLE mean elements into positions.
+///
+/// Higher tiers cost more per step but drift less from the true orbit over
+/// multi-day windows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PropagationAccuracy {
+    /// Unperturbed Keplerian motion. Without nodal precession a LEO ground
+    /// track is off by hundreds of km after one day and thousands after three.
+    TwoBody,
+    /// Two-body plus secular J2 drift of RAAN, argument of perigee, and mean
+    /// anomaly. Near-circular LEO stays within tens of km over several days,
+    /// limited by drag and short-period terms.
+    J2,
+    /// Full SGP4, with SDP4 for periods of 225 minutes or more. Matches the
+    /// models TLEs are fitted with (~1 km at epoch, growing 1-3 km per day in
+    /// LEO).
+    #[default]
+    Sgp4,
+}
+
+/// Propagation failure with the object and time it occurred for.
+///
+/// Batch jobs over many objects can log exactly which propagation failed.
+#[derive(Error, Debug)]
+#[error("Propagation of '{satellite_id}' (epoch {epoch}) to {requested_time} failed: {source}")]
+pub struct PropagationError {
+    /// Satellite identifier
+    pub satellite_id: String,
+    /// Epoch of the element set used
+    pub epoch: DateTime<Utc>,
+    /// Time propagation was requested for
+    pub requested_time: DateTime<Utc>,
+    /// Underlying error
+    #[source]
+    pub source: ValidationError,
+}
+
+/// Reference frame of a [`StateVector`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Frame {
+    /// True Equator, Mean Equinox: the frame TLE propagation produces
+    #[default]
+    Teme,
+    /// Earth-centered inertial. Precession and nutation are not modeled, so
+    /// this is currently the same as [`Teme`](Self::Teme) (sub-arcminute
+    /// difference from true-of-date).
+    Eci,
+    /// Earth-centered, Earth-fixed
+    Ecef,
+}
+
+/// Position and velocity of an object at a time, in a given frame.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StateVector {
+    /// Time of the state
+    pub time: DateTime<Utc>,
+    /// Frame the vectors are expressed in
+    pub frame: Frame,
+    /// Position in km
+    pub position_km: [f64; 3],
+    /// Velocity in km/s
+    pub velocity_km_s: [f64; 3],
+}
+
+impl StateVector {
+    /// Express this state in another frame.
+    pub fn to_frame(&self, frame: Frame) -> StateVector {
+        let gmst = gmst_rad(self.time);
+        let (position_km, velocity_km_s) = match (self.frame, frame) {
+            (Frame::Teme | Frame::Eci, Frame::Ecef) => {
+                let r = eci_to_ecef(self.position_km, gmst);
+                let v = eci_to_ecef(self.velocity_km_s, gmst);
+                // Remove the frame rotation: v_ecef = R·v_eci - ω × r_ecef
+                (
+                    r,
+                    [
+                        v[0] + EARTH_ROTATION_RATE * r[1],
+                        v[1] - EARTH_ROTATION_RATE * r[0],
+                        v[2],
+                    ],
+                )
+            }
+            (Frame::Ecef, Frame::Teme | Frame::Eci) => {
+                let r = self.position_km;
+                let v = [
+                    self.velocity_km_s[0] - EARTH_ROTATION_RATE * r[1],
+                    self.velocity_km_s[1] + EARTH_ROTATION_RATE * r[0],
+                    self.velocity_km_s[2],
+                ];
+                (eci_to_ecef(r, -gmst), eci_to_ecef(v, -gmst))
+            }
+            _ => (self.position_km, self.velocity_km_s),
+        };
+        StateVector {
+            time: self.time,
+            frame,
+            position_km,
+            velocity_km_s,
+        }
+    }
+
+    /// Geodetic position of this state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position is below the surface.
+    pub fn to_position(&self) -> Result<Position, ValidationError> {
+        geodetic_position(self.to_frame(Frame::Ecef).position_km)
+    }
+}
+
+/// Builder for TLE.
+///
+/// Epoch and mean motion (or semi-major axis) are required; the remaining
+/// elements default to zero, matching blank fields in the two-line format.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rotastellar_intel::TLE;
+///
+/// let tle = TLE::builder()
+///     .name("SIM-1")
+///     .norad_id(90001)
+///     .epoch(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+///     .semi_major_axis_km(6928.137)
+///     .inclination_deg(53.0)
+///     .build()
+///     .unwrap();
+/// let lines = tle.to_lines();
+/// assert_eq!(lines.len(), 3);
+/// ```
+#[derive(Debug, Default)]
+pub struct TleBuilder {
+    name: Option<String>,
+    norad_id: Option<u32>,
+    classification: Option<char>,
+    intl_designator: Option<String>,
+    epoch: Option<DateTime<Utc>>,
+    mean_motion: Option<f64>,
+    mean_motion_dot: Option<f64>,
+    bstar: Option<f64>,
+    inclination_deg: Option<f64>,
+    raan_deg: Option<f64>,
+    eccentricity: Option<f64>,
+    arg_perigee_deg: Option<f64>,
+    mean_anomaly_deg: Option<f64>,
+    rev_number: Option<u32>,
+}
+
+impl TleBuilder {
+    /// Set the satellite name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the NORAD catalog number.
+    pub fn norad_id(mut self, norad_id: u32) -> Self {
+        self.norad_id = Some(norad_id);
+        self
+    }
+
+    /// Set the classification (defaults to 'U').
+    pub fn classification(mut self, classification: char) -> Self {
+        self.classification = Some(classification);
+        self
+    }
+
+    /// Set the international designator (e.g. "98067A").
+    pub fn intl_designator(mut self, intl_designator: impl Into<String>) -> Self {
+        self.intl_designator = Some(intl_designator.into());
+        self
+    }
+
+    /// Set the epoch.
+    pub fn epoch(mut self, epoch: DateTime<Utc>) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+
+    /// Set the mean motion in revolutions per day.
+    pub fn mean_motion(mut self, mean_motion: f64) -> Self {
+        self.mean_motion = Some(mean_motion);
+        self
+    }
+
+    /// Set the mean motion from a semi-major axis in km.
+    pub fn semi_major_axis_km(mut self, semi_major_axis_km: f64) -> Self {
+        let n_rad_per_sec = (EARTH_MU / semi_major_axis_km.powi(3)).sqrt();
+        self.mean_motion = Some(n_rad_per_sec * SECONDS_PER_DAY / (2.0 * PI));
+        self
+    }
+
+    /// Set the first derivative of mean motion (rev/day^2).
+    pub fn mean_motion_dot(mut self, mean_motion_dot: f64) -> Self {
+        self.mean_motion_dot = Some(mean_motion_dot);
+        self
+    }
+
+    /// Set the BSTAR drag term.
+    pub fn bstar(mut self, bstar: f64) -> Self {
+        self.bstar = Some(bstar);
+        self
+    }
+
+    /// Set the inclination in degrees.
+    pub fn inclination_deg(mut self, inclination_deg: f64) -> Self {
+        self.inclination_deg = Some(inclination_deg);
+        self
+    }
+
+    /// Set the right ascension of ascending node in degrees.
+    pub fn raan_deg(mut self, raan_deg: f64) -> Self {
+        self.raan_deg = Some(raan_deg);
+        self
+    }
+
+    /// Set the eccentricity.
+    pub fn eccentricity(mut self, eccentricity: f64) -> Self {
+        self.eccentricity = Some(eccentricity);
+        self
+    }
+
+    /// Set the argument of perigee in degrees.
+    pub fn arg_perigee_deg(mut self, arg_perigee_deg: f64) -> Self {
+        self.arg_perigee_deg = Some(arg_perigee_deg);
+        self
+    }
+
+    /// Set the mean anomaly in degrees.
+    pub fn mean_anomaly_deg(mut self, mean_anomaly_deg: f64) -> Self {
+        self.mean_anomaly_deg = Some(mean_anomaly_deg);
+        self
+    }
+
+    /// Set the revolution number at epoch.
+    pub fn rev_number(mut self, rev_number: u32) -> Self {
+        self.rev_number = Some(rev_number);
+        self
+    }
+
+    /// Build the TLE.
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if the epoch or mean motion is missing, the
+    /// epoch is outside the two-digit-year range (1957–2056), an angle is
+    /// outside [0, 360), or the orbit fails
+    /// [`TLE::validate_physical`].
+    pub fn build(self) -> Result<TLE, ValidationError> {
+        let epoch = self
+            .epoch
+            .ok_or_else(|| ValidationError::new("epoch", "Epoch is required"))?;
+        if !(1957..=2056).contains(&epoch.year()) {
+            return Err(ValidationError::new(
+                "epoch",
+                format!(
+                    "Year {} can't be written as a 2-digit TLE epoch",
+                    epoch.year()
+                ),
+            ));
+        }
+        let mean_motion = self.mean_motion.ok_or_else(|| {
+            ValidationError::new("mean_motion", "Mean motion or semi-major axis is required")
+        })?;
+        for (field, value) in [
+            ("raan", self.raan_deg),
+            ("arg_perigee", self.arg_perigee_deg),
+            ("mean_anomaly", self.mean_anomaly_deg),
+        ] {
+            if value.is_some_and(|v| !(0.0..360.0).contains(&v)) {
+                return Err(ValidationError::new(
+                    field,
+                    "Must be between 0 (inclusive) and 360 (exclusive) degrees",
+                ));
+            }
+        }
+
+        let (epoch_year, epoch_day) = epoch_fields(epoch.naive_utc());
+        let tle = TLE {
+            name: self.name.unwrap_or_else(|| "UNKNOWN".to_string()),
+            norad_id: self.norad_id.unwrap_or(0),
+            classification: self.classification.unwrap_or('U'),
+            intl_designator: self.intl_designator.unwrap_or_default(),
+            epoch_year,
+            epoch_day,
+            mean_motion_dot: self.mean_motion_dot.unwrap_or(0.0),
+            mean_motion_ddot: 0.0,
+            bstar: self.bstar.unwrap_or(0.0),
+            element_set_type: 0,
+            element_number: 0,
+            inclination: self.inclination_deg.unwrap_or(0.0),
+            raan: self.raan_deg.unwrap_or(0.0),
+            eccentricity: self.eccentricity.unwrap_or(0.0),
+            arg_perigee: self.arg_perigee_deg.unwrap_or(0.0),
+            mean_anomaly: self.mean_anomaly_deg.unwrap_or(0.0),
+            mean_motion,
+            rev_number: self.rev_number.unwrap_or(0),
+        };
+        tle.validate_physical()?;
+        Ok(tle)
+    }
+}
+
+/// Change in mean elements between two element sets of the same object.
+///
+/// Each delta is `other - self`, so a later `other` gives the change over
+/// time.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TleDiff {
+    /// Change in semi-major axis (km)
+    pub semi_major_axis_km: f64,
+    /// Change in inclination (degrees)
+    pub inclination_deg: f64,
+    /// Change in RAAN (degrees), wrapped to [-180, 180)
+    pub raan_deg: f64,
+    /// Change in eccentricity
+    pub eccentricity: f64,
+    /// Change in mean motion (revolutions/day)
+    pub mean_motion: f64,
+    /// Time between the epochs (hours)
+    pub epoch_gap_hours: f64,
+}
 
 /// Two-Line Element set for satellite orbit determination.
 ///
@@ -83,19 +436,31 @@ pub struct TLE {
 }
 
 impl TLE {
+    /// Create a builder for synthesizing a TLE from elements.
+    pub fn builder() -> TleBuilder {
+        TleBuilder::default()
+    }
+
     /// Parse a TLE from its text representation.
     ///
     /// # Arguments
     ///
     /// * `lines` - Slice of 2 or 3 strings (name optional, then line 1, line 2)
     ///
+    /// A leading UTF-8 BOM, surrounding whitespace (including `\r`), and
+    /// blank lines are ignored.
+    ///
     /// # Errors
     ///
     /// Returns a ValidationError if the TLE format is invalid.
+    ///
+    /// Line checksums are not checked; use [`parse_checked`](Self::parse_checked)
+    /// to reject corrupted lines.
     pub fn parse(lines: &[String]) -> Result<Self, ValidationError> {
+        let lines = clean_lines(lines);
         let (name, line1, line2) = match lines.len() {
-            2 => ("UNKNOWN".to_string(), &lines[0], &lines[1]),
-            3 => (lines[0].trim().to_string(), &lines[1], &lines[2]),
+            2 => ("UNKNOWN".to_string(), lines[0], lines[1]),
+            3 => (lines[0].to_string(), lines[1], lines[2]),
             _ => {
                 return Err(ValidationError::new(
                     "lines",
@@ -118,10 +483,8 @@ impl TLE {
         }
 
         // Parse line 1
-        let norad_id = line1[2..7]
-            .trim()
-            .parse::<u32>()
-            .map_err(|_| ValidationError::new("norad_id", "Invalid NORAD ID"))?;
+        let norad_id = decode_alpha5(&line1[2..7])
+            .ok_or_else(|| ValidationError::new("norad_id", "Invalid NORAD ID"))?;
         let classification = line1.chars().nth(7).unwrap_or('U');
         let intl_designator = line1[9..17].trim().to_string();
         let epoch_year = line1[18..20]
@@ -204,6 +567,275 @@ impl TLE {
         })
     }
 
+    /// Format this TLE as a name line plus the two 69-character data lines.
+    ///
+    /// Fields are written at their standard column positions with freshly
+    /// computed checksums, so `TLE::parse(&tle.to_lines())` reproduces the
+    /// element set to the precision of the format.
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut line1 = format!(
+            "1 {}{} {:<8} {:02}{:012.8} {} {} {} {} {:4}",
+            encode_alpha5(self.norad_id),
+            self.classification,
+            self.intl_designator.chars().take(8).collect::<String>(),
+            self.epoch_year % 100,
+            self.epoch_day,
+            format_tle_decimal(self.mean_motion_dot),
+            format_tle_scientific(self.mean_motion_ddot),
+            format_tle_scientific(self.bstar),
+            self.element_set_type % 10,
+            self.element_number % 10_000,
+        );
+        // Eccentricity is written as seven digits with the leading "0." implied
+        let eccentricity = ((self.eccentricity * 1e7).round() as u32).min(9_999_999);
+        let mut line2 = format!(
+            "2 {} {:8.4} {:8.4} {:07} {:8.4} {:8.4} {:11.8}{:5}",
+            encode_alpha5(self.norad_id),
+            self.inclination,
+            self.raan,
+            eccentricity,
+            self.arg_perigee,
+            self.mean_anomaly,
+            self.mean_motion,
+            self.rev_number % 100_000,
+        );
+        line1.push_str(&checksum(&line1).to_string());
+        line2.push_str(&checksum(&line2).to_string());
+
+        vec![self.name.clone(), line1, line2]
+    }
+
+    /// Parse a TLE, rejecting lines whose checksum digit doesn't match and
+    /// elements that fail [`validate_physical`](Self::validate_physical).
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Slice of 2 or 3 strings (name optional, then line 1, line 2)
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if the TLE format is invalid, either line's
+    /// checksum is wrong, or the orbit is physically implausible.
+    pub fn parse_checked(lines: &[String]) -> Result<Self, ValidationError> {
+        let tle = Self::parse(lines)?;
+        let lines = clean_lines(lines);
+        let data = &lines[lines.len() - 2..];
+        check_line_checksum("line1", data[0])?;
+        check_line_checksum("line2", data[1])?;
+        tle.validate_physical()?;
+        Ok(tle)
+    }
+
+    /// Check that the elements describe a plausible Earth orbit.
+    ///
+    /// Corrupted records can parse into absurd orbits; this catches them
+    /// before they reach propagation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if the eccentricity is outside [0, 1), the
+    /// inclination outside [0, 180]°, the mean motion outside 0.5–20
+    /// rev/day, or the perigee is below the surface (the object has
+    /// reentered).
+    pub fn validate_physical(&self) -> Result<(), ValidationError> {
+        if !(0.0..1.0).contains(&self.eccentricity) {
+            return Err(ValidationError::new(
+                "eccentricity",
+                format!(
+                    "Must be between 0 (inclusive) and 1 (exclusive), got {}",
+                    self.eccentricity
+                ),
+            ));
+        }
+        if !(0.0..=180.0).contains(&self.inclination) {
+            return Err(ValidationError::new(
+                "inclination",
+                format!(
+                    "Must be between 0 and 180 degrees, got {}",
+                    self.inclination
+                ),
+            ));
+        }
+        if !MEAN_MOTION_RANGE.contains(&self.mean_motion) {
+            return Err(ValidationError::new(
+                "mean_motion",
+                format!(
+                    "Must be between {} and {} rev/day, got {}",
+                    MEAN_MOTION_RANGE.start(),
+                    MEAN_MOTION_RANGE.end(),
+                    self.mean_motion
+                ),
+            ));
+        }
+        let perigee = self.perigee_km();
+        if perigee <= 0.0 {
+            return Err(ValidationError::new(
+                "perigee",
+                format!(
+                    "Perigee altitude {:.1} km is below the surface; object has reentered",
+                    perigee
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse a TLE from a CCSDS OMM (Orbit Mean-elements Message) record in
+    /// JSON, as served by CelesTrak and Space-Track.
+    ///
+    /// Values may be JSON numbers or numeric strings. If `s` holds an array,
+    /// the first record is used. Optional fields (`BSTAR`, `MEAN_MOTION_DOT`,
+    /// ...) that are missing, `null` or blank default to zero like blank
+    /// fields in the two-line format.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - OMM JSON text
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if the JSON is malformed, a required field
+    /// (`NORAD_CAT_ID`, `EPOCH`, or a mean element) is missing or invalid, or
+    /// an optional numeric field is present but not a number.
+    pub fn from_omm_json(s: &str) -> Result<Self, ValidationError> {
+        let value: serde_json::Value = serde_json::from_str(s)
+            .map_err(|e| ValidationError::new("omm", format!("Invalid OMM JSON: {}", e)))?;
+        let record = match &value {
+            serde_json::Value::Array(records) => records.first(),
+            other => Some(other),
+        }
+        .and_then(|record| record.as_object())
+        .ok_or_else(|| ValidationError::new("omm", "Expected an OMM record object"))?;
+
+        Self::from_omm_fields(|key| match record.get(key)? {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            serde_json::Value::Null => None,
+            other => Some(other.to_string()),
+        })
+    }
+
+    /// Parse a TLE from a CCSDS OMM document in XML.
+    ///
+    /// Reads the first `<omm>` element, standalone or inside an `<ndm>`
+    /// wrapper: `OBJECT_NAME` and `OBJECT_ID` from `<metadata>`, then the
+    /// `<meanElements>` and `<tleParameters>` sections. Missing or empty
+    /// optional fields (`BSTAR`, `MEAN_MOTION_DOT`, ...) default to zero like
+    /// blank fields in the two-line format.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - OMM XML text
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if the XML is malformed, has no
+    /// `<meanElements>` section, a required field is missing or invalid, or
+    /// an optional numeric field is present but not a number.
+    pub fn from_omm_xml(s: &str) -> Result<Self, ValidationError> {
+        let doc = roxmltree::Document::parse(s)
+            .map_err(|e| ValidationError::new("omm", format!("Invalid OMM XML: {}", e)))?;
+        let omm = doc
+            .descendants()
+            .find(|n| n.has_tag_name("omm"))
+            .ok_or_else(|| ValidationError::new("omm", "Missing <omm> element"))?;
+        let section = |name: &str| omm.descendants().find(|n| n.has_tag_name(name));
+        let mean_elements = section("meanElements")
+            .ok_or_else(|| ValidationError::new("omm", "Missing <meanElements> section"))?;
+
+        let mut fields = HashMap::new();
+        for node in [
+            section("metadata"),
+            Some(mean_elements),
+            section("tleParameters"),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for child in node.children().filter(|c| c.is_element()) {
+                fields.insert(
+                    child.tag_name().name(),
+                    child.text().unwrap_or_default().to_string(),
+                );
+            }
+        }
+
+        Self::from_omm_fields(|key| fields.get(key).cloned())
+    }
+
+    /// Build a TLE from OMM keyword values, looked up by keyword name.
+    fn from_omm_fields(field: impl Fn(&str) -> Option<String>) -> Result<Self, ValidationError> {
+        let number = |key: &str| field(key).and_then(|v| v.trim().parse::<f64>().ok());
+        let required = |key: &str, name: &str| {
+            number(key)
+                .ok_or_else(|| ValidationError::new(name, format!("Missing or invalid {}", key)))
+        };
+        // Absent or blank is zero; anything else must parse
+        let optional = |key: &str, name: &str| match field(key) {
+            Some(v) if !v.trim().is_empty() => v
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| ValidationError::new(name, format!("Invalid {} '{}'", key, v.trim()))),
+            _ => Ok(0.0),
+        };
+
+        let norad_id = field("NORAD_CAT_ID")
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .ok_or_else(|| ValidationError::new("norad_id", "Missing or invalid NORAD_CAT_ID"))?;
+        let (epoch_year, epoch_day) = field("EPOCH")
+            .and_then(|v| parse_omm_epoch(&v))
+            .ok_or_else(|| ValidationError::new("epoch", "Missing or invalid EPOCH"))?;
+        // OBJECT_ID is "YYYY-NNNP"; the two-line format drops the century and dash
+        let intl_designator = field("OBJECT_ID")
+            .map(|id| match id.trim().split_once('-') {
+                Some((year, piece)) if year.len() == 4 => format!("{}{}", &year[2..], piece),
+                _ => id.trim().to_string(),
+            })
+            .unwrap_or_default();
+
+        Ok(TLE {
+            name: field("OBJECT_NAME")
+                .map(|n| n.trim().to_string())
+                .unwrap_or_else(|| "UNKNOWN".to_string()),
+            norad_id,
+            classification: field("CLASSIFICATION_TYPE")
+                .and_then(|c| c.trim().chars().next())
+                .unwrap_or('U'),
+            intl_designator,
+            epoch_year,
+            epoch_day,
+            mean_motion_dot: optional("MEAN_MOTION_DOT", "mean_motion_dot")?,
+            mean_motion_ddot: optional("MEAN_MOTION_DDOT", "mean_motion_ddot")?,
+            bstar: optional("BSTAR", "bstar")?,
+            element_set_type: optional("EPHEMERIS_TYPE", "element_set_type")? as u8,
+            element_number: optional("ELEMENT_SET_NO", "element_number")? as u16,
+            inclination: required("INCLINATION", "inclination")?,
+            raan: required("RA_OF_ASC_NODE", "raan")?,
+            eccentricity: required("ECCENTRICITY", "eccentricity")?,
+            arg_perigee: required("ARG_OF_PERICENTER", "arg_perigee")?,
+            mean_anomaly: required("MEAN_ANOMALY", "mean_anomaly")?,
+            mean_motion: required("MEAN_MOTION", "mean_motion")?,
+            rev_number: optional("REV_AT_EPOCH", "rev_number")? as u32,
+        })
+    }
+
+    /// Check the checksum digits of the lines this TLE was parsed from.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Slice of 2 or 3 strings (name optional, then line 1, line 2)
+    ///
+    /// # Returns
+    ///
+    /// `true` if both data lines end in their correct checksum digit.
+    pub fn verify_checksum(&self, lines: &[String]) -> bool {
+        let lines = clean_lines(lines);
+        lines.len() >= 2
+            && lines[lines.len() - 2..]
+                .iter()
+                .all(|line| check_line_checksum("line", line).is_ok())
+    }
+
     /// Get the epoch as a DateTime<Utc>.
     pub fn epoch(&self) -> DateTime<Utc> {
         // Convert 2-digit year to 4-digit
@@ -221,10 +853,35 @@ impl TLE {
         jan1 + days_offset
     }
 
+    /// Inclination in radians.
+    pub fn inclination_rad(&self) -> f64 {
+        self.inclination.to_radians()
+    }
+
+    /// Right ascension of ascending node in radians.
+    pub fn raan_rad(&self) -> f64 {
+        self.raan.to_radians()
+    }
+
+    /// Argument of perigee in radians.
+    pub fn arg_perigee_rad(&self) -> f64 {
+        self.arg_perigee.to_radians()
+    }
+
+    /// Mean anomaly at epoch in radians.
+    pub fn mean_anomaly_rad(&self) -> f64 {
+        self.mean_anomaly.to_radians()
+    }
+
+    /// Mean motion in radians per second.
+    pub fn mean_motion_rad_per_s(&self) -> f64 {
+        self.mean_motion * 2.0 * PI / SECONDS_PER_DAY
+    }
+
     /// Calculate semi-major axis from mean motion.
     pub fn semi_major_axis_km(&self) -> f64 {
         // n = sqrt(mu / a^3), so a = (mu / n^2)^(1/3)
-        let n_rad_per_sec = self.mean_motion * 2.0 * PI / SECONDS_PER_DAY;
+        let n_rad_per_sec = self.mean_motion_rad_per_s();
         (EARTH_MU / (n_rad_per_sec * n_rad_per_sec)).powf(1.0 / 3.0)
     }
 
@@ -233,6 +890,38 @@ impl TLE {
         MINUTES_PER_DAY / self.mean_motion
     }
 
+    /// Hours elapsed from the TLE epoch to `now` (negative before epoch).
+    pub fn age_hours(&self, now: DateTime<Utc>) -> f64 {
+        (now - self.epoch()).num_milliseconds() as f64 / 3_600_000.0
+    }
+
+    /// Rough position error of this element set at `now`, in km.
+    ///
+    /// Error grows linearly with age, in either direction from epoch: about
+    /// 2 km/day in LEO, scaled up with `bstar` since mis-modeled drag
+    /// dominates for low, high-drag objects, and 0.5 km/day above 2000 km
+    /// perigee. Use it to decide when to refresh elements, not as a
+    /// covariance.
+    pub fn estimated_position_error_km(&self, now: DateTime<Utc>) -> f64 {
+        let rate_km_per_day = if self.perigee_km() < LEO_CEILING_KM {
+            LEO_ERROR_KM_PER_DAY * (1.0 + self.bstar.max(0.0) / DRAG_ERROR_BSTAR)
+        } else {
+            HIGH_ORBIT_ERROR_KM_PER_DAY
+        };
+        rate_km_per_day * self.age_hours(now).abs() / 24.0
+    }
+
+    /// Whether this object needs deep-space (SDP4) propagation.
+    ///
+    /// True for periods of 225 minutes or more (Molniya, GPS, GEO), where
+    /// [`propagate_sgp4`](Self::propagate_sgp4) adds lunar-solar
+    /// perturbations and 12-hour/24-hour geopotential resonance terms. The
+    /// period is taken from the un-Kozai mean motion, as in the reference
+    /// implementation.
+    pub fn is_deep_space(&self) -> bool {
+        sgp4::is_deep_space(self)
+    }
+
     /// Calculate apogee altitude in km.
     pub fn apogee_km(&self) -> f64 {
         let a = self.semi_major_axis_km();
@@ -245,10 +934,58 @@ impl TLE {
         a * (1.0 - self.eccentricity) - EARTH_RADIUS_KM
     }
 
+    /// Estimate when the object will reenter the atmosphere.
+    ///
+    /// Only objects with a perigee below 200 km and a significant positive
+    /// `bstar` are considered. The ballistic coefficient is recovered from
+    /// `bstar`, and the perigee is decayed as a circular orbit through the
+    /// default [`ExponentialAtmosphere`] until it reaches 120 km.
+    ///
+    /// This is a crude model: it ignores solar activity, eccentricity, and
+    /// attitude-dependent drag, so treat the result as an order-of-magnitude
+    /// estimate rather than an operational reentry prediction.
+    ///
+    /// # Returns
+    ///
+    /// Estimated reentry time, or `None` if the object is not decaying.
+    pub fn estimated_reentry(&self) -> Option<DateTime<Utc>> {
+        self.estimated_reentry_with(&ExponentialAtmosphere::default())
+    }
+
+    /// Estimate reentry time using a specific atmosphere model.
+    ///
+    /// See [`estimated_reentry`](Self::estimated_reentry) for the method and
+    /// its limitations.
+    ///
+    /// # Arguments
+    ///
+    /// * `atmosphere` - Density model applied for drag
+    ///
+    /// # Returns
+    ///
+    /// Estimated reentry time, or `None` if the object is not decaying.
+    pub fn estimated_reentry_with(&self, atmosphere: &dyn Atmosphere) -> Option<DateTime<Utc>> {
+        let perigee = self.perigee_km();
+        if perigee >= DECAY_PERIGEE_THRESHOLD_KM || self.bstar < MIN_DECAY_BSTAR {
+            return None;
+        }
+        if perigee <= REENTRY_ALTITUDE_KM {
+            return Some(self.epoch());
+        }
+
+        let seconds = seconds_to_reentry(atmosphere, self.ballistic_coefficient(), perigee);
+        Some(self.epoch() + chrono::Duration::milliseconds((seconds * 1000.0) as i64))
+    }
+
+    /// Ballistic coefficient B = Cd·A/m in m²/kg, recovered from `bstar`.
+    pub(crate) fn ballistic_coefficient(&self) -> f64 {
+        2.0 * self.bstar / BSTAR_REFERENCE_DENSITY
+    }
+
     /// Convert TLE to Orbit object.
     ///
-    /// Note: This uses osculating elements at epoch. For accurate
-    /// propagation, use SGP4/SDP4.
+    /// Note: This treats the mean elements at epoch as osculating. For
+    /// accurate propagation, use SGP4/SDP4.
     pub fn to_orbit(&self) -> Result<Orbit, ValidationError> {
         Orbit::new(
             self.semi_major_axis_km(),
@@ -256,14 +993,35 @@ impl TLE {
             self.inclination,
             self.raan,
             self.arg_perigee,
-            self.mean_anomaly, // Approximation
+            mean_to_true_anomaly(self.mean_anomaly, self.eccentricity),
         )
     }
 
+    /// Compare this element set against another.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Element set to compare against, typically a later one
+    ///
+    /// # Returns
+    ///
+    /// Element deltas from `self` to `other`.
+    pub fn diff(&self, other: &TLE) -> TleDiff {
+        TleDiff {
+            semi_major_axis_km: other.semi_major_axis_km() - self.semi_major_axis_km(),
+            inclination_deg: other.inclination - self.inclination,
+            raan_deg: (other.raan - self.raan + 180.0).rem_euclid(360.0) - 180.0,
+            eccentricity: other.eccentricity - self.eccentricity,
+            mean_motion: other.mean_motion - self.mean_motion,
+            epoch_gap_hours: (other.epoch() - self.epoch()).num_milliseconds() as f64 / 3_600_000.0,
+        }
+    }
+
     /// Propagate the orbit to a given time.
     ///
-    /// This is a simplified propagation. For accurate results,
-    /// use the `sgp4` feature.
+    /// Uses SGP4; see [`propagate_sgp4`](Self::propagate_sgp4). For quick
+    /// estimates, [`propagate_with`](Self::propagate_with) with
+    /// [`PropagationAccuracy::TwoBody`] skips the model initialization.
     ///
     /// # Arguments
     ///
@@ -273,54 +1031,620 @@ impl TLE {
     ///
     /// Estimated position at the given time.
     pub fn propagate(&self, dt: DateTime<Utc>) -> Result<Position, ValidationError> {
-        // Simplified propagation - just use mean motion
-        let minutes_since_epoch = (dt - self.epoch()).num_milliseconds() as f64 / 60000.0;
-        let revolutions = minutes_since_epoch / self.orbital_period_minutes();
-
-        // Simple circular orbit approximation
-        let mean_anomaly_rad = self.mean_anomaly.to_radians();
-        let new_anomaly = mean_anomaly_rad + revolutions * 2.0 * PI;
-
-        // Convert to lat/lon (very simplified)
-        let lat = (self.inclination.to_radians().sin() * new_anomaly.sin())
-            .asin()
-            .to_degrees();
-        let mut lon = new_anomaly.to_degrees() - 180.0;
-        while lon < -180.0 {
-            lon += 360.0;
-        }
-        while lon > 180.0 {
-            lon -= 360.0;
-        }
-
-        let alt = (self.apogee_km() + self.perigee_km()) / 2.0;
-
-        Position::new(lat, lon, alt)
+        self.propagate_sgp4(dt)
     }
-}
 
-/// Parse TLE scientific notation (without 'E').
-/// e.g., " 12345-6" means 0.12345 * 10^-6
-fn parse_tle_scientific(s: &str) -> f64 {
-    let trimmed = s.trim();
-    if trimmed.is_empty() {
-        return 0.0;
+    /// Propagate the orbit to a given time with SGP4/SDP4.
+    ///
+    /// AFSPC-compatible: WGS-72 constants, with the deep-space branch for
+    /// periods of 225 minutes or more. The model is initialized on every
+    /// call, so add the TLE to a [`Tracker`](crate::Tracker) and use its
+    /// batch queries when sampling many times.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - Target datetime (UTC)
+    ///
+    /// # Returns
+    ///
+    /// Estimated position at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dt` is after the [estimated reentry](Self::estimated_reentry),
+    /// drag drives the elements out of range, or the orbit has decayed.
+    pub fn propagate_sgp4(&self, dt: DateTime<Utc>) -> Result<Position, ValidationError> {
+        self.propagate_with(dt, PropagationAccuracy::Sgp4)
     }
 
-    // Handle sign at the beginning
-    let (sign, s) = if trimmed.starts_with('-') {
-        (-1.0, &trimmed[1..])
-    } else if trimmed.starts_with('+') {
-        (1.0, &trimmed[1..])
-    } else {
-        (1.0, trimmed)
-    };
-
-    if s.len() < 2 {
-        return 0.0;
+    /// Propagate the orbit to a given time with the chosen model.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - Target datetime (UTC)
+    /// * `accuracy` - Propagation model
+    ///
+    /// # Returns
+    ///
+    /// Estimated position at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dt` is after the [estimated reentry](Self::estimated_reentry)
+    /// or the propagated position is below the surface.
+    pub fn propagate_with(
+        &self,
+        dt: DateTime<Utc>,
+        accuracy: PropagationAccuracy,
+    ) -> Result<Position, ValidationError> {
+        TlePropagator::borrowed(self).position(dt, accuracy)
     }
 
-    // Extract mantissa and exponent
+    /// Propagate the orbit to a state vector in the chosen frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - Target datetime (UTC)
+    /// * `accuracy` - Propagation model
+    /// * `frame` - Output frame
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dt` is after the [estimated reentry](Self::estimated_reentry).
+    pub fn state_with(
+        &self,
+        dt: DateTime<Utc>,
+        accuracy: PropagationAccuracy,
+        frame: Frame,
+    ) -> Result<StateVector, ValidationError> {
+        TlePropagator::borrowed(self).state(dt, accuracy, frame)
+    }
+
+    /// SGP4 TEME position (km) and velocity (km/s) at the given time.
+    fn sgp4_state(&self, dt: DateTime<Utc>) -> Result<([f64; 3], [f64; 3]), ValidationError> {
+        self.sgp4_propagator()?.state(dt)
+    }
+
+    /// SGP4 model for this element set, for propagating to many times
+    /// without re-initializing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if SGP4 rejects the elements.
+    pub(crate) fn sgp4_propagator(&self) -> Result<Sgp4Propagator, ValidationError> {
+        Ok(Sgp4Propagator {
+            model: Sgp4::new(self)?,
+            epoch: self.epoch(),
+        })
+    }
+
+    /// Position at `dt` blended from two element sets of the same object.
+    ///
+    /// Both sets are propagated with SGP4 and their ECI positions weighted by
+    /// how close `dt` is to each epoch, so the track moves smoothly from one
+    /// set to the next instead of jumping at the handover. Outside the
+    /// bracketing epochs only the nearer set is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - One element set
+    /// * `b` - The other element set, in either epoch order
+    /// * `dt` - Target datetime (UTC)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if propagating a contributing element set fails.
+    pub fn interpolate_position(
+        a: &TLE,
+        b: &TLE,
+        dt: DateTime<Utc>,
+    ) -> Result<Position, ValidationError> {
+        let (first, second) = if a.epoch() <= b.epoch() {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let span_ms = (second.epoch() - first.epoch()).num_milliseconds() as f64;
+        let weight = if span_ms > 0.0 {
+            ((dt - first.epoch()).num_milliseconds() as f64 / span_ms).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        if weight == 0.0 {
+            return first.propagate_sgp4(dt);
+        }
+        if weight == 1.0 {
+            return second.propagate_sgp4(dt);
+        }
+
+        first.check_before_reentry(dt)?;
+        second.check_before_reentry(dt)?;
+        let from = Coordinate3D::from(first.sgp4_state(dt)?.0);
+        let to = Coordinate3D::from(second.sgp4_state(dt)?.0);
+        // Blend direction and radius separately so diverging sets don't cut
+        // the chord below the orbit
+        let blended = from + (to - from) * weight;
+        let radius = from.magnitude() + weight * (to.magnitude() - from.magnitude());
+        let eci = blended * (radius / blended.magnitude().max(1e-9));
+        geodetic_position(eci_to_ecef(eci.into(), gmst_rad(dt)))
+    }
+
+    /// Sample the subsatellite ground track.
+    ///
+    /// Longitudes stay in [-180, 180], so the track jumps by ~360° at the
+    /// antimeridian; pass it to [`split_ground_track`] for polylines that can
+    /// be drawn directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start time
+    /// * `end` - End time (inclusive)
+    /// * `step_seconds` - Time step between samples (at least 1)
+    ///
+    /// # Returns
+    ///
+    /// (time, position) samples in time order. Each sample comes from SGP4,
+    /// or from the J2 model if SGP4 rejects the elements or fails at that
+    /// time. Samples after the estimated reentry, or that propagate below
+    /// the surface, are left out, so the result can have gaps or be empty.
+    pub fn ground_track(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step_seconds: i64,
+    ) -> Vec<(DateTime<Utc>, Position)> {
+        let step = chrono::Duration::seconds(step_seconds.max(1));
+        // Initialize once rather than per sample
+        let propagator = TlePropagator::borrowed(self);
+
+        std::iter::successors(Some(start), |t| Some(*t + step))
+            .take_while(|t| *t <= end)
+            .filter_map(|t| {
+                let eci = propagator
+                    .eci_position_km(t, PropagationAccuracy::Sgp4)
+                    .or_else(|_| propagator.eci_position_km(t, PropagationAccuracy::J2))
+                    .ok()?;
+                geodetic_position(eci_to_ecef(eci, gmst_rad(t)))
+                    .ok()
+                    .map(|p| (t, p))
+            })
+            .collect()
+    }
+
+    /// Reject times after the estimated reentry.
+    fn check_before_reentry(&self, dt: DateTime<Utc>) -> Result<(), ValidationError> {
+        check_before(self.estimated_reentry(), dt)
+    }
+
+    /// ECI (TEME) position at the given time, in km.
+    ///
+    /// SGP4 is initialized afresh on each call; use [`TlePropagator`] to
+    /// sample SGP4 at many times.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if SGP4 is asked for and fails.
+    pub(crate) fn eci_position_km(
+        &self,
+        dt: DateTime<Utc>,
+        accuracy: PropagationAccuracy,
+    ) -> Result<[f64; 3], ValidationError> {
+        match accuracy {
+            PropagationAccuracy::Sgp4 => Ok(self.sgp4_state(dt)?.0),
+            _ => Ok(self.analytic_eci_position_km(dt, accuracy)),
+        }
+    }
+
+    /// Two-body or J2 ECI (TEME) position at the given time, in km.
+    fn analytic_eci_position_km(
+        &self,
+        dt: DateTime<Utc>,
+        accuracy: PropagationAccuracy,
+    ) -> [f64; 3] {
+        let seconds_since_epoch = (dt - self.epoch()).num_milliseconds() as f64 / 1000.0;
+        let n_rad_per_sec = self.mean_motion_rad_per_s();
+        let a = self.semi_major_axis_km();
+        let e = self.eccentricity;
+
+        // Secular J2 rates (rad/s) of RAAN, argument of perigee, and mean anomaly
+        let (raan_rate, arg_perigee_rate, mean_anomaly_rate) = match accuracy {
+            PropagationAccuracy::TwoBody => (0.0, 0.0, n_rad_per_sec),
+            PropagationAccuracy::J2 | PropagationAccuracy::Sgp4 => {
+                let p = a * (1.0 - e * e);
+                let k = n_rad_per_sec * EARTH_J2 * (EARTH_RADIUS_KM / p).powi(2);
+                let cos_i = self.inclination_rad().cos();
+                (
+                    -1.5 * k * cos_i,
+                    0.75 * k * (5.0 * cos_i * cos_i - 1.0),
+                    n_rad_per_sec + 0.75 * k * (1.0 - e * e).sqrt() * (3.0 * cos_i * cos_i - 1.0),
+                )
+            }
+        };
+
+        let mean_anomaly = self.mean_anomaly_rad() + mean_anomaly_rate * seconds_since_epoch;
+        let ecc_anomaly = solve_kepler(mean_anomaly, e);
+
+        // Perifocal coordinates
+        let xp = a * (ecc_anomaly.cos() - e);
+        let yp = a * (1.0 - e * e).sqrt() * ecc_anomaly.sin();
+
+        // Rotate by argument of perigee, inclination, and RAAN into ECI
+        let (sin_w, cos_w) =
+            (self.arg_perigee_rad() + arg_perigee_rate * seconds_since_epoch).sin_cos();
+        let (sin_i, cos_i) = self.inclination_rad().sin_cos();
+        let (sin_o, cos_o) = (self.raan_rad() + raan_rate * seconds_since_epoch).sin_cos();
+
+        [
+            (cos_o * cos_w - sin_o * sin_w * cos_i) * xp
+                + (-cos_o * sin_w - sin_o * cos_w * cos_i) * yp,
+            (sin_o * cos_w + cos_o * sin_w * cos_i) * xp
+                + (-sin_o * sin_w + cos_o * cos_w * cos_i) * yp,
+            (sin_w * sin_i) * xp + (cos_w * sin_i) * yp,
+        ]
+    }
+
+    /// ECI (TEME) velocity at the given time, in km/s.
+    ///
+    /// The two-body and J2 velocities are a central difference of positions
+    /// one second apart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if SGP4 is asked for and fails.
+    pub(crate) fn eci_velocity_km_s(
+        &self,
+        dt: DateTime<Utc>,
+        accuracy: PropagationAccuracy,
+    ) -> Result<[f64; 3], ValidationError> {
+        if accuracy == PropagationAccuracy::Sgp4 {
+            return Ok(self.sgp4_state(dt)?.1);
+        }
+        let half_step = chrono::Duration::milliseconds(500);
+        let before = self.analytic_eci_position_km(dt - half_step, accuracy);
+        let after = self.analytic_eci_position_km(dt + half_step, accuracy);
+        Ok([
+            after[0] - before[0],
+            after[1] - before[1],
+            after[2] - before[2],
+        ])
+    }
+
+    /// Element set for the same object whose two-body elements reproduce the
+    /// given ECI state at `epoch`.
+    ///
+    /// The result holds osculating elements, so it is only meaningful for
+    /// two-body or J2 propagation, not SGP4.
+    pub(crate) fn with_eci_state(
+        &self,
+        epoch: DateTime<Utc>,
+        position: [f64; 3],
+        velocity: [f64; 3],
+    ) -> TLE {
+        let (position, velocity) = (Coordinate3D::from(position), Coordinate3D::from(velocity));
+        let r = position.magnitude();
+        let v2 = velocity.dot(&velocity);
+        let rv = position.dot(&velocity);
+
+        let h = position.cross(&velocity);
+        let node = {
+            let n = Coordinate3D::new(-h.y, h.x, 0.0);
+            if n.magnitude() < 1e-9 {
+                Coordinate3D::new(1.0, 0.0, 0.0)
+            } else {
+                n
+            }
+        };
+        let e_vec = (position * (v2 - EARTH_MU / r) - velocity * rv) / EARTH_MU;
+        let e = e_vec.magnitude();
+        let a = 1.0 / (2.0 / r - v2 / EARTH_MU);
+
+        let inclination = (h.z / h.magnitude()).clamp(-1.0, 1.0).acos();
+        let raan = node.y.atan2(node.x).rem_euclid(2.0 * PI);
+        // Signed angle from `from` to `to` measured about the orbit normal
+        let angle = |from: Coordinate3D, to: Coordinate3D| {
+            from.cross(&to).dot(&h).signum()
+                * (from.dot(&to) / (from.magnitude() * to.magnitude()))
+                    .clamp(-1.0, 1.0)
+                    .acos()
+        };
+        let (arg_perigee, true_anomaly) = if e < 1e-9 {
+            // Circular: measure from the node
+            (0.0, angle(node, position))
+        } else {
+            (angle(node, e_vec), angle(e_vec, position))
+        };
+        let ecc_anomaly = ((1.0 - e * e).sqrt() * true_anomaly.sin()).atan2(e + true_anomaly.cos());
+        let mean_anomaly = ecc_anomaly - e * ecc_anomaly.sin();
+
+        let year = epoch.year();
+        let jan1 = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+        let mut tle = self.clone();
+        tle.epoch_year = year.rem_euclid(100) as u16;
+        tle.epoch_day = 1.0 + (epoch - jan1).num_milliseconds() as f64 / (SECONDS_PER_DAY * 1000.0);
+        tle.inclination = inclination.to_degrees();
+        tle.raan = raan.to_degrees();
+        tle.eccentricity = e;
+        tle.arg_perigee = arg_perigee.rem_euclid(2.0 * PI).to_degrees();
+        tle.mean_anomaly = mean_anomaly.rem_euclid(2.0 * PI).to_degrees();
+        tle.mean_motion = (EARTH_MU / a.powi(3)).sqrt() * SECONDS_PER_DAY / (2.0 * PI);
+        tle
+    }
+}
+
+/// An initialized SGP4 model and the epoch of its element set.
+#[derive(Debug, Clone)]
+pub(crate) struct Sgp4Propagator {
+    model: Sgp4,
+    epoch: DateTime<Utc>,
+}
+
+impl Sgp4Propagator {
+    /// TEME position (km) and velocity (km/s) at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if propagation drives the elements out of range or
+    /// below the surface.
+    pub(crate) fn state(&self, dt: DateTime<Utc>) -> Result<([f64; 3], [f64; 3]), ValidationError> {
+        let minutes_since_epoch = (dt - self.epoch).num_milliseconds() as f64 / 60_000.0;
+        self.model.propagate(minutes_since_epoch)
+    }
+}
+
+/// Reject times after `reentry`, if there is one.
+fn check_before(reentry: Option<DateTime<Utc>>, dt: DateTime<Utc>) -> Result<(), ValidationError> {
+    match reentry {
+        Some(reentry) if dt > reentry => Err(ValidationError::new(
+            "dt",
+            format!(
+                "Requested time is after estimated reentry at {}",
+                reentry.to_rfc3339()
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// An element set prepared for propagation to many times.
+///
+/// The SGP4 model and the estimated reentry are computed on first use and
+/// reused for every later sample, whatever the accuracy asked for.
+#[derive(Debug)]
+pub(crate) struct TlePropagator<'a> {
+    tle: Cow<'a, TLE>,
+    sgp4: OnceLock<Result<Sgp4Propagator, ValidationError>>,
+    reentry: OnceLock<Option<DateTime<Utc>>>,
+}
+
+impl<'a> TlePropagator<'a> {
+    /// Propagator over a borrowed element set.
+    pub(crate) fn borrowed(tle: &'a TLE) -> Self {
+        Self {
+            tle: Cow::Borrowed(tle),
+            sgp4: OnceLock::new(),
+            reentry: OnceLock::new(),
+        }
+    }
+
+    /// The element set being propagated.
+    pub(crate) fn tle(&self) -> &TLE {
+        &self.tle
+    }
+
+    /// The initialized SGP4 model, or why SGP4 rejected the elements.
+    fn sgp4(&self) -> Result<&Sgp4Propagator, ValidationError> {
+        self.sgp4
+            .get_or_init(|| self.tle.sgp4_propagator())
+            .as_ref()
+            .map_err(|e| ValidationError::new(e.field.clone(), e.message.clone()))
+    }
+
+    /// Reject times after the estimated reentry, integrating the decay
+    /// only the first time.
+    fn check_before_reentry(&self, dt: DateTime<Utc>) -> Result<(), ValidationError> {
+        check_before(
+            *self.reentry.get_or_init(|| self.tle.estimated_reentry()),
+            dt,
+        )
+    }
+
+    /// ECI (TEME) position at the given time, in km.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dt` is after the estimated reentry or SGP4 fails.
+    pub(crate) fn eci_position_km(
+        &self,
+        dt: DateTime<Utc>,
+        accuracy: PropagationAccuracy,
+    ) -> Result<[f64; 3], ValidationError> {
+        self.check_before_reentry(dt)?;
+        match accuracy {
+            PropagationAccuracy::Sgp4 => Ok(self.sgp4()?.state(dt)?.0),
+            // Position only; the velocity a full state needs would triple the cost
+            _ => self.tle.eci_position_km(dt, accuracy),
+        }
+    }
+
+    /// Geodetic position at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dt` is after the estimated reentry, SGP4 fails,
+    /// or the position is below the surface.
+    pub(crate) fn position(
+        &self,
+        dt: DateTime<Utc>,
+        accuracy: PropagationAccuracy,
+    ) -> Result<Position, ValidationError> {
+        let eci = self.eci_position_km(dt, accuracy)?;
+        geodetic_position(eci_to_ecef(eci, gmst_rad(dt)))
+    }
+
+    /// State vector in the chosen frame at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dt` is after the estimated reentry or SGP4 fails.
+    pub(crate) fn state(
+        &self,
+        dt: DateTime<Utc>,
+        accuracy: PropagationAccuracy,
+        frame: Frame,
+    ) -> Result<StateVector, ValidationError> {
+        self.check_before_reentry(dt)?;
+        let (position_km, velocity_km_s) = match accuracy {
+            PropagationAccuracy::Sgp4 => self.sgp4()?.state(dt)?,
+            _ => (
+                self.tle.eci_position_km(dt, accuracy)?,
+                self.tle.eci_velocity_km_s(dt, accuracy)?,
+            ),
+        };
+        let state = StateVector {
+            time: dt,
+            frame: Frame::Teme,
+            position_km,
+            velocity_km_s,
+        };
+        Ok(state.to_frame(frame))
+    }
+}
+
+impl TlePropagator<'static> {
+    /// Propagator that owns its element set.
+    pub(crate) fn owned(tle: TLE) -> Self {
+        Self {
+            tle: Cow::Owned(tle),
+            sgp4: OnceLock::new(),
+            reentry: OnceLock::new(),
+        }
+    }
+
+    /// Give back the element set.
+    pub(crate) fn into_tle(self) -> TLE {
+        self.tle.into_owned()
+    }
+}
+
+/// Solve Kepler's equation `M = E - e·sin(E)` for the eccentric anomaly.
+fn solve_kepler(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let m = mean_anomaly.rem_euclid(2.0 * PI);
+    let mut e_anom = if eccentricity > 0.8 { PI } else { m };
+    for _ in 0..50 {
+        let delta =
+            (e_anom - eccentricity * e_anom.sin() - m) / (1.0 - eccentricity * e_anom.cos());
+        e_anom -= delta;
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+    e_anom
+}
+
+/// Convert a mean anomaly to the true anomaly, both in degrees.
+///
+/// The result is in [0, 360).
+fn mean_to_true_anomaly(mean_anomaly_deg: f64, eccentricity: f64) -> f64 {
+    let ecc_anomaly = solve_kepler(mean_anomaly_deg.to_radians(), eccentricity);
+    let true_anomaly = ((1.0 - eccentricity * eccentricity).sqrt() * ecc_anomaly.sin())
+        .atan2(ecc_anomaly.cos() - eccentricity);
+    true_anomaly.to_degrees().rem_euclid(360.0)
+}
+
+/// Greenwich Mean Sidereal Time in radians (IAU 1982).
+pub(crate) fn gmst_rad(dt: DateTime<Utc>) -> f64 {
+    let julian_date = dt.timestamp_millis() as f64 / 86_400_000.0 + 2_440_587.5;
+    let t = (julian_date - 2_451_545.0) / 36525.0;
+    let gmst_sec = 67310.54841 + (876600.0 * 3600.0 + 8640184.812866) * t + 0.093104 * t * t
+        - 6.2e-6 * t * t * t;
+    (gmst_sec.rem_euclid(SECONDS_PER_DAY) / SECONDS_PER_DAY) * 2.0 * PI
+}
+
+/// Unit vector from the Earth to the Sun in ECI.
+///
+/// Low-precision solar ephemeris from the Astronomical Almanac, good to about
+/// 0.01° between 1950 and 2050.
+pub(crate) fn sun_direction_eci(dt: DateTime<Utc>) -> [f64; 3] {
+    let days = dt.timestamp_millis() as f64 / 86_400_000.0 + 2_440_587.5 - 2_451_545.0;
+    let mean_longitude = 280.460 + 0.9856474 * days;
+    let mean_anomaly = (357.528 + 0.9856003 * days).to_radians();
+    let ecliptic_longitude =
+        (mean_longitude + 1.915 * mean_anomaly.sin() + 0.020 * (2.0 * mean_anomaly).sin())
+            .to_radians();
+    let obliquity = (23.439 - 0.0000004 * days).to_radians();
+
+    let (sin_l, cos_l) = ecliptic_longitude.sin_cos();
+    [cos_l, obliquity.cos() * sin_l, obliquity.sin() * sin_l]
+}
+
+/// Rotate an ECI vector into the Earth-fixed frame.
+pub(crate) fn eci_to_ecef(eci: [f64; 3], gmst: f64) -> [f64; 3] {
+    let (sin_g, cos_g) = gmst.sin_cos();
+    [
+        cos_g * eci[0] + sin_g * eci[1],
+        -sin_g * eci[0] + cos_g * eci[1],
+        eci[2],
+    ]
+}
+
+/// Convert Earth-fixed coordinates (km) to geodetic (lat°, lon°, alt km).
+pub(crate) fn ecef_to_geodetic(ecef: [f64; 3]) -> (f64, f64, f64) {
+    let [x, y, z] = ecef;
+    let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+    let p = (x * x + y * y).sqrt();
+    let lon = y.atan2(x);
+
+    let mut lat = z.atan2(p * (1.0 - e2));
+    let mut n = EARTH_RADIUS_KM;
+    for _ in 0..10 {
+        n = EARTH_RADIUS_KM / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+        lat = (z + e2 * n * lat.sin()).atan2(p);
+    }
+
+    let alt = if lat.cos().abs() > 1e-6 {
+        p / lat.cos() - n
+    } else {
+        z.abs() - n * (1.0 - e2)
+    };
+
+    (lat.to_degrees(), lon.to_degrees(), alt)
+}
+
+/// Geodetic position of an Earth-fixed point, rejecting points below the surface.
+fn geodetic_position(ecef: [f64; 3]) -> Result<Position, ValidationError> {
+    let (lat, lon, alt) = ecef_to_geodetic(ecef);
+    if alt < 0.0 {
+        return Err(ValidationError::new(
+            "altitude_km",
+            format!("Propagated altitude is below the surface ({:.1} km)", alt),
+        ));
+    }
+
+    Position::new(lat, lon, alt)
+}
+
+/// Parse TLE scientific notation (without 'E').
+/// e.g., " 12345-6" means 0.12345 * 10^-6
+fn parse_tle_scientific(s: &str) -> f64 {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return 0.0;
+    }
+
+    // Handle sign at the beginning
+    let (sign, s) = if let Some(rest) = trimmed.strip_prefix('-') {
+        (-1.0, rest)
+    } else if let Some(rest) = trimmed.strip_prefix('+') {
+        (1.0, rest)
+    } else {
+        (1.0, trimmed)
+    };
+
+    if s.len() < 2 {
+        return 0.0;
+    }
+
+    // Extract mantissa and exponent
     // Format: "NNNNN-E" or "NNNNN+E" where second-to-last char is exponent sign
     let exp_sign_char = s.chars().nth(s.len() - 2).unwrap_or('0');
     let exp_sign = if exp_sign_char == '-' { -1 } else { 1 };
@@ -343,8 +1667,184 @@ fn parse_tle_scientific(s: &str) -> f64 {
     sign * mantissa * 10_f64.powi(exponent)
 }
 
+/// Alpha-5 leading characters for catalog numbers 100000-339999 (I and O
+/// are skipped to avoid confusion with 1 and 0)
+const ALPHA5_LETTERS: &str = "ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// Decode a 5-character catalog number, numeric or Alpha-5 (e.g. "B1234"
+/// is 111234).
+fn decode_alpha5(field: &str) -> Option<u32> {
+    let field = field.trim();
+    let first = field.chars().next()?;
+    if first.is_ascii_digit() {
+        return field.parse().ok();
+    }
+    let prefix = ALPHA5_LETTERS.find(first.to_ascii_uppercase())? as u32 + 10;
+    let rest = &field[1..];
+    if rest.len() != 4 || !rest.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(prefix * 10_000 + rest.parse::<u32>().ok()?)
+}
+
+/// Encode a catalog number in 5 characters, using Alpha-5 above 99999.
+///
+/// Numbers beyond the Alpha-5 range (339999) are written modulo 100000.
+fn encode_alpha5(norad_id: u32) -> String {
+    match ALPHA5_LETTERS
+        .chars()
+        .nth((norad_id / 10_000).saturating_sub(10) as usize)
+    {
+        Some(letter) if norad_id >= 100_000 => format!("{}{:04}", letter, norad_id % 10_000),
+        _ => format!("{:05}", norad_id % 100_000),
+    }
+}
+
+/// Format a value in TLE decimal-point notation (10 chars, e.g. " .00001082").
+///
+/// The field has no room for an integer digit, so magnitudes from 0.99999999
+/// up saturate there and NaN is written as zero.
+fn format_tle_decimal(value: f64) -> String {
+    let magnitude = if value.is_nan() {
+        0.0
+    } else {
+        value.abs().min(0.999_999_99)
+    };
+    let digits = format!("{:.8}", magnitude);
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    format!("{}{}", sign, digits.strip_prefix('0').unwrap_or(&digits))
+}
+
+/// Format a value in TLE scientific notation (8 chars, e.g. " 27450-4").
+///
+/// The inverse of [`parse_tle_scientific`]: five mantissa digits with an
+/// implied leading "0." and a signed single-digit exponent. Below 0.1e-9 the
+/// exponent stays at -9 and the mantissa loses leading digits, so magnitudes
+/// under 0.000005e-9 round to zero; magnitudes from 0.99999e9 up saturate.
+fn format_tle_scientific(value: f64) -> String {
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    if value == 0.0 || !value.is_finite() {
+        return format!("{}00000-0", sign);
+    }
+
+    let mut exponent = (value.abs().log10().floor() as i32 + 1).max(-9);
+    let mut mantissa = (value.abs() / 10f64.powi(exponent) * 1e5).round() as u32;
+    // Rounding can carry into a sixth digit (0.999996 -> 1.00000)
+    if mantissa >= 100_000 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    if mantissa == 0 {
+        return format!("{}00000-0", sign);
+    }
+    if exponent > 9 {
+        return format!("{}99999+9", sign);
+    }
+    let exponent_sign = if exponent < 0 { '-' } else { '+' };
+    format!("{}{:05}{}{}", sign, mantissa, exponent_sign, exponent.abs())
+}
+
+/// Strip a UTF-8 byte order mark and surrounding whitespace from a TLE line.
+fn clean_line(line: &str) -> &str {
+    line.trim_start_matches('\u{feff}').trim()
+}
+
+/// Split a ground track where it crosses the antimeridian.
+///
+/// A new segment starts wherever consecutive longitudes differ by more than
+/// 180°, so each segment can be drawn as a polyline without a line across
+/// the whole map.
+///
+/// # Arguments
+///
+/// * `track` - Samples from [`TLE::ground_track`]
+///
+/// # Returns
+///
+/// Track segments in time order.
+pub fn split_ground_track(
+    track: &[(DateTime<Utc>, Position)],
+) -> Vec<Vec<(DateTime<Utc>, Position)>> {
+    let mut segments: Vec<Vec<(DateTime<Utc>, Position)>> = Vec::new();
+    for &sample in track {
+        match segments.last_mut() {
+            Some(segment)
+                if segment.last().is_some_and(|(_, last)| {
+                    (sample.1.longitude - last.longitude).abs() <= 180.0
+                }) =>
+            {
+                segment.push(sample)
+            }
+            _ => segments.push(vec![sample]),
+        }
+    }
+    segments
+}
+
+/// Cleaned, non-blank lines.
+fn clean_lines(lines: &[String]) -> Vec<&str> {
+    lines
+        .iter()
+        .map(|l| clean_line(l))
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// Parse an OMM ISO-8601 epoch (UTC, optional trailing `Z`) into the
+/// two-digit year and fractional day of year used by TLEs.
+fn parse_omm_epoch(epoch: &str) -> Option<(u16, f64)> {
+    let epoch = epoch.trim().trim_end_matches('Z');
+    let dt = NaiveDateTime::parse_from_str(epoch, "%Y-%m-%dT%H:%M:%S%.f").ok()?;
+    Some(epoch_fields(dt))
+}
+
+/// TLE epoch fields (2-digit year, fractional day of year) for a time.
+fn epoch_fields(dt: NaiveDateTime) -> (u16, f64) {
+    let seconds = dt.num_seconds_from_midnight() as f64 + dt.nanosecond() as f64 / 1e9;
+    (
+        dt.year().rem_euclid(100) as u16,
+        dt.ordinal() as f64 + seconds / SECONDS_PER_DAY,
+    )
+}
+
+/// Mod-10 checksum of a TLE line.
+///
+/// Digits count as their value, minus signs as 1, and everything else as 0.
+/// Only the first 68 characters are summed, so a full 69-character line can
+/// be passed as-is.
+pub fn checksum(line: &str) -> u8 {
+    let sum: u32 = line
+        .chars()
+        .take(68)
+        .map(|c| match c {
+            '-' => 1,
+            c => c.to_digit(10).unwrap_or(0),
+        })
+        .sum();
+    (sum % 10) as u8
+}
+
+/// Verify the checksum digit in column 69 of a TLE line.
+fn check_line_checksum(field: &str, line: &str) -> Result<(), ValidationError> {
+    let expected = checksum(line);
+    match line.chars().nth(68).and_then(|c| c.to_digit(10)) {
+        Some(digit) if digit as u8 == expected => Ok(()),
+        Some(digit) => Err(ValidationError::new(
+            field,
+            format!(
+                "Checksum mismatch: line ends in {} but sums to {}",
+                digit, expected
+            ),
+        )),
+        None => Err(ValidationError::new(field, "Missing checksum digit")),
+    }
+}
+
 /// Parse multiple TLEs from text.
 ///
+/// Tolerates CRLF line endings, a leading UTF-8 BOM, and blank lines.
+/// Malformed records are skipped; use [`parse_tle_report`] to find out which.
+///
 /// # Arguments
 ///
 /// * `text` - Text containing one or more TLEs
@@ -353,47 +1853,89 @@ fn parse_tle_scientific(s: &str) -> f64 {
 ///
 /// Vector of parsed TLE objects.
 pub fn parse_tle(text: &str) -> Vec<TLE> {
-    let lines: Vec<String> = text
+    parse_tle_report(text).0
+}
+
+/// Parse multiple TLEs from text, reporting the records that failed.
+///
+/// Accepts the same input as [`parse_tle`]. Lines that can't be assigned to
+/// a record (a line 1 with no line 2, or stray text) are reported too, so
+/// nothing is dropped silently.
+///
+/// # Arguments
+///
+/// * `text` - Text containing one or more TLEs, e.g. a CelesTrak bulk file
+///
+/// # Returns
+///
+/// The parsed TLEs, and for each failed record its starting line number
+/// (1-based, counting blank lines) with the reason.
+pub fn parse_tle_report(text: &str) -> (Vec<TLE>, Vec<(usize, ValidationError)>) {
+    let (numbers, lines): (Vec<usize>, Vec<String>) = text
         .lines()
-        .map(|l| l.trim().to_string())
-        .filter(|l| !l.is_empty())
-        .collect();
+        .enumerate()
+        .map(|(i, l)| (i + 1, clean_line(l).to_string()))
+        .filter(|(_, l)| !l.is_empty())
+        .unzip();
 
     let mut tles = Vec::new();
+    let mut errors = Vec::new();
     let mut i = 0;
 
     while i < lines.len() {
-        if lines[i].starts_with("1 ") {
+        let record_len = if lines[i].starts_with("1 ") {
             // No name, just lines 1 and 2
             if i + 1 < lines.len() && lines[i + 1].starts_with("2 ") {
-                if let Ok(tle) = TLE::parse(&lines[i..i + 2].to_vec()) {
-                    tles.push(tle);
-                }
-                i += 2;
+                2
             } else {
+                errors.push((
+                    numbers[i],
+                    ValidationError::new("line2", "Line 1 is not followed by line 2"),
+                ));
                 i += 1;
+                continue;
             }
         } else if i + 2 < lines.len() && lines[i + 1].starts_with("1 ") {
             // Name + line 1 + line 2
-            if let Ok(tle) = TLE::parse(&lines[i..i + 3].to_vec()) {
-                tles.push(tle);
-            }
-            i += 3;
+            3
         } else {
+            errors.push((
+                numbers[i],
+                ValidationError::new("lines", "Line is not part of a TLE record"),
+            ));
             i += 1;
+            continue;
+        };
+
+        match TLE::parse(&lines[i..i + record_len]) {
+            Ok(tle) => tles.push(tle),
+            Err(e) => errors.push((numbers[i], e)),
         }
+        i += record_len;
     }
 
-    tles
+    (tles, errors)
 }
 
+/// ISS element set shared by the crate's tests
+#[cfg(test)]
+pub(crate) const ISS_TLE: &str = r#"ISS (ZARYA)
+1 25544U 98067A   21275.52243902  .00001082  00000-0  27450-4 0  9999
+2 25544  51.6443 208.5943 0003631 355.3422 144.3824 15.48919755304818"#;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const ISS_TLE: &str = r#"ISS (ZARYA)
-1 25544U 98067A   21275.52243902  .00001082  00000-0  27450-4 0  9999
-2 25544  51.6443 208.5943 0003631 355.3422 144.3824 15.48919755304818"#;
+    /// `ISS_TLE` with correct checksum digits, for the checksum-validating paths
+    const ISS_TLE_VALID_CHECKSUM: &str = r#"ISS (ZARYA)
+1 25544U 98067A   21275.52243902  .00001082  00000-0  27450-4 0  9997
+2 25544  51.6443 208.5943 0003631 355.3422 144.3824 15.48919755304819"#;
+
+    /// ISS elements about three days after `ISS_TLE`
+    const ISS_TLE_LATER: &str = r#"ISS (ZARYA)
+1 25544U 98067A   21278.51781412  .00001251  00000-0  31244-4 0  9996
+2 25544  51.6441 193.7687 0003578   2.1051 117.6534 15.48931467305289"#;
 
     #[test]
     fn test_parse_tle() {
@@ -427,4 +1969,733 @@ mod tests {
         assert_eq!(tles.len(), 1);
         assert_eq!(tles[0].name, "ISS (ZARYA)");
     }
+
+    #[test]
+    fn test_estimated_reentry() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+
+        // ISS-like orbit that has decayed to a ~190 km perigee
+        let mut decaying = TLE::parse(&lines).unwrap();
+        decaying.mean_motion = 16.3;
+        decaying.bstar = 2.7e-4;
+        assert!(decaying.perigee_km() < 200.0);
+        let reentry = decaying.estimated_reentry().unwrap();
+        assert!(reentry > decaying.epoch());
+        assert!(reentry < decaying.epoch() + chrono::Duration::days(365));
+
+        let mut geo = TLE::parse(&lines).unwrap();
+        geo.mean_motion = 1.0027;
+        geo.inclination = 0.05;
+        assert!(geo.estimated_reentry().is_none());
+    }
+
+    #[test]
+    fn test_propagator_reuses_reentry_bound() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let mut decaying = TLE::parse(&lines).unwrap();
+        decaying.mean_motion = 16.3;
+        decaying.bstar = 2.7e-4;
+        let reentry = decaying.estimated_reentry().unwrap();
+
+        let propagator = TlePropagator::borrowed(&decaying);
+        assert!(propagator.reentry.get().is_none());
+        assert!(propagator
+            .position(decaying.epoch(), PropagationAccuracy::J2)
+            .is_ok());
+        assert_eq!(propagator.reentry.get(), Some(&Some(reentry)));
+        let after_reentry = reentry + chrono::Duration::minutes(1);
+        assert!(propagator
+            .position(after_reentry, PropagationAccuracy::J2)
+            .is_err());
+    }
+
+    #[test]
+    fn test_reentry_atmosphere_models() {
+        use crate::atmosphere::Us76Atmosphere;
+
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let mut decaying = TLE::parse(&lines).unwrap();
+        decaying.mean_motion = 16.3;
+        decaying.bstar = 2.7e-4;
+        let lifetime_days = |atmosphere: &dyn Atmosphere| {
+            (decaying.estimated_reentry_with(atmosphere).unwrap() - decaying.epoch()).num_minutes()
+                as f64
+                / 1440.0
+        };
+
+        let exponential = lifetime_days(&ExponentialAtmosphere::default());
+        let us76 = lifetime_days(&Us76Atmosphere);
+        assert!(
+            exponential / us76 > 1.0 / 3.0 && exponential / us76 < 3.0,
+            "{} vs {} days",
+            exponential,
+            us76
+        );
+
+        // Solar maximum: same profile, double the density
+        let dense = ExponentialAtmosphere {
+            reference_density_kg_m3: 2.0 * 2.438e-8,
+            ..ExponentialAtmosphere::default()
+        };
+        assert!(lifetime_days(&dense) < exponential);
+    }
+
+    #[test]
+    fn test_parse_crlf_bom_blank_lines() {
+        let clean = parse_tle(ISS_TLE);
+        let messy = format!("\u{feff}{}\r\n\r\n", ISS_TLE.replace('\n', "\r\n\r\n"));
+        let parsed = parse_tle(&messy);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(
+            serde_json::to_string(&parsed[0]).unwrap(),
+            serde_json::to_string(&clean[0]).unwrap()
+        );
+
+        // Two-line form with a BOM directly before line 1
+        let unnamed = format!(
+            "\u{feff}{}",
+            ISS_TLE.lines().skip(1).collect::<Vec<_>>().join("\r\n")
+        );
+        let parsed = parse_tle(&unnamed);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].norad_id, 25544);
+
+        let lines: Vec<String> = messy.split('\n').map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        assert_eq!(
+            serde_json::to_string(&tle).unwrap(),
+            serde_json::to_string(&clean[0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_radian_accessors() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+
+        assert_eq!(tle.inclination_rad(), tle.inclination.to_radians());
+        assert_eq!(tle.raan_rad(), tle.raan.to_radians());
+        assert_eq!(tle.arg_perigee_rad(), tle.arg_perigee.to_radians());
+        assert_eq!(tle.mean_anomaly_rad(), tle.mean_anomaly.to_radians());
+
+        // n = sqrt(mu / a^3)
+        let a = tle.semi_major_axis_km();
+        let n = (EARTH_MU / (a * a * a)).sqrt();
+        assert!((tle.mean_motion_rad_per_s() - n).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_propagate_eccentric_altitude_varies() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let mut tle = TLE::parse(&lines).unwrap();
+        tle.mean_motion = 12.0;
+        tle.eccentricity = 0.1;
+
+        let perigee = tle.perigee_km();
+        let apogee = tle.apogee_km();
+        let period_ms = (tle.orbital_period_minutes() * 60_000.0) as i64;
+
+        let altitudes: Vec<f64> = (0..=120)
+            .map(|i| {
+                let dt = tle.epoch() + chrono::Duration::milliseconds(period_ms * i / 120);
+                tle.propagate(dt).unwrap().altitude_km
+            })
+            .collect();
+        let min_alt = altitudes.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max_alt = altitudes.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        // Geodetic altitude differs from radius - Re by up to ~21 km (flattening)
+        assert!(
+            (min_alt - perigee).abs() < 30.0,
+            "min {} vs perigee {}",
+            min_alt,
+            perigee
+        );
+        assert!(
+            (max_alt - apogee).abs() < 30.0,
+            "max {} vs apogee {}",
+            max_alt,
+            apogee
+        );
+        assert!(max_alt - min_alt > 0.9 * (apogee - perigee));
+    }
+
+    #[test]
+    fn test_propagate_matches_epoch_radius() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let eci = tle
+            .eci_position_km(tle.epoch(), PropagationAccuracy::TwoBody)
+            .unwrap();
+        let r = (eci[0] * eci[0] + eci[1] * eci[1] + eci[2] * eci[2]).sqrt();
+        let a = tle.semi_major_axis_km();
+        assert!((r - a).abs() < a * tle.eccentricity + 1e-6);
+
+        // Earth rotation moves the ground track west between revolutions
+        let period =
+            chrono::Duration::milliseconds((tle.orbital_period_minutes() * 60_000.0) as i64);
+        let p0 = tle.propagate(tle.epoch()).unwrap();
+        let p1 = tle.propagate(tle.epoch() + period).unwrap();
+        assert!((p0.latitude - p1.latitude).abs() < 0.5);
+        let shift = (p0.longitude - p1.longitude).rem_euclid(360.0);
+        assert!((shift - 23.3).abs() < 1.0, "shift {}", shift);
+    }
+
+    #[test]
+    fn test_propagate_sgp4() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let dt = tle.epoch() + chrono::Duration::hours(6);
+
+        let sgp4 = tle.propagate_sgp4(dt).unwrap();
+        assert_eq!(tle.propagate(dt).unwrap().latitude, sgp4.latitude);
+        assert!(
+            sgp4.altitude_km > 400.0 && sgp4.altitude_km < 440.0,
+            "altitude {}",
+            sgp4.altitude_km
+        );
+
+        // The secular J2 model tracks SGP4 to within short-period terms
+        let estimate = tle
+            .state_with(dt, PropagationAccuracy::J2, Frame::Teme)
+            .unwrap();
+        let exact = tle
+            .state_with(dt, PropagationAccuracy::Sgp4, Frame::Teme)
+            .unwrap();
+        let error: f64 = (0..3)
+            .map(|i| (estimate.position_km[i] - exact.position_km[i]).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        assert!(error < 50.0, "J2 off by {:.1} km", error);
+    }
+
+    #[test]
+    fn test_checksum() {
+        let lines: Vec<String> = ISS_TLE_VALID_CHECKSUM
+            .lines()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(checksum(&lines[1]), 7);
+        assert_eq!(checksum(&lines[2]), 9);
+        let tle = TLE::parse_checked(&lines).unwrap();
+        assert!(tle.verify_checksum(&lines));
+
+        let mut corrupted = lines.clone();
+        corrupted[2] = corrupted[2].replace("304819", "304818");
+        assert!(!tle.verify_checksum(&corrupted));
+        let err = TLE::parse_checked(&corrupted).unwrap_err();
+        assert_eq!(err.field, "line2");
+        // Lenient parsing still accepts it
+        assert!(TLE::parse(&corrupted).is_ok());
+    }
+
+    #[test]
+    fn test_from_omm_json() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+
+        let omm = r#"[{"OBJECT_NAME":"ISS (ZARYA)","OBJECT_ID":"1998-067A","EPOCH":"2021-10-02T12:32:18.731328",
+            "MEAN_MOTION":15.48919755,"ECCENTRICITY":0.0003631,"INCLINATION":51.6443,"RA_OF_ASC_NODE":208.5943,
+            "ARG_OF_PERICENTER":355.3422,"MEAN_ANOMALY":144.3824,"EPHEMERIS_TYPE":0,"CLASSIFICATION_TYPE":"U",
+            "NORAD_CAT_ID":25544,"ELEMENT_SET_NO":999,"REV_AT_EPOCH":30481,"BSTAR":2.745e-5,
+            "MEAN_MOTION_DOT":1.082e-5,"MEAN_MOTION_DDOT":0}]"#;
+        let from_omm = TLE::from_omm_json(omm).unwrap();
+
+        assert_eq!(from_omm.name, tle.name);
+        assert_eq!(from_omm.norad_id, tle.norad_id);
+        assert_eq!(from_omm.intl_designator, tle.intl_designator);
+        assert_eq!(from_omm.epoch_year, tle.epoch_year);
+        assert!((from_omm.epoch_day - tle.epoch_day).abs() < 1e-9);
+        assert_eq!(from_omm.epoch(), tle.epoch());
+        assert!((from_omm.bstar - tle.bstar).abs() < 1e-12);
+        assert!((from_omm.mean_motion_dot - tle.mean_motion_dot).abs() < 1e-12);
+        assert_eq!(from_omm.element_number, tle.element_number);
+        assert_eq!(from_omm.rev_number, tle.rev_number);
+        assert_eq!(from_omm.eccentricity, tle.eccentricity);
+        assert_eq!(from_omm.mean_motion, tle.mean_motion);
+        assert_eq!(from_omm.raan, tle.raan);
+
+        let dt = tle.epoch() + chrono::Duration::hours(3);
+        let a = tle.propagate(dt).unwrap();
+        let b = from_omm.propagate(dt).unwrap();
+        assert!((a.latitude - b.latitude).abs() < 1e-6 && (a.longitude - b.longitude).abs() < 1e-6);
+
+        // Space-Track serves numbers as strings
+        let strings = omm
+            .replace("15.48919755", "\"15.48919755\"")
+            .replace("25544", "\"25544\"");
+        assert_eq!(
+            TLE::from_omm_json(&strings).unwrap().mean_motion,
+            tle.mean_motion
+        );
+
+        let missing = omm.replace("\"MEAN_MOTION\":15.48919755,", "");
+        assert_eq!(
+            TLE::from_omm_json(&missing).unwrap_err().field,
+            "mean_motion"
+        );
+
+        // Optional fields may be absent or null, but not garbage
+        let null_bstar = omm.replace("\"BSTAR\":2.745e-5", "\"BSTAR\":null");
+        assert_eq!(TLE::from_omm_json(&null_bstar).unwrap().bstar, 0.0);
+        let bad_bstar = omm.replace("\"BSTAR\":2.745e-5", "\"BSTAR\":\"abc\"");
+        assert_eq!(TLE::from_omm_json(&bad_bstar).unwrap_err().field, "bstar");
+        let bool_rev = omm.replace("\"REV_AT_EPOCH\":30481", "\"REV_AT_EPOCH\":true");
+        assert_eq!(
+            TLE::from_omm_json(&bool_rev).unwrap_err().field,
+            "rev_number"
+        );
+    }
+
+    #[test]
+    fn test_from_omm_xml() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+
+        let omm = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ndm xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <omm id="CCSDS_OMM_VERS" version="2.0">
+    <header><CREATION_DATE/><ORIGINATOR/></header>
+    <body><segment>
+      <metadata>
+        <OBJECT_NAME>ISS (ZARYA)</OBJECT_NAME>
+        <OBJECT_ID>1998-067A</OBJECT_ID>
+        <CENTER_NAME>EARTH</CENTER_NAME>
+        <REF_FRAME>TEME</REF_FRAME>
+        <TIME_SYSTEM>UTC</TIME_SYSTEM>
+        <MEAN_ELEMENT_THEORY>SGP4</MEAN_ELEMENT_THEORY>
+      </metadata>
+      <data>
+        <meanElements>
+          <EPOCH>2021-10-02T12:32:18.731328</EPOCH>
+          <MEAN_MOTION units="rev/day">15.48919755</MEAN_MOTION>
+          <ECCENTRICITY>.0003631</ECCENTRICITY>
+          <INCLINATION units="deg">51.6443</INCLINATION>
+          <RA_OF_ASC_NODE units="deg">208.5943</RA_OF_ASC_NODE>
+          <ARG_OF_PERICENTER units="deg">355.3422</ARG_OF_PERICENTER>
+          <MEAN_ANOMALY units="deg">144.3824</MEAN_ANOMALY>
+        </meanElements>
+        <tleParameters>
+          <EPHEMERIS_TYPE>0</EPHEMERIS_TYPE>
+          <CLASSIFICATION_TYPE>U</CLASSIFICATION_TYPE>
+          <NORAD_CAT_ID>25544</NORAD_CAT_ID>
+          <ELEMENT_SET_NO>999</ELEMENT_SET_NO>
+          <REV_AT_EPOCH>30481</REV_AT_EPOCH>
+          <BSTAR>.2745e-4</BSTAR>
+          <MEAN_MOTION_DOT>.1082e-4</MEAN_MOTION_DOT>
+          <MEAN_MOTION_DDOT>0</MEAN_MOTION_DDOT>
+        </tleParameters>
+      </data>
+    </segment></body>
+  </omm>
+</ndm>"#;
+        let from_omm = TLE::from_omm_xml(omm).unwrap();
+        assert_eq!(from_omm.name, tle.name);
+        assert_eq!(from_omm.norad_id, tle.norad_id);
+        assert_eq!(from_omm.intl_designator, tle.intl_designator);
+        assert_eq!(from_omm.epoch(), tle.epoch());
+        assert_eq!(from_omm.mean_motion, tle.mean_motion);
+        assert_eq!(from_omm.eccentricity, tle.eccentricity);
+        assert!((from_omm.bstar - tle.bstar).abs() < 1e-12);
+        assert!((from_omm.mean_motion_dot - tle.mean_motion_dot).abs() < 1e-12);
+
+        // The mean-motion derivatives are optional and default to zero
+        let minimal = omm
+            .replace("<MEAN_MOTION_DOT>.1082e-4</MEAN_MOTION_DOT>", "")
+            .replace("<MEAN_MOTION_DDOT>0</MEAN_MOTION_DDOT>", "");
+        let from_minimal = TLE::from_omm_xml(&minimal).unwrap();
+        assert_eq!(from_minimal.mean_motion_dot, 0.0);
+        assert_eq!(from_minimal.mean_motion_ddot, 0.0);
+        assert_eq!(from_minimal.mean_motion, tle.mean_motion);
+
+        let bare = r#"<omm><body><segment><data>
+  <meanElements>
+    <EPOCH>2021-10-02T12:32:18.731328Z</EPOCH><MEAN_MOTION>15.48919755</MEAN_MOTION>
+    <ECCENTRICITY>.0003631</ECCENTRICITY><INCLINATION>51.6443</INCLINATION><RA_OF_ASC_NODE>208.5943</RA_OF_ASC_NODE>
+    <ARG_OF_PERICENTER>355.3422</ARG_OF_PERICENTER><MEAN_ANOMALY>144.3824</MEAN_ANOMALY>
+  </meanElements>
+  <tleParameters><NORAD_CAT_ID>25544</NORAD_CAT_ID></tleParameters>
+</data></segment></body></omm>"#;
+        let from_bare = TLE::from_omm_xml(bare).unwrap();
+        assert_eq!(from_bare.name, "UNKNOWN");
+        assert_eq!(from_bare.bstar, 0.0);
+        assert_eq!(from_bare.epoch(), tle.epoch());
+
+        let no_elements = omm.replace("meanElements", "elements");
+        assert_eq!(TLE::from_omm_xml(&no_elements).unwrap_err().field, "omm");
+    }
+
+    #[test]
+    fn test_to_lines_round_trip() {
+        let lines: Vec<String> = ISS_TLE_VALID_CHECKSUM
+            .lines()
+            .map(|s| s.to_string())
+            .collect();
+        let tle = TLE::parse(&lines).unwrap();
+        assert_eq!(tle.to_lines(), lines);
+
+        let mut edited = tle.clone();
+        edited.norad_id = 5;
+        edited.epoch_day = 5.5;
+        edited.mean_motion_dot = -0.00000321;
+        edited.mean_motion_ddot = 0.12345e-6;
+        edited.bstar = -0.99999999e-3;
+        edited.eccentricity = 0.0000042;
+        edited.mean_motion = 2.00491383;
+        edited.rev_number = 7;
+        let lines = edited.to_lines();
+        assert!(lines[1..].iter().all(|line| line.len() == 69));
+        assert_eq!(&lines[1][33..43], "-.00000321");
+        assert_eq!(&lines[1][44..52], " 12345-6");
+        // The mantissa rounds up into the next exponent
+        assert_eq!(&lines[1][53..61], "-10000-2");
+        assert_eq!(&lines[2][26..33], "0000042");
+
+        let parsed = TLE::parse_checked(&lines).unwrap();
+        assert_eq!(parsed.norad_id, 5);
+        assert_eq!(format_tle_scientific(1e-12), " 00100-9");
+        assert!(
+            (parse_tle_scientific(&format_tle_scientific(-1.234e-12)) + 1.23e-12).abs() < 1e-20
+        );
+        assert_eq!(format_tle_scientific(4e-15), " 00000-0");
+        assert_eq!(format_tle_scientific(-4e-15), "-00000-0");
+        assert_eq!(format_tle_scientific(-2e12), "-99999+9");
+        assert!((parsed.epoch_day - 5.5).abs() < 1e-9);
+        assert!((parsed.mean_motion_dot - edited.mean_motion_dot).abs() < 1e-12);
+        assert!((parsed.mean_motion_ddot - edited.mean_motion_ddot).abs() < 1e-15);
+        assert!((parsed.bstar - edited.bstar).abs() < 1e-9);
+        assert!((parsed.eccentricity - edited.eccentricity).abs() < 1e-12);
+        assert!((parsed.mean_motion - edited.mean_motion).abs() < 1e-9);
+        assert_eq!(parsed.rev_number, 7);
+
+        // Out-of-range first derivatives saturate instead of widening the line
+        for (value, field, parsed_value) in [
+            (1.5, " .99999999", 0.99999999),
+            (-0.999999996, "-.99999999", -0.99999999),
+            (f64::NAN, " .00000000", 0.0),
+        ] {
+            edited.mean_motion_dot = value;
+            let lines = edited.to_lines();
+            assert_eq!(lines[1].len(), 69);
+            assert_eq!(&lines[1][33..43], field);
+            let parsed = TLE::parse_checked(&lines).unwrap();
+            assert_eq!(parsed.mean_motion_dot, parsed_value);
+        }
+    }
+
+    #[test]
+    fn test_deep_space_propagation() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let iss = TLE::parse(&lines).unwrap();
+        assert!(!iss.is_deep_space());
+
+        let mut geo = iss.clone();
+        geo.inclination = 0.05;
+        geo.eccentricity = 0.0002;
+        geo.bstar = 0.0;
+        geo.mean_motion = 1.00273;
+        let mut gps = geo.clone();
+        gps.inclination = 55.0;
+        gps.eccentricity = 0.005;
+        gps.mean_motion = 2.00564;
+        assert!(geo.is_deep_space() && gps.is_deep_space());
+
+        // Over a day the GEO stays on station and the GPS orbit keeps its size
+        let day = chrono::Duration::days(1);
+        let start = geo.propagate_sgp4(geo.epoch()).unwrap();
+        for hours in [6, 12, 18, 24] {
+            let dt = geo.epoch() + chrono::Duration::hours(hours);
+            let position = geo.propagate_sgp4(dt).unwrap();
+            assert!(
+                (position.altitude_km - 35786.0).abs() < 30.0,
+                "GEO altitude {}",
+                position.altitude_km
+            );
+            assert!(position.latitude.abs() < 0.2);
+            let gps_state = gps
+                .state_with(dt, PropagationAccuracy::Sgp4, Frame::Teme)
+                .unwrap();
+            let r = Coordinate3D::from(gps_state.position_km).magnitude();
+            assert!(
+                (r - gps.semi_major_axis_km()).abs() < 200.0,
+                "GPS radius {}",
+                r
+            );
+        }
+        let end = geo.propagate_sgp4(geo.epoch() + day).unwrap();
+        assert!(
+            (end.longitude - start.longitude).abs() < 0.5,
+            "GEO drifted to {}",
+            end.longitude
+        );
+    }
+
+    #[test]
+    fn test_alpha5_catalog_numbers() {
+        assert_eq!(decode_alpha5("B1234"), Some(111234));
+        assert_eq!(decode_alpha5("A0000"), Some(100000));
+        assert_eq!(decode_alpha5("J0001"), Some(180001));
+        assert_eq!(decode_alpha5("Z9999"), Some(339999));
+        assert_eq!(decode_alpha5("25544"), Some(25544));
+        assert_eq!(decode_alpha5("    5"), Some(5));
+        assert_eq!(decode_alpha5("I1234"), None);
+        assert_eq!(encode_alpha5(111234), "B1234");
+        assert_eq!(encode_alpha5(25544), "25544");
+
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let mut tle = TLE::parse(&lines).unwrap();
+        tle.norad_id = 111234;
+        let lines = tle.to_lines();
+        assert_eq!(&lines[1][2..7], "B1234");
+        assert_eq!(&lines[2][2..7], "B1234");
+        assert_eq!(TLE::parse_checked(&lines).unwrap().norad_id, 111234);
+    }
+
+    #[test]
+    fn test_parse_tle_report() {
+        let text = format!(
+            "{}\n\nBROKEN\n1 99999U 98067A   21275.5224390\n2 99999  51.6443\n{}\nORPHAN\n1 25544U stray line one",
+            ISS_TLE, ISS_TLE
+        );
+        let (tles, errors) = parse_tle_report(&text);
+        assert_eq!(tles.len(), 2);
+        assert_eq!(parse_tle(&text).len(), 2);
+
+        let lines: Vec<usize> = errors.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![5, 11, 12]);
+        assert_eq!(errors[0].1.field, "tle");
+        assert_eq!(errors[2].1.field, "line2");
+    }
+
+    #[test]
+    fn test_estimated_position_error() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let epoch = tle.epoch();
+        assert_eq!(tle.age_hours(epoch + chrono::Duration::hours(36)), 36.0);
+        assert!(tle.estimated_position_error_km(epoch) < 1e-9);
+
+        let errors: Vec<f64> = (1..=10)
+            .map(|d| tle.estimated_position_error_km(epoch + chrono::Duration::days(d)))
+            .collect();
+        assert!(errors.windows(2).all(|w| w[1] > w[0]));
+        assert!(
+            errors[0] > 1.0 && errors[0] < 3.0,
+            "ISS error after a day {}",
+            errors[0]
+        );
+
+        let mut draggy = tle.clone();
+        draggy.bstar = 5e-4;
+        let mut geo = tle.clone();
+        geo.mean_motion = 1.0027;
+        let week = epoch + chrono::Duration::days(7);
+        assert!(draggy.estimated_position_error_km(week) > tle.estimated_position_error_km(week));
+        assert!(geo.estimated_position_error_km(week) < tle.estimated_position_error_km(week));
+    }
+
+    #[test]
+    fn test_ground_track() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let start = tle.epoch();
+        let track = tle.ground_track(start, start + chrono::Duration::hours(6), 60);
+        assert_eq!(track.len(), 361);
+        assert_eq!(track[10].1, tle.propagate(track[10].0).unwrap());
+
+        // About four orbits cross the antimeridian several times
+        let segments = split_ground_track(&track);
+        assert!(segments.len() >= 4, "{} segments", segments.len());
+        assert_eq!(segments.iter().map(Vec::len).sum::<usize>(), track.len());
+        for segment in &segments {
+            assert!(segment
+                .windows(2)
+                .all(|w| (w[1].1.longitude - w[0].1.longitude).abs() <= 180.0));
+        }
+    }
+
+    #[test]
+    fn test_mean_to_true_anomaly() {
+        // Circular orbits and the apsides map to themselves
+        assert!((mean_to_true_anomaly(123.4, 0.0) - 123.4).abs() < 1e-9);
+        assert!(mean_to_true_anomaly(0.0, 0.74).abs() < 1e-9);
+        assert!((mean_to_true_anomaly(180.0, 0.74) - 180.0).abs() < 1e-9);
+
+        // Molniya: the satellite sweeps through perigee far ahead of the mean anomaly
+        let lines: Vec<String> = "MOLNIYA 2-14
+1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656"
+            .lines()
+            .map(|s| s.to_string())
+            .collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let orbit = tle.to_orbit().unwrap();
+        assert!(
+            orbit.true_anomaly_deg - tle.mean_anomaly > 60.0,
+            "{}",
+            orbit.true_anomaly_deg
+        );
+
+        // And it round-trips through Kepler's equation
+        let e = tle.eccentricity;
+        let nu = orbit.true_anomaly_deg.to_radians();
+        let ecc_anomaly = 2.0 * ((1.0 - e).sqrt() * (nu / 2.0).tan()).atan2((1.0 + e).sqrt());
+        let mean_anomaly = (ecc_anomaly - e * ecc_anomaly.sin()).to_degrees();
+        assert!((mean_anomaly - tle.mean_anomaly).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_diff() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let earlier = TLE::parse(&lines).unwrap();
+        let lines: Vec<String> = ISS_TLE_LATER.lines().map(|s| s.to_string()).collect();
+        let later = TLE::parse(&lines).unwrap();
+
+        let diff = earlier.diff(&later);
+        assert!(
+            (diff.epoch_gap_hours - 71.889).abs() < 1e-3,
+            "{}",
+            diff.epoch_gap_hours
+        );
+        // Nodal regression of about -5°/day
+        assert!((diff.raan_deg + 14.8256).abs() < 1e-9);
+        assert!((diff.inclination_deg + 0.0002).abs() < 1e-9);
+        assert!((diff.eccentricity + 0.0000053).abs() < 1e-12);
+        assert!((diff.mean_motion - 0.00011712).abs() < 1e-9);
+        // Faster mean motion means a slightly lower orbit
+        assert!(diff.semi_major_axis_km < 0.0 && diff.semi_major_axis_km > -0.1);
+
+        // Reversed comparison negates every delta
+        let back = later.diff(&earlier);
+        assert!((back.raan_deg - 14.8256).abs() < 1e-9);
+        assert!((back.epoch_gap_hours + diff.epoch_gap_hours).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_validate_physical() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        assert!(tle.validate_physical().is_ok());
+
+        let hyperbolic = TLE {
+            eccentricity: 1.5,
+            ..tle.clone()
+        };
+        assert_eq!(
+            hyperbolic.validate_physical().unwrap_err().field,
+            "eccentricity"
+        );
+
+        let retrograde_past_polar = TLE {
+            inclination: 181.0,
+            ..tle.clone()
+        };
+        assert_eq!(
+            retrograde_past_polar.validate_physical().unwrap_err().field,
+            "inclination"
+        );
+
+        // 0.9 eccentricity at ISS mean motion dips far below the surface
+        let reentered = TLE {
+            eccentricity: 0.9,
+            ..tle.clone()
+        };
+        assert_eq!(reentered.validate_physical().unwrap_err().field, "perigee");
+
+        // parse_checked applies the same checks to well-formed lines
+        let fast = TLE {
+            mean_motion: 25.0,
+            ..tle
+        };
+        let lines = fast.to_lines();
+        assert!(TLE::parse(&lines).is_ok());
+        assert_eq!(TLE::parse_checked(&lines).unwrap_err().field, "mean_motion");
+    }
+
+    #[test]
+    fn test_interpolate_position() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let earlier = TLE::parse(&lines).unwrap();
+        let lines: Vec<String> = ISS_TLE_LATER.lines().map(|s| s.to_string()).collect();
+        let later = TLE::parse(&lines).unwrap();
+
+        let midpoint = earlier.epoch() + (later.epoch() - earlier.epoch()) / 2;
+        let blended = TLE::interpolate_position(&earlier, &later, midpoint).unwrap();
+        let from_earlier = earlier.propagate(midpoint).unwrap();
+        let from_later = later.propagate(midpoint).unwrap();
+
+        // Halfway along the path between the two propagations
+        let apart = from_earlier.great_circle_distance(&from_later);
+        let to_earlier = blended.great_circle_distance(&from_earlier);
+        let to_later = blended.great_circle_distance(&from_later);
+        assert!(
+            to_earlier + to_later - apart < 0.01 * apart + 1.0,
+            "{} + {} vs {}",
+            to_earlier,
+            to_later,
+            apart
+        );
+        assert!((to_earlier - to_later).abs() < 0.05 * apart + 1.0);
+        let (low, high) = if from_earlier.altitude_km < from_later.altitude_km {
+            (from_earlier.altitude_km, from_later.altitude_km)
+        } else {
+            (from_later.altitude_km, from_earlier.altitude_km)
+        };
+        assert!(blended.altitude_km > low - 1.0 && blended.altitude_km < high + 1.0);
+
+        // Outside the bracket, the nearer element set alone; argument order doesn't matter
+        let before = earlier.epoch() - chrono::Duration::hours(1);
+        assert_eq!(
+            TLE::interpolate_position(&later, &earlier, before).unwrap(),
+            earlier.propagate(before).unwrap()
+        );
+        let after = later.epoch() + chrono::Duration::hours(1);
+        assert_eq!(
+            TLE::interpolate_position(&earlier, &later, after).unwrap(),
+            later.propagate(after).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let epoch = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let tle = TLE::builder()
+            .name("SIM-1")
+            .norad_id(90001)
+            .epoch(epoch)
+            .semi_major_axis_km(EARTH_RADIUS_KM + 550.0)
+            .inclination_deg(53.0)
+            .raan_deg(120.0)
+            .bstar(2.5e-4)
+            .build()
+            .unwrap();
+        assert_eq!(tle.epoch(), epoch);
+        assert!((tle.perigee_km() - 550.0).abs() < 1e-6);
+        assert!((tle.apogee_km() - 550.0).abs() < 1e-6);
+        // T = 2π·sqrt(a³/μ) ≈ 95.6 min at 550 km
+        let a = EARTH_RADIUS_KM + 550.0;
+        let expected_period = 2.0 * PI * (a.powi(3) / EARTH_MU).sqrt() / 60.0;
+        assert!((tle.orbital_period_minutes() - expected_period).abs() < 1e-9);
+        assert!((tle.orbital_period_minutes() - 95.6).abs() < 0.1);
+
+        // Round-trips through the two-line format
+        let parsed = TLE::parse_checked(&tle.to_lines()).unwrap();
+        assert_eq!(parsed.norad_id, 90001);
+        assert!((parsed.mean_motion - tle.mean_motion).abs() < 1e-8);
+
+        let missing_epoch = TLE::builder().mean_motion(15.0).build();
+        assert_eq!(missing_epoch.unwrap_err().field, "epoch");
+        let hyperbolic = TLE::builder()
+            .epoch(epoch)
+            .mean_motion(15.0)
+            .eccentricity(1.5)
+            .build();
+        assert_eq!(hyperbolic.unwrap_err().field, "eccentricity");
+        let bad_angle = TLE::builder()
+            .epoch(epoch)
+            .mean_motion(15.0)
+            .raan_deg(-10.0)
+            .build();
+        assert_eq!(bad_angle.unwrap_err().field, "raan");
+    }
 }
@@ -2,13 +2,53 @@
 //!
 //! Real-time satellite tracking and position calculations.
 
-use chrono::{DateTime, Duration, Utc};
-use rotastellar::{Position, ValidationError};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rotastellar::{Coordinate3D, Position, Satellite, ValidationError, EARTH_RADIUS_KM};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 
+use crate::clock::{Clock, SystemClock};
+use crate::link::LinkBudget;
+use crate::tle::{
+    split_ground_track, sun_direction_eci, Frame, PropagationAccuracy, PropagationError,
+    StateVector, TlePropagator,
+};
+use crate::window::QueryWindow;
 use crate::TLE;
 
+/// Time step used when searching for passes (seconds)
+const PASS_SEARCH_STEP_SECONDS: i64 = 30;
+/// How far ahead `next_pass` searches (days)
+const NEXT_PASS_SEARCH_DAYS: i64 = 7;
+/// Length of each window `next_pass` scans before giving up early (hours)
+const NEXT_PASS_CHUNK_HOURS: i64 = 6;
+/// Resolution to which AOS, LOS, and TCA are refined (milliseconds)
+const PASS_REFINE_TOLERANCE_MS: i64 = 1000;
+/// Time step used when searching for eclipse entry and exit (seconds)
+const ECLIPSE_SEARCH_STEP_SECONDS: i64 = 30;
+/// Minimum samples per parallel work item in `get_positions_parallel`
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK_SAMPLES: usize = 256;
+/// Time step when integrating the data rate across a pass (seconds)
+const DOWNLINK_STEP_SECONDS: i64 = 10;
+
+/// Speed of light in vacuum (km/s)
+const SPEED_OF_LIGHT_KM_S: f64 = 299792.458;
+
+/// Weight of max elevation in the pass quality score
+const PASS_QUALITY_ELEVATION_WEIGHT: f64 = 0.5;
+/// Weight of duration in the pass quality score
+const PASS_QUALITY_DURATION_WEIGHT: f64 = 0.3;
+/// Weight of minimum slant range in the pass quality score
+const PASS_QUALITY_RANGE_WEIGHT: f64 = 0.2;
+/// Pass duration that earns the full duration score (minutes)
+const PASS_QUALITY_FULL_DURATION_MIN: f64 = 10.0;
+/// Slant range that earns the full range score (km)
+const PASS_QUALITY_BEST_RANGE_KM: f64 = 500.0;
+/// Slant range that earns no range score (km)
+const PASS_QUALITY_WORST_RANGE_KM: f64 = 3000.0;
+
 /// Ground station for satellite pass calculations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroundStation {
@@ -18,6 +58,12 @@ pub struct GroundStation {
     pub position: Position,
     /// Minimum elevation angle for visibility (default: 10°)
     pub min_elevation_deg: f64,
+    /// Terrain horizon mask as (azimuth°, minimum elevation°) points
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub horizon_mask: Option<Vec<(f64, f64)>>,
+    /// Link bandwidth in Mbps, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bandwidth_mbps: Option<f64>,
 }
 
 impl GroundStation {
@@ -33,8 +79,216 @@ impl GroundStation {
             name: name.into(),
             position,
             min_elevation_deg: min_elevation_deg.unwrap_or(10.0),
+            horizon_mask: None,
+            bandwidth_mbps: None,
+        }
+    }
+
+    /// Set the link bandwidth in Mbps.
+    pub fn with_bandwidth(mut self, bandwidth_mbps: f64) -> Self {
+        self.bandwidth_mbps = Some(bandwidth_mbps);
+        self
+    }
+
+    /// Set an azimuth-dependent horizon mask.
+    ///
+    /// Each point is `(azimuth_deg, min_elevation_deg)`. The mask is linearly
+    /// interpolated between points and wraps around at 360°.
+    pub fn with_horizon_mask(mut self, mask: Vec<(f64, f64)>) -> Self {
+        let mut mask: Vec<(f64, f64)> = mask
+            .into_iter()
+            .map(|(az, el)| (az.rem_euclid(360.0), el))
+            .collect();
+        mask.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        self.horizon_mask = if mask.is_empty() { None } else { Some(mask) };
+        self
+    }
+
+    /// Minimum elevation required for visibility at a given azimuth.
+    ///
+    /// This is the larger of `min_elevation_deg` and the horizon mask (if any).
+    pub fn min_elevation_at(&self, azimuth_deg: f64) -> f64 {
+        let mask = match &self.horizon_mask {
+            Some(mask) => mask,
+            None => return self.min_elevation_deg,
+        };
+
+        let az = azimuth_deg.rem_euclid(360.0);
+        let upper = mask.iter().position(|&(a, _)| a >= az);
+        let ((az0, el0), (az1, el1)) = match upper {
+            Some(0) | None => {
+                // Wrap between the last and first mask points
+                let (last_az, last_el) = mask[mask.len() - 1];
+                let (first_az, first_el) = mask[0];
+                ((last_az, last_el), (first_az + 360.0, first_el))
+            }
+            Some(i) => (mask[i - 1], mask[i]),
+        };
+        let az = if az < az0 { az + 360.0 } else { az };
+
+        let mask_el = if (az1 - az0).abs() < 1e-9 {
+            el0
+        } else {
+            el0 + (el1 - el0) * (az - az0) / (az1 - az0)
+        };
+        mask_el.max(self.min_elevation_deg)
+    }
+
+    /// Compute the look angle from this station to a target position.
+    pub fn look_angle(&self, target: &Position) -> LookAngle {
+        let [east, north, up] = target.to_enu(&self.position);
+
+        let range_km = (east * east + north * north + up * up).sqrt();
+        let elevation_deg = if range_km > 0.0 {
+            (up / range_km).asin().to_degrees()
+        } else {
+            90.0
+        };
+
+        LookAngle {
+            azimuth_deg: east.atan2(north).to_degrees().rem_euclid(360.0),
+            elevation_deg,
+            range_km,
+        }
+    }
+
+    /// Check whether a look angle clears the horizon mask.
+    pub fn is_visible(&self, look: &LookAngle) -> bool {
+        look.elevation_deg >= self.min_elevation_at(look.azimuth_deg)
+    }
+
+    /// Check whether a target position is visible from this station.
+    pub fn visible_from(&self, target: &Position) -> bool {
+        self.is_visible(&self.look_angle(target))
+    }
+}
+
+/// Maximum azimuth slew rate along a look-angle trajectory (deg/s).
+///
+/// Azimuth differences are wrapped to ±180° so crossing north doesn't count as
+/// a full turn. Rates spike near zenith on high passes, which is where slow
+/// rotators fall behind.
+pub fn max_azimuth_rate_deg_s(trajectory: &[(DateTime<Utc>, LookAngle)]) -> f64 {
+    trajectory
+        .windows(2)
+        .filter_map(|w| {
+            let dt = (w[1].0 - w[0].0).num_milliseconds() as f64 / 1000.0;
+            if dt <= 0.0 {
+                return None;
+            }
+            let d_az = (w[1].1.azimuth_deg - w[0].1.azimuth_deg + 180.0).rem_euclid(360.0) - 180.0;
+            Some(d_az.abs() / dt)
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Where the segment from `from` to `to` crosses the antimeridian.
+///
+/// Returns the edge longitude on `from`'s side (±180) and the crossing
+/// latitude, interpolated linearly in longitude.
+fn antimeridian_crossing(from: &Position, to: &Position) -> (f64, f64) {
+    let edge = 180f64.copysign(from.longitude);
+    let to_longitude = to.longitude + 360f64.copysign(from.longitude);
+    let fraction = (edge - from.longitude) / (to_longitude - from.longitude);
+    (
+        edge,
+        from.latitude + fraction * (to.latitude - from.latitude),
+    )
+}
+
+/// Great-circle radius (km) of the region seeing a satellite at `altitude_km`
+/// at least `min_elevation_deg` above the horizon.
+fn footprint_radius_km(altitude_km: f64, min_elevation_deg: f64) -> Result<f64, ValidationError> {
+    if !(0.0..90.0).contains(&min_elevation_deg) {
+        return Err(ValidationError::new(
+            "min_elevation_deg",
+            "Must be between 0 (inclusive) and 90 (exclusive) degrees",
+        ));
+    }
+    // Earth central angle between the sub-satellite point and the edge
+    let elevation = min_elevation_deg.to_radians();
+    let central_angle =
+        (EARTH_RADIUS_KM * elevation.cos() / (EARTH_RADIUS_KM + altitude_km)).acos() - elevation;
+    Ok(EARTH_RADIUS_KM * central_angle)
+}
+
+/// Bisect a horizon crossing between a visible and an invisible sample.
+///
+/// `rising` means `before` is invisible and `after` visible, with
+/// `visible_look` seen at `after`; otherwise the reverse. Returns the visible
+/// side of the crossing and its look angle. Failed propagations count as
+/// invisible.
+fn refine_crossing(
+    look_at: &impl Fn(DateTime<Utc>) -> Option<LookAngle>,
+    station: &GroundStation,
+    mut before: DateTime<Utc>,
+    mut after: DateTime<Utc>,
+    mut visible_look: LookAngle,
+    rising: bool,
+) -> (DateTime<Utc>, LookAngle) {
+    while (after - before).num_milliseconds() > PASS_REFINE_TOLERANCE_MS {
+        let mid = before + (after - before) / 2;
+        match look_at(mid).filter(|look| station.is_visible(look)) {
+            Some(look) => {
+                visible_look = look;
+                if rising {
+                    after = mid
+                } else {
+                    before = mid
+                }
+            }
+            None => {
+                if rising {
+                    before = mid
+                } else {
+                    after = mid
+                }
+            }
         }
     }
+    (if rising { after } else { before }, visible_look)
+}
+
+/// Refine a pass's TCA and maximum elevation within a search step of the
+/// best sample, by golden-section search on elevation.
+fn refine_tca(
+    look_at: &impl Fn(DateTime<Utc>) -> Option<LookAngle>,
+    pass: &mut SatellitePass,
+    step: Duration,
+) {
+    let elevation = |t| look_at(t).map_or(f64::NEG_INFINITY, |look| look.elevation_deg);
+    let inv_phi = (5f64.sqrt() - 1.0) / 2.0;
+
+    let mut lo = (pass.tca - step).max(pass.aos);
+    let mut hi = (pass.tca + step).min(pass.los);
+    while (hi - lo).num_milliseconds() > PASS_REFINE_TOLERANCE_MS {
+        let span_ms = (hi - lo).num_milliseconds() as f64;
+        let left = hi - Duration::milliseconds((span_ms * inv_phi) as i64);
+        let right = lo + Duration::milliseconds((span_ms * inv_phi) as i64);
+        if elevation(left) < elevation(right) {
+            lo = left;
+        } else {
+            hi = right;
+        }
+    }
+
+    let tca = lo + (hi - lo) / 2;
+    if let Some(look) = look_at(tca).filter(|look| look.elevation_deg > pass.max_elevation_deg) {
+        pass.tca = tca;
+        pass.max_elevation_deg = look.elevation_deg;
+        pass.min_range_km = pass.min_range_km.map(|r| r.min(look.range_km));
+    }
+}
+
+/// Topocentric look angle from a ground station to a satellite.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LookAngle {
+    /// Azimuth in degrees clockwise from north (0-360)
+    pub azimuth_deg: f64,
+    /// Elevation above the horizon in degrees (-90 to 90)
+    pub elevation_deg: f64,
+    /// Slant range in km
+    pub range_km: f64,
 }
 
 /// A satellite pass over a ground station.
@@ -56,9 +310,35 @@ pub struct SatellitePass {
     pub aos_azimuth_deg: f64,
     /// Azimuth at LOS
     pub los_azimuth_deg: f64,
+    /// Minimum slant range during the pass in km
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_range_km: Option<f64>,
 }
 
 impl SatellitePass {
+    /// Figure of merit for the pass, from 0 (useless) to 100 (ideal).
+    ///
+    /// Combines max elevation, duration, and (when known) minimum slant range.
+    /// Without a slant range the remaining terms are re-weighted to fill 0-100.
+    pub fn quality_score(&self) -> f64 {
+        let elevation = (self.max_elevation_deg / 90.0).clamp(0.0, 1.0);
+        let duration = (self.duration_minutes() / PASS_QUALITY_FULL_DURATION_MIN).clamp(0.0, 1.0);
+
+        let mut score =
+            PASS_QUALITY_ELEVATION_WEIGHT * elevation + PASS_QUALITY_DURATION_WEIGHT * duration;
+        let mut total_weight = PASS_QUALITY_ELEVATION_WEIGHT + PASS_QUALITY_DURATION_WEIGHT;
+
+        if let Some(range_km) = self.min_range_km {
+            let range = ((PASS_QUALITY_WORST_RANGE_KM - range_km)
+                / (PASS_QUALITY_WORST_RANGE_KM - PASS_QUALITY_BEST_RANGE_KM))
+                .clamp(0.0, 1.0);
+            score += PASS_QUALITY_RANGE_WEIGHT * range;
+            total_weight += PASS_QUALITY_RANGE_WEIGHT;
+        }
+
+        (score / total_weight * 1000.0).round() / 10.0
+    }
+
     /// Duration of the pass in seconds.
     pub fn duration_seconds(&self) -> f64 {
         (self.los - self.aos).num_milliseconds() as f64 / 1000.0
@@ -85,6 +365,57 @@ pub struct TrackedSatelliteInfo {
     pub constellation: Option<String>,
 }
 
+/// Memoized positions keyed by satellite and rounded timestamp, least recently
+/// used first.
+#[derive(Debug)]
+struct PositionCache {
+    capacity: usize,
+    granularity_ms: i64,
+    entries: VecDeque<((String, i64), Position)>,
+    hits: u64,
+    misses: u64,
+}
+
+impl PositionCache {
+    fn new(capacity: usize, granularity: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            granularity_ms: granularity.num_milliseconds().max(1),
+            entries: VecDeque::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn key(&self, satellite_id: &str, time: DateTime<Utc>) -> (String, i64) {
+        (
+            satellite_id.to_string(),
+            time.timestamp_millis().div_euclid(self.granularity_ms),
+        )
+    }
+
+    fn get(&mut self, key: &(String, i64)) -> Option<Position> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+        let entry = self.entries.remove(index)?;
+        let position = entry.1;
+        self.entries.push_back(entry);
+        self.hits += 1;
+        Some(position)
+    }
+
+    fn insert(&mut self, key: (String, i64), position: Position) {
+        self.misses += 1;
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, position));
+    }
+
+    fn invalidate(&mut self, satellite_id: &str) {
+        self.entries.retain(|((id, _), _)| id != satellite_id);
+    }
+}
+
 /// Real-time satellite tracker.
 ///
 /// Track satellites, calculate positions, and predict passes over ground stations.
@@ -112,8 +443,14 @@ pub struct TrackedSatelliteInfo {
 pub struct Tracker {
     /// Satellite info cache
     satellite_cache: HashMap<String, TrackedSatelliteInfo>,
-    /// TLE cache
-    tle_cache: HashMap<String, TLE>,
+    /// TLE cache, each with its propagator state
+    tle_cache: HashMap<String, TlePropagator<'static>>,
+    /// Time source for default query times
+    clock: Arc<dyn Clock>,
+    /// Propagation model for positions and passes
+    accuracy: PropagationAccuracy,
+    /// Optional memoization of [`get_position`](Self::get_position)
+    position_cache: Option<Mutex<PositionCache>>,
 }
 
 impl Default for Tracker {
@@ -128,9 +465,80 @@ impl Tracker {
         Self {
             satellite_cache: HashMap::new(),
             tle_cache: HashMap::new(),
+            clock: Arc::new(SystemClock),
+            accuracy: PropagationAccuracy::default(),
+            position_cache: None,
         }
     }
 
+    /// Use a custom time source instead of the system clock.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Get the tracker's time source.
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    /// Set the propagation model used for positions and passes.
+    ///
+    /// Defaults to [`PropagationAccuracy::Sgp4`], the model TLEs are fitted
+    /// with. [`PropagationAccuracy::J2`] is cheaper per step and keeps ISS
+    /// pass times over three days within about a minute of it, though it
+    /// ignores drag; [`PropagationAccuracy::TwoBody`] walks off further.
+    pub fn with_accuracy(mut self, accuracy: PropagationAccuracy) -> Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    /// Get the propagation model.
+    pub fn accuracy(&self) -> PropagationAccuracy {
+        self.accuracy
+    }
+
+    /// Memoize [`get_position`](Self::get_position) results.
+    ///
+    /// Queries for the same satellite whose times fall in the same
+    /// `granularity` bucket return the cached position instead of propagating
+    /// again, so positions may be up to `granularity` stale. At most `capacity`
+    /// positions are kept, evicting the least recently used. Entries for a
+    /// satellite are dropped when its TLE is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of cached positions
+    /// * `granularity` - Width of the time buckets
+    pub fn with_position_cache(mut self, capacity: usize, granularity: Duration) -> Self {
+        self.position_cache = Some(Mutex::new(PositionCache::new(capacity, granularity)));
+        self
+    }
+
+    /// Drop all cached positions. Hit and miss counts are kept.
+    pub fn clear_cache(&self) {
+        self.with_cache(|cache| cache.entries.clear());
+    }
+
+    /// Number of [`get_position`](Self::get_position) calls served from the cache.
+    pub fn cache_hits(&self) -> u64 {
+        self.with_cache(|cache| cache.hits).unwrap_or(0)
+    }
+
+    /// Number of [`get_position`](Self::get_position) calls that had to propagate
+    /// while caching was enabled.
+    pub fn cache_misses(&self) -> u64 {
+        self.with_cache(|cache| cache.misses).unwrap_or(0)
+    }
+
+    fn with_cache<T>(&self, f: impl FnOnce(&mut PositionCache) -> T) -> Option<T> {
+        let cache = self.position_cache.as_ref()?;
+        let mut guard = cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Some(f(&mut guard))
+    }
+
     /// Add a TLE to the cache for tracking.
     ///
     /// # Arguments
@@ -149,7 +557,102 @@ impl Tracker {
                 constellation: None,
             },
         );
-        self.tle_cache.insert(id, tle);
+        self.with_cache(|cache| cache.invalidate(&id));
+        self.tle_cache.insert(id, TlePropagator::owned(tle));
+    }
+
+    /// Replace the TLE of a tracked satellite, keeping its metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `tle` - New TLE data
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not tracked.
+    pub fn update_tle(&mut self, satellite_id: &str, tle: TLE) -> Result<(), ValidationError> {
+        let slot = self.tle_cache.get_mut(satellite_id).ok_or_else(|| {
+            ValidationError::new(
+                "satellite_id",
+                format!("Satellite not found: {}", satellite_id),
+            )
+        })?;
+        *slot = TlePropagator::owned(tle);
+        self.with_cache(|cache| cache.invalidate(satellite_id));
+        Ok(())
+    }
+
+    /// Add a satellite with its metadata for tracking.
+    ///
+    /// Unlike [`add_tle`](Self::add_tle), operator and constellation are kept
+    /// from `satellite`. Its `orbit` and `position` are not stored; they are
+    /// derived from the TLE on each [`get_satellite`](Self::get_satellite).
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite` - Satellite metadata, keyed by `satellite.id`
+    /// * `tle` - TLE data
+    pub fn add_satellite(&mut self, satellite: Satellite, tle: TLE) {
+        self.satellite_cache.insert(
+            satellite.id.clone(),
+            TrackedSatelliteInfo {
+                id: satellite.id.clone(),
+                norad_id: satellite.norad_id,
+                name: satellite.name,
+                operator: satellite.operator,
+                constellation: satellite.constellation,
+            },
+        );
+        self.with_cache(|cache| cache.invalidate(&satellite.id));
+        self.tle_cache
+            .insert(satellite.id, TlePropagator::owned(tle));
+    }
+
+    /// Stop tracking a satellite, dropping its metadata and cached positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    ///
+    /// # Returns
+    ///
+    /// The satellite's TLE, or None if it was not tracked.
+    pub fn remove_tle(&mut self, satellite_id: &str) -> Option<TLE> {
+        self.satellite_cache.remove(satellite_id);
+        self.with_cache(|cache| cache.invalidate(satellite_id));
+        self.tle_cache
+            .remove(satellite_id)
+            .map(TlePropagator::into_tle)
+    }
+
+    /// Stop tracking all satellites, dropping their cached positions.
+    pub fn clear(&mut self) {
+        self.satellite_cache.clear();
+        self.tle_cache.clear();
+        self.clear_cache();
+    }
+
+    /// Get a tracked satellite with its current orbit and position.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    ///
+    /// # Returns
+    ///
+    /// Satellite with `orbit` and `position` propagated to the tracker's
+    /// current time, or None if not tracked.
+    pub fn get_satellite(&self, satellite_id: &str) -> Option<Satellite> {
+        let info = self.satellite_cache.get(satellite_id)?;
+        let tle = self.tle_cache.get(satellite_id)?;
+
+        let mut satellite = Satellite::new(info.id.clone(), info.norad_id, info.name.clone());
+        satellite.operator = info.operator.clone();
+        satellite.constellation = info.constellation.clone();
+        satellite.orbit = tle.tle().to_orbit().ok();
+        satellite.position = tle.position(self.clock.now(), self.accuracy).ok();
+        Some(satellite)
     }
 
     /// Get the TLE for a satellite.
@@ -162,7 +665,7 @@ impl Tracker {
     ///
     /// TLE if found in cache.
     pub fn get_tle(&self, satellite_id: &str) -> Option<&TLE> {
-        self.tle_cache.get(satellite_id)
+        self.tle_cache.get(satellite_id).map(TlePropagator::tle)
     }
 
     /// Get satellite position at a specific time.
@@ -188,124 +691,1863 @@ impl Tracker {
             ValidationError::new("satellite_id", format!("Satellite not found: {}", satellite_id))
         })?;
 
-        let time = at_time.unwrap_or_else(Utc::now);
-        tle.propagate(time)
+        let time = at_time.unwrap_or_else(|| self.clock.now());
+        let key = match self.with_cache(|cache| {
+            let key = cache.key(satellite_id, time);
+            (cache.get(&key), key)
+        }) {
+            Some((Some(position), _)) => return Ok(position),
+            Some((None, key)) => Some(key),
+            None => None,
+        };
+
+        let position = tle.position(time, self.accuracy)?;
+        if let Some(key) = key {
+            self.with_cache(|cache| cache.insert(key, position));
+        }
+        Ok(position)
     }
 
-    /// Get satellite positions over a time range.
+    /// Get a satellite's position and velocity in the chosen frame.
+    ///
+    /// Inertial and Earth-fixed states come straight from the propagator,
+    /// keeping the velocity that a geographic position drops.
     ///
     /// # Arguments
     ///
     /// * `satellite_id` - Satellite identifier
-    /// * `start` - Start time
-    /// * `end` - End time
-    /// * `step_seconds` - Time step between positions (default: 60)
-    ///
-    /// # Returns
-    ///
-    /// Vector of (time, position) tuples.
-    pub fn get_positions(
+    /// * `at_time` - Target time (default: now)
+    /// * `frame` - Output frame
+    pub fn get_state(
         &self,
         satellite_id: &str,
-        start: DateTime<Utc>,
-        end: DateTime<Utc>,
-        step_seconds: Option<i64>,
-    ) -> Result<Vec<(DateTime<Utc>, Position)>, ValidationError> {
+        at_time: Option<DateTime<Utc>>,
+        frame: Frame,
+    ) -> Result<StateVector, ValidationError> {
         let tle = self.tle_cache.get(satellite_id).ok_or_else(|| {
-            ValidationError::new("satellite_id", format!("Satellite not found: {}", satellite_id))
+            ValidationError::new(
+                "satellite_id",
+                format!("Satellite not found: {}", satellite_id),
+            )
         })?;
 
-        let step = Duration::seconds(step_seconds.unwrap_or(60));
-        let mut positions = Vec::new();
-        let mut current = start;
-
-        while current <= end {
-            if let Ok(pos) = tle.propagate(current) {
-                positions.push((current, pos));
-            }
-            current = current + step;
-        }
-
-        Ok(positions)
+        tle.state(
+            at_time.unwrap_or_else(|| self.clock.now()),
+            self.accuracy,
+            frame,
+        )
     }
 
-    /// Predict satellite passes over a ground station.
+    /// Get the look angle from a ground station to a satellite, for antenna
+    /// pointing.
     ///
-    /// Note: This is a placeholder. Real implementation would use
-    /// SGP4 propagation for accurate pass predictions.
+    /// Negative elevation means the satellite is below the horizon.
     ///
     /// # Arguments
     ///
     /// * `satellite_id` - Satellite identifier
-    /// * `ground_station` - Ground station
-    /// * `hours` - Time window in hours (default: 24)
+    /// * `station` - Ground station
+    /// * `at_time` - Target time (default: now)
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Vector of predicted passes.
-    pub fn predict_passes(
+    /// Returns an error if the satellite is not found or propagation fails.
+    pub fn look_angles(
         &self,
-        _satellite_id: &str,
-        _ground_station: &GroundStation,
-        _hours: Option<f64>,
-    ) -> Vec<SatellitePass> {
-        // Placeholder - would need SGP4 propagation for real implementation
-        Vec::new()
+        satellite_id: &str,
+        station: &GroundStation,
+        at_time: Option<DateTime<Utc>>,
+    ) -> Result<LookAngle, ValidationError> {
+        let position = self.get_position(satellite_id, at_time)?;
+        Ok(station.look_angle(&position))
     }
 
-    /// List all tracked satellites.
-    pub fn list_satellites(&self) -> Vec<&TrackedSatelliteInfo> {
-        self.satellite_cache.values().collect()
+    /// Rate of change of the slant range from a ground station, in km/s.
+    ///
+    /// Negative while the satellite approaches, positive while it recedes.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `station` - Ground station
+    /// * `at_time` - Target time (default: now)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found or propagation fails.
+    pub fn range_rate_km_s(
+        &self,
+        satellite_id: &str,
+        station: &GroundStation,
+        at_time: Option<DateTime<Utc>>,
+    ) -> Result<f64, ValidationError> {
+        let state = self.get_state(satellite_id, at_time, Frame::Ecef)?;
+        let line_of_sight = Coordinate3D::from(state.position_km) - station.position.to_ecef();
+        let range_km = line_of_sight.magnitude();
+        if range_km == 0.0 {
+            return Ok(0.0);
+        }
+        // The station is fixed in ECEF, so the satellite's ECEF velocity is the relative velocity
+        Ok(line_of_sight.dot(&state.velocity_km_s.into()) / range_km)
     }
 
-    /// Get satellite info.
-    pub fn get_satellite_info(&self, satellite_id: &str) -> Option<&TrackedSatelliteInfo> {
-        self.satellite_cache.get(satellite_id)
+    /// Doppler shift of a downlink as received at a ground station, in Hz.
+    ///
+    /// Positive while the satellite approaches, negative while it recedes,
+    /// and zero at closest approach.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `station` - Ground station
+    /// * `downlink_freq_hz` - Transmitted frequency in Hz
+    /// * `at_time` - Target time (default: now)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found or propagation fails.
+    pub fn doppler_shift_hz(
+        &self,
+        satellite_id: &str,
+        station: &GroundStation,
+        downlink_freq_hz: f64,
+        at_time: Option<DateTime<Utc>>,
+    ) -> Result<f64, ValidationError> {
+        let range_rate = self.range_rate_km_s(satellite_id, station, at_time)?;
+        Ok(-downlink_freq_hz * range_rate / SPEED_OF_LIGHT_KM_S)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Check whether a satellite is in sunlight.
+    ///
+    /// Uses a cylindrical shadow model: the satellite is eclipsed when it is
+    /// on the night side and within one Earth radius of the Earth-Sun line.
+    /// Penumbra is not modeled.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `at_time` - Target time (default: now)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found or propagation fails.
+    pub fn is_sunlit(
+        &self,
+        satellite_id: &str,
+        at_time: Option<DateTime<Utc>>,
+    ) -> Result<bool, ValidationError> {
+        let time = at_time.unwrap_or_else(|| self.clock.now());
+        let state = self.get_state(satellite_id, Some(time), Frame::Eci)?;
+        let r = Coordinate3D::from(state.position_km);
+        let sun = Coordinate3D::from(sun_direction_eci(time));
 
-    const ISS_TLE: &str = r#"ISS (ZARYA)
-1 25544U 98067A   21275.52243902  .00001082  00000-0  27450-4 0  9999
-2 25544  51.6443 208.5943 0003631 355.3422 144.3824 15.48919755304818"#;
+        let along_sun = r.dot(&sun);
+        if along_sun >= 0.0 {
+            return Ok(true);
+        }
+        Ok((r - sun * along_sun).magnitude() > EARTH_RADIUS_KM)
+    }
 
-    #[test]
-    fn test_tracker_add_tle() {
-        let mut tracker = Tracker::new();
-        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
-        let tle = TLE::parse(&lines).unwrap();
+    /// Find the intervals a satellite spends in Earth's shadow.
+    ///
+    /// Entry and exit are located to within a second. Intervals already in
+    /// progress at `start`, or still running at `end`, are clipped to the
+    /// window.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `start` - Start time
+    /// * `end` - End time
+    ///
+    /// # Returns
+    ///
+    /// Eclipse windows from entry to exit, in chronological order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found or propagation fails.
+    pub fn eclipse_intervals(
+        &self,
+        satellite_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<QueryWindow>, ValidationError> {
+        let step = Duration::seconds(ECLIPSE_SEARCH_STEP_SECONDS);
+        let eclipsed = |t| self.is_sunlit(satellite_id, Some(t)).map(|sunlit| !sunlit);
+        // First eclipsed instant between a sunlit and an eclipsed sample, or
+        // the reverse
+        let edge = |mut before: DateTime<Utc>,
+                    mut after: DateTime<Utc>|
+         -> Result<DateTime<Utc>, ValidationError> {
+            let entering = eclipsed(after)?;
+            while (after - before).num_milliseconds() > PASS_REFINE_TOLERANCE_MS {
+                let mid = before + (after - before) / 2;
+                if eclipsed(mid)? == entering {
+                    after = mid;
+                } else {
+                    before = mid;
+                }
+            }
+            Ok(after)
+        };
 
-        tracker.add_tle("ISS", tle);
+        let mut intervals = Vec::new();
+        let mut entry = if eclipsed(start)? { Some(start) } else { None };
+        let mut previous = start;
+        let mut current = start + step;
+        while previous < end {
+            let sample = current.min(end);
+            match (entry, eclipsed(sample)?) {
+                (None, true) => entry = Some(edge(previous, sample)?),
+                (Some(entered), false) => {
+                    intervals.push(QueryWindow::between(entered, edge(previous, sample)?));
+                    entry = None;
+                }
+                _ => {}
+            }
+            previous = sample;
+            current += step;
+        }
+        if let Some(entered) = entry {
+            intervals.push(QueryWindow::between(entered, end));
+        }
+        Ok(intervals)
+    }
+
+    /// Ground radius of a satellite's footprint, in km.
+    ///
+    /// The footprint is the area from which the satellite appears at least
+    /// `min_elevation_deg` above the horizon, on a spherical Earth. The radius
+    /// is the great-circle distance from the sub-satellite point to its edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `at_time` - Target time (default: now)
+    /// * `min_elevation_deg` - Minimum elevation at the footprint edge (0-90)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found, propagation fails, or
+    /// the elevation is out of range.
+    pub fn footprint_radius_km(
+        &self,
+        satellite_id: &str,
+        at_time: Option<DateTime<Utc>>,
+        min_elevation_deg: f64,
+    ) -> Result<f64, ValidationError> {
+        let position = self.get_position(satellite_id, at_time)?;
+        footprint_radius_km(position.altitude_km, min_elevation_deg)
+    }
+
+    /// Polygon approximating the edge of a satellite's footprint.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `at_time` - Target time (default: now)
+    /// * `min_elevation_deg` - Minimum elevation at the footprint edge (0-90)
+    /// * `points` - Number of vertices (at least 3)
+    ///
+    /// # Returns
+    ///
+    /// Vertices clockwise from due north of the sub-satellite point, at
+    /// ground level. The ring is not closed; repeat the first vertex if the
+    /// consumer requires it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found, propagation fails, the
+    /// elevation is out of range, or `points` is below 3.
+    pub fn visibility_circle(
+        &self,
+        satellite_id: &str,
+        at_time: Option<DateTime<Utc>>,
+        min_elevation_deg: f64,
+        points: usize,
+    ) -> Result<Vec<Position>, ValidationError> {
+        if points < 3 {
+            return Err(ValidationError::new("points", "Must be at least 3"));
+        }
+        let position = self.get_position(satellite_id, at_time)?;
+        let radius_km = footprint_radius_km(position.altitude_km, min_elevation_deg)?;
+        let center = Position {
+            altitude_km: 0.0,
+            ..position
+        };
+
+        (0..points)
+            .map(|i| center.destination(360.0 * i as f64 / points as f64, radius_km))
+            .collect()
+    }
+
+    /// Get a satellite's inertial (ECI) position vector in km.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `at_time` - Target time (default: now)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found or propagation fails.
+    pub fn get_eci(
+        &self,
+        satellite_id: &str,
+        at_time: Option<DateTime<Utc>>,
+    ) -> Result<[f64; 3], ValidationError> {
+        Ok(self
+            .get_state(satellite_id, at_time, Frame::Eci)?
+            .position_km)
+    }
+
+    /// Get a satellite's Earth-fixed (ECEF) position vector in km.
+    ///
+    /// The inertial position is rotated by Greenwich Mean Sidereal Time at
+    /// `at_time`. The geodetic position from [`get_position`](Self::get_position)
+    /// is the same point; [`StateVector::to_position`] converts between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `at_time` - Target time (default: now)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found or propagation fails.
+    pub fn get_ecef(
+        &self,
+        satellite_id: &str,
+        at_time: Option<DateTime<Utc>>,
+    ) -> Result<[f64; 3], ValidationError> {
+        Ok(self
+            .get_state(satellite_id, at_time, Frame::Ecef)?
+            .position_km)
+    }
+
+    /// Get the positions of all tracked satellites at a specific time.
+    ///
+    /// Failures are reported per satellite with the satellite, element set
+    /// epoch, and requested time attached, so one bad object does not stop a
+    /// batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `at_time` - Target time (default: now)
+    ///
+    /// # Returns
+    ///
+    /// `(satellite_id, result)` pairs ordered by satellite ID.
+    pub fn get_all_positions(
+        &self,
+        at_time: Option<DateTime<Utc>>,
+    ) -> Vec<(String, Result<Position, PropagationError>)> {
+        let time = at_time.unwrap_or_else(|| self.clock.now());
+        let mut ids: Vec<&String> = self.tle_cache.keys().collect();
+        ids.sort();
+
+        ids.into_iter()
+            .map(|id| {
+                let result = self
+                    .get_position(id, Some(time))
+                    .map_err(|source| PropagationError {
+                        satellite_id: id.clone(),
+                        epoch: self.tle_cache[id].tle().epoch(),
+                        requested_time: time,
+                        source,
+                    });
+                (id.clone(), result)
+            })
+            .collect()
+    }
+
+    /// Get satellite positions over a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `start` - Start time
+    /// * `end` - End time
+    /// * `step_seconds` - Time step between positions (default: 60)
+    ///
+    /// # Returns
+    ///
+    /// Vector of (time, position) tuples.
+    pub fn get_positions(
+        &self,
+        satellite_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step_seconds: Option<i64>,
+    ) -> Result<Vec<(DateTime<Utc>, Position)>, ValidationError> {
+        if !self.tle_cache.contains_key(satellite_id) {
+            return Err(ValidationError::new(
+                "satellite_id",
+                format!("Satellite not found: {}", satellite_id),
+            ));
+        }
+
+        Ok(self
+            .positions_iter(satellite_id, start, end, step_seconds)
+            .filter_map(Result::ok)
+            .collect())
+    }
+
+    /// Get satellite positions over a time range, propagating in parallel.
+    ///
+    /// The timeline is split into chunks propagated concurrently on the rayon
+    /// thread pool. The result is identical to
+    /// [`get_positions`](Self::get_positions); this pays off for long windows
+    /// at fine steps. Requires the `parallel` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `start` - Start time
+    /// * `end` - End time
+    /// * `step_seconds` - Time step between positions (default: 60)
+    ///
+    /// # Returns
+    ///
+    /// Vector of (time, position) tuples.
+    #[cfg(feature = "parallel")]
+    pub fn get_positions_parallel(
+        &self,
+        satellite_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step_seconds: Option<i64>,
+    ) -> Result<Vec<(DateTime<Utc>, Position)>, ValidationError> {
+        let tle = self.tle_cache.get(satellite_id).ok_or_else(|| {
+            ValidationError::new(
+                "satellite_id",
+                format!("Satellite not found: {}", satellite_id),
+            )
+        })?;
+        let step = Duration::seconds(step_seconds.unwrap_or(60).max(1));
+        let count = if end < start {
+            0
+        } else {
+            ((end - start).num_milliseconds() / step.num_milliseconds() + 1) as usize
+        };
+        let accuracy = self.accuracy;
+        let sample = |i: usize| {
+            let t = start + step * i as i32;
+            tle.position(t, accuracy).ok().map(|pos| (t, pos))
+        };
+
+        use rayon::prelude::*;
+        Ok((0..count)
+            .into_par_iter()
+            .with_min_len(PARALLEL_CHUNK_SAMPLES)
+            .filter_map(sample)
+            .collect())
+    }
+
+    /// Export a satellite's ground track as a GeoJSON `FeatureCollection`.
+    ///
+    /// The track is split at antimeridian crossings into one `LineString`
+    /// feature per segment, each ending on ±180° at the interpolated crossing
+    /// latitude so maps draw it without a line across the whole world. Each
+    /// feature's properties hold `satellite_id` and the RFC 3339 `start` and
+    /// `end` times of its samples. Positions come from
+    /// [`get_positions`](Self::get_positions), so they use the tracker's
+    /// propagation accuracy.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `start` - Start time
+    /// * `end` - End time
+    /// * `step_seconds` - Time step between samples (default: 60)
+    ///
+    /// # Returns
+    ///
+    /// The GeoJSON text. A `LineString` needs at least two positions, so a
+    /// segment left with fewer (a window holding a single sample) is dropped,
+    /// and the collection may be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not found.
+    pub fn ground_track_geojson(
+        &self,
+        satellite_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step_seconds: Option<i64>,
+    ) -> Result<String, ValidationError> {
+        let segments =
+            split_ground_track(&self.get_positions(satellite_id, start, end, step_seconds)?);
+
+        let features: Vec<serde_json::Value> = segments
+            .iter()
+            .enumerate()
+            .filter_map(|(i, segment)| {
+                let mut coordinates: Vec<[f64; 2]> = segment
+                    .iter()
+                    .map(|(_, p)| [p.longitude, p.latitude])
+                    .collect();
+                if let Some(previous) = i.checked_sub(1).and_then(|j| segments[j].last()) {
+                    let (edge, latitude) = antimeridian_crossing(&previous.1, &segment[0].1);
+                    coordinates.insert(0, [-edge, latitude]);
+                }
+                if let Some(next) = segments.get(i + 1) {
+                    let (edge, latitude) =
+                        antimeridian_crossing(&segment[segment.len() - 1].1, &next[0].1);
+                    coordinates.push([edge, latitude]);
+                }
+                if coordinates.len() < 2 {
+                    return None;
+                }
+                Some(serde_json::json!({
+                    "type": "Feature",
+                    "geometry": { "type": "LineString", "coordinates": coordinates },
+                    "properties": {
+                        "satellite_id": satellite_id,
+                        "start": segment[0].0.to_rfc3339(),
+                        "end": segment[segment.len() - 1].0.to_rfc3339(),
+                    },
+                }))
+            })
+            .collect();
+
+        Ok(serde_json::json!({ "type": "FeatureCollection", "features": features }).to_string())
+    }
+
+    /// Lazily iterate satellite positions over a time range.
+    ///
+    /// Each position is propagated only when the iterator is advanced, so
+    /// memory use is independent of the window length.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `start` - Start time
+    /// * `end` - End time
+    /// * `step_seconds` - Time step between positions (default: 60, at least 1)
+    ///
+    /// # Returns
+    ///
+    /// Iterator of (time, position) results. Yields a single error if the
+    /// satellite is not tracked.
+    pub fn positions_iter<'a>(
+        &'a self,
+        satellite_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step_seconds: Option<i64>,
+    ) -> impl Iterator<Item = Result<(DateTime<Utc>, Position), ValidationError>> + 'a {
+        let tle = self.tle_cache.get(satellite_id);
+        let missing = tle.is_none().then(|| {
+            ValidationError::new(
+                "satellite_id",
+                format!("Satellite not found: {}", satellite_id),
+            )
+        });
+        let step = Duration::seconds(step_seconds.unwrap_or(60).max(1));
+        let accuracy = self.accuracy;
+
+        let positions = tle.into_iter().flat_map(move |tle| {
+            std::iter::successors(Some(start), move |t| Some(*t + step))
+                .take_while(move |t| *t <= end)
+                .map(move |t| tle.position(t, accuracy).map(|pos| (t, pos)))
+        });
+
+        missing.map(Err).into_iter().chain(positions)
+    }
+
+    /// Predict satellite passes over a ground station.
+    ///
+    /// Steps the propagator across the window starting at the tracker's
+    /// current time and reports each interval where the satellite clears the
+    /// station's horizon mask. AOS, LOS, and TCA are then refined between
+    /// samples to within a second.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `ground_station` - Ground station
+    /// * `hours` - Time window in hours from now (default: 24), clamped to
+    ///   [`MAX_WINDOW_HOURS`](crate::MAX_WINDOW_HOURS)
+    ///
+    /// # Returns
+    ///
+    /// Vector of predicted passes, best [`quality_score`](SatellitePass::quality_score) first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the satellite is not tracked or `hours` is
+    /// negative or not finite.
+    pub fn predict_passes(
+        &self,
+        satellite_id: &str,
+        ground_station: &GroundStation,
+        hours: Option<f64>,
+    ) -> Result<Vec<SatellitePass>, ValidationError> {
+        let tle = self.tle_cache.get(satellite_id).ok_or_else(|| {
+            ValidationError::new(
+                "satellite_id",
+                format!("Satellite not found: {}", satellite_id),
+            )
+        })?;
+        let hours = hours.unwrap_or(24.0);
+        if !hours.is_finite() || hours < 0.0 {
+            return Err(ValidationError::new(
+                "hours",
+                format!("Window must be a non-negative number of hours: {}", hours),
+            ));
+        }
+
+        let window = QueryWindow::next_hours_with(hours, self.clock.as_ref());
+        let mut passes =
+            self.find_passes(tle, satellite_id, ground_station, window.start, window.end);
+
+        // Best passes first
+        passes.sort_by(|a, b| {
+            b.quality_score()
+                .partial_cmp(&a.quality_score())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        Ok(passes)
+    }
+
+    /// Find the first pass that rises after a given time.
+    ///
+    /// Scans forward in windows of a few hours, so a pass soon after `after`
+    /// is found without predicting a whole day. AOS, LOS, and TCA are refined
+    /// as in [`predict_passes`](Self::predict_passes). A pass already in
+    /// progress at `after` is skipped, however many windows it spans, so
+    /// passing the previous pass's `los` steps through passes in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `station` - Ground station
+    /// * `after` - Time to search from
+    ///
+    /// # Returns
+    ///
+    /// The next pass within a week, or None if there is none or the satellite
+    /// is not tracked. A satellite that stays up from `after` through the
+    /// week, such as a geostationary one above the station, has none.
+    pub fn next_pass(
+        &self,
+        satellite_id: &str,
+        station: &GroundStation,
+        after: DateTime<Utc>,
+    ) -> Option<SatellitePass> {
+        let tle = self.tle_cache.get(satellite_id)?;
+        let horizon = after + Duration::days(NEXT_PASS_SEARCH_DAYS);
+
+        let mut chunk_start = after;
+        while chunk_start < horizon {
+            let chunk_end = (chunk_start + Duration::hours(NEXT_PASS_CHUNK_HOURS)).min(horizon);
+            // A pass up at the window start keeps the start as its AOS. Each
+            // window also samples the previous one's end, so such a pass was
+            // already up there: in progress at `after` or carried over from
+            // the previous window, not a new rise.
+            let found = self
+                .find_passes(tle, satellite_id, station, chunk_start, chunk_end)
+                .into_iter()
+                .find(|pass| pass.aos > chunk_start);
+            if let Some(pass) = found {
+                if pass.los < chunk_end || chunk_end == horizon {
+                    return Some(pass);
+                }
+                // Still up at the end of the window: rescan from just before
+                // AOS so LOS is located too
+                let before_aos = pass.aos - Duration::milliseconds(PASS_REFINE_TOLERANCE_MS);
+                return self
+                    .find_passes(tle, satellite_id, station, before_aos, horizon)
+                    .into_iter()
+                    .next();
+            }
+            chunk_start = chunk_end;
+        }
+        None
+    }
+
+    /// Passes between two times, in chronological order.
+    fn find_passes(
+        &self,
+        tle: &TlePropagator,
+        satellite_id: &str,
+        ground_station: &GroundStation,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<SatellitePass> {
+        let step = Duration::seconds(PASS_SEARCH_STEP_SECONDS);
+        let look_at = |t: DateTime<Utc>| {
+            tle.position(t, self.accuracy)
+                .ok()
+                .map(|pos| ground_station.look_angle(&pos))
+        };
+
+        let mut passes = Vec::new();
+        let mut current_pass: Option<SatellitePass> = None;
+        let mut previous: Option<DateTime<Utc>> = None;
+        // Look angle at `los` of the open pass
+        let mut last_look = LookAngle {
+            azimuth_deg: 0.0,
+            elevation_deg: 0.0,
+            range_km: 0.0,
+        };
+        let mut current = start;
+
+        while current <= end {
+            match look_at(current).filter(|look| ground_station.is_visible(look)) {
+                Some(look) => {
+                    let pass = current_pass.get_or_insert_with(|| {
+                        // Rose since the previous sample; a pass already up at
+                        // the window start keeps the start as its AOS
+                        let (aos, aos_look) = match previous {
+                            Some(before) => refine_crossing(
+                                &look_at,
+                                ground_station,
+                                before,
+                                current,
+                                look,
+                                true,
+                            ),
+                            None => (current, look),
+                        };
+                        SatellitePass {
+                            satellite_id: satellite_id.to_string(),
+                            ground_station: ground_station.name.clone(),
+                            aos,
+                            los: current,
+                            tca: current,
+                            max_elevation_deg: look.elevation_deg,
+                            aos_azimuth_deg: aos_look.azimuth_deg,
+                            los_azimuth_deg: look.azimuth_deg,
+                            min_range_km: Some(aos_look.range_km.min(look.range_km)),
+                        }
+                    });
+                    pass.los = current;
+                    pass.los_azimuth_deg = look.azimuth_deg;
+                    last_look = look;
+                    pass.min_range_km = pass.min_range_km.map(|r| r.min(look.range_km));
+                    if look.elevation_deg > pass.max_elevation_deg {
+                        pass.max_elevation_deg = look.elevation_deg;
+                        pass.tca = current;
+                    }
+                }
+                None => {
+                    if let Some(mut pass) = current_pass.take() {
+                        let (los, los_look) = refine_crossing(
+                            &look_at,
+                            ground_station,
+                            pass.los,
+                            current,
+                            last_look,
+                            false,
+                        );
+                        pass.los = los;
+                        pass.los_azimuth_deg = los_look.azimuth_deg;
+                        refine_tca(&look_at, &mut pass, step);
+                        passes.push(pass);
+                    }
+                }
+            }
+
+            previous = Some(current);
+            current += step;
+        }
+
+        // Still up at the window end; LOS stays at the last sample
+        if let Some(mut pass) = current_pass {
+            refine_tca(&look_at, &mut pass, step);
+            passes.push(pass);
+        }
+        passes
+    }
+
+    /// Data volume a station can downlink from a satellite over one UTC day.
+    ///
+    /// Integrates the link budget's data rate across every pass that day,
+    /// capped at the station's bandwidth when known.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `station` - Ground station
+    /// * `date` - UTC day to plan
+    /// * `link_budget` - Downlink budget
+    ///
+    /// # Returns
+    ///
+    /// Capacity in gigabytes (10⁹ bytes); zero if the satellite is not tracked.
+    pub fn daily_downlink_capacity_gb(
+        &self,
+        satellite_id: &str,
+        station: &GroundStation,
+        date: NaiveDate,
+        link_budget: &LinkBudget,
+    ) -> f64 {
+        let tle = match self.tle_cache.get(satellite_id) {
+            Some(tle) => tle,
+            None => return 0.0,
+        };
+        let start = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let end = start + Duration::days(1);
+        let max_rate = station.bandwidth_mbps.unwrap_or(f64::INFINITY);
+
+        let megabits: f64 = self
+            .find_passes(tle, satellite_id, station, start, end)
+            .iter()
+            .map(|pass| {
+                let rates: Vec<(DateTime<Utc>, f64)> = self
+                    .pass_trajectory(satellite_id, station, pass, Some(DOWNLINK_STEP_SECONDS))
+                    .into_iter()
+                    .map(|(t, look)| {
+                        let rate = if station.is_visible(&look) {
+                            link_budget.data_rate_at(&look).min(max_rate)
+                        } else {
+                            0.0
+                        };
+                        (t, rate)
+                    })
+                    .collect();
+                rates
+                    .windows(2)
+                    .map(|w| {
+                        (w[1].0 - w[0].0).num_milliseconds() as f64 / 1000.0 * (w[0].1 + w[1].1)
+                            / 2.0
+                    })
+                    .sum::<f64>()
+            })
+            .sum();
+
+        megabits / 8.0 / 1000.0
+    }
+
+    /// Compute the look-angle trajectory across a pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `satellite_id` - Satellite identifier
+    /// * `station` - Ground station
+    /// * `pass` - Pass to sample, from AOS to LOS
+    /// * `step_seconds` - Time step between samples (default: 1)
+    ///
+    /// # Returns
+    ///
+    /// Vector of (time, look angle) tuples; empty if the satellite is not tracked.
+    pub fn pass_trajectory(
+        &self,
+        satellite_id: &str,
+        station: &GroundStation,
+        pass: &SatellitePass,
+        step_seconds: Option<i64>,
+    ) -> Vec<(DateTime<Utc>, LookAngle)> {
+        self.positions_iter(
+            satellite_id,
+            pass.aos,
+            pass.los,
+            Some(step_seconds.unwrap_or(1)),
+        )
+        .filter_map(Result::ok)
+        .map(|(t, pos)| (t, station.look_angle(&pos)))
+        .collect()
+    }
+
+    /// List all tracked satellites.
+    pub fn list_satellites(&self) -> Vec<&TrackedSatelliteInfo> {
+        self.satellite_cache.values().collect()
+    }
+
+    /// Get satellite info.
+    pub fn get_satellite_info(&self, satellite_id: &str) -> Option<&TrackedSatelliteInfo> {
+        self.satellite_cache.get(satellite_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISS_TLE: &str = r#"ISS (ZARYA)
+1 25544U 98067A   21275.52243902  .00001082  00000-0  27450-4 0  9999
+2 25544  51.6443 208.5943 0003631 355.3422 144.3824 15.48919755304818"#;
+
+    #[test]
+    fn test_tracker_add_tle() {
+        let mut tracker = Tracker::new();
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+
+        tracker.add_tle("ISS", tle);
 
         assert!(tracker.get_tle("ISS").is_some());
         assert!(tracker.get_satellite_info("ISS").is_some());
     }
 
     #[test]
-    fn test_tracker_get_position() {
+    fn test_tracker_get_position() {
+        let mut tracker = Tracker::new();
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let epoch = tle.epoch();
+
+        tracker.add_tle("ISS", tle);
+
+        let pos = tracker.get_position("ISS", Some(epoch)).unwrap();
+        assert!(pos.latitude.abs() <= 90.0);
+        assert!(pos.longitude.abs() <= 180.0);
+    }
+
+    #[test]
+    fn test_ground_station() {
+        let station = GroundStation::new(
+            "Test Station",
+            Position::new(40.7128, -74.0060, 0.0).unwrap(),
+            Some(5.0),
+        );
+        assert_eq!(station.name, "Test Station");
+        assert_eq!(station.min_elevation_deg, 5.0);
+    }
+
+    #[test]
+    fn test_horizon_mask_interpolation() {
+        let station = GroundStation::new("Test", Position::new(0.0, 0.0, 0.0).unwrap(), Some(5.0))
+            .with_horizon_mask(vec![(350.0, 20.0), (10.0, 40.0), (180.0, 0.0)]);
+
+        assert!((station.min_elevation_at(0.0) - 30.0).abs() < 1e-9);
+        assert!((station.min_elevation_at(10.0) - 40.0).abs() < 1e-9);
+        // Mask below the station minimum falls back to min_elevation_deg
+        assert!((station.min_elevation_at(180.0) - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_horizon_mask_shortens_pass() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let overhead = tle.propagate(tle.epoch() + Duration::minutes(10)).unwrap();
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(tle.epoch()));
+        tracker.add_tle("ISS", tle);
+
+        let site = Position::new(overhead.latitude, overhead.longitude, 0.0).unwrap();
+        let flat = GroundStation::new("Site", site, Some(0.0));
+        let flat_passes = tracker.predict_passes("ISS", &flat, Some(1.0)).unwrap();
+        let pass = flat_passes
+            .iter()
+            .find(|p| p.max_elevation_deg > 80.0)
+            .expect("overhead pass");
+
+        // Wall off the half of the sky the satellite rises from
+        let az = pass.aos_azimuth_deg;
+        let masked = flat.clone().with_horizon_mask(vec![
+            (az - 50.0, 0.0),
+            (az - 45.0, 80.0),
+            (az + 45.0, 80.0),
+            (az + 50.0, 0.0),
+        ]);
+        let masked_passes = tracker.predict_passes("ISS", &masked, Some(1.0)).unwrap();
+
+        let visible_secs = |passes: &[SatellitePass]| -> i64 {
+            passes
+                .iter()
+                .filter(|p| p.aos <= pass.los && p.los >= pass.aos)
+                .map(|p| (p.los - p.aos).num_seconds())
+                .sum()
+        };
+        assert!(visible_secs(&masked_passes) < visible_secs(&flat_passes));
+        assert!(masked_passes
+            .iter()
+            .all(|p| p.aos > pass.aos || p.los < pass.aos));
+    }
+
+    #[test]
+    fn test_positions_iter_matches_eager() {
+        let mut tracker = Tracker::new();
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let start = tle.epoch();
+        tracker.add_tle("ISS", tle);
+
+        let end = start + Duration::minutes(30);
+        let eager = tracker.get_positions("ISS", start, end, Some(60)).unwrap();
+        let lazy: Vec<_> = tracker
+            .positions_iter("ISS", start, end, Some(60))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(eager.len(), 31);
+        assert_eq!(eager.len(), lazy.len());
+        for ((t1, p1), (t2, p2)) in eager.iter().zip(lazy.iter()) {
+            assert_eq!(t1, t2);
+            assert_eq!(p1.latitude, p2.latitude);
+            assert_eq!(p1.longitude, p2.longitude);
+        }
+
+        // A century of one-second samples is only computed as far as consumed
+        let far = start + Duration::days(36_500);
+        let mut iter = tracker.positions_iter("ISS", start, far, Some(1));
+        let (t0, _) = iter.next().unwrap().unwrap();
+        assert_eq!(t0, start);
+        assert_eq!(iter.take(5).count(), 5);
+
+        // Zero and negative steps fall back to one second instead of never ending
+        let minute = start + Duration::minutes(1);
+        for step in [0, -30] {
+            let positions = tracker
+                .get_positions("ISS", start, minute, Some(step))
+                .unwrap();
+            assert_eq!(positions.len(), 61);
+        }
+
+        let mut missing = tracker.positions_iter("NOPE", start, end, None);
+        assert!(missing.next().unwrap().is_err());
+        assert!(missing.next().is_none());
+    }
+
+    #[test]
+    fn test_pass_quality_score() {
+        let aos = Utc::now();
+        let pass = |max_el: f64, minutes: i64, range: Option<f64>| SatellitePass {
+            satellite_id: "SAT".to_string(),
+            ground_station: "GS".to_string(),
+            aos,
+            los: aos + Duration::minutes(minutes),
+            tca: aos + Duration::minutes(minutes / 2),
+            max_elevation_deg: max_el,
+            aos_azimuth_deg: 0.0,
+            los_azimuth_deg: 180.0,
+            min_range_km: range,
+        };
+
+        let overhead = pass(90.0, 10, Some(550.0));
+        let grazing = pass(12.0, 2, Some(2000.0));
+        assert!(overhead.quality_score() > grazing.quality_score());
+        assert!(overhead.quality_score() <= 100.0);
+        assert!(pass(90.0, 10, None).quality_score() > pass(12.0, 2, None).quality_score());
+        assert_eq!(pass(90.0, 10, None).quality_score(), 100.0);
+    }
+
+    #[test]
+    fn test_get_position_uses_clock() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let at = tle.epoch() + Duration::minutes(42);
+
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(at));
+        tracker.add_tle("ISS", tle);
+
+        let implicit = tracker.get_position("ISS", None).unwrap();
+        let explicit = tracker.get_position("ISS", Some(at)).unwrap();
+        assert_eq!(implicit.latitude, explicit.latitude);
+        assert_eq!(implicit.longitude, explicit.longitude);
+    }
+
+    #[test]
+    fn test_default_accuracy_is_sgp4() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let day_later = tle.epoch() + Duration::days(1);
+        let expected = tle.propagate_sgp4(day_later).unwrap();
+
+        let mut tracker = Tracker::new();
+        assert_eq!(tracker.accuracy(), PropagationAccuracy::Sgp4);
+        tracker.add_tle("ISS", tle);
+        let position = tracker.get_position("ISS", Some(day_later)).unwrap();
+        assert_eq!(position.latitude, expected.latitude);
+        assert_eq!(position.longitude, expected.longitude);
+        let batch = tracker
+            .get_positions("ISS", day_later, day_later, None)
+            .unwrap();
+        assert_eq!(batch[0].1.longitude, expected.longitude);
+    }
+
+    #[test]
+    fn test_get_all_positions_error_context() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let iss = TLE::parse(&lines).unwrap();
+        let mut decayed = iss.clone();
+        decayed.name = "DECAYED".to_string();
+        // ~150 km circular with heavy drag
+        decayed.mean_motion = 16.3;
+        decayed.bstar = 1e-3;
+
         let mut tracker = Tracker::new();
+        tracker.add_tle("ISS", iss.clone());
+        tracker.add_tle("DECAYED", decayed.clone());
+
+        let reentry = decayed.estimated_reentry().unwrap();
+        let requested = reentry + Duration::days(365);
+        let results = tracker.get_all_positions(Some(requested));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "DECAYED");
+        assert!(results[1].1.is_ok());
+
+        let error = results[0].1.as_ref().unwrap_err();
+        assert_eq!(error.satellite_id, "DECAYED");
+        assert_eq!(error.epoch, decayed.epoch());
+        assert_eq!(error.requested_time, requested);
+        let message = error.to_string();
+        assert!(message.contains("DECAYED") && message.contains(&requested.to_string()));
+    }
+
+    #[test]
+    fn test_position_cache() {
         let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
         let tle = TLE::parse(&lines).unwrap();
         let epoch = tle.epoch();
+        let mut tracker = Tracker::new().with_position_cache(16, Duration::seconds(1));
+        tracker.add_tle("ISS", tle.clone());
+
+        let first = tracker.get_position("ISS", Some(epoch)).unwrap();
+        let second = tracker
+            .get_position("ISS", Some(epoch + Duration::milliseconds(200)))
+            .unwrap();
+        assert_eq!(first, second);
+        assert_eq!(tracker.cache_misses(), 1);
+        assert_eq!(tracker.cache_hits(), 1);
+
+        // A new bucket propagates again
+        let later = tracker
+            .get_position("ISS", Some(epoch + Duration::seconds(5)))
+            .unwrap();
+        assert_ne!(first, later);
+        assert_eq!(tracker.cache_misses(), 2);
+
+        // Replacing the TLE or clearing the cache forces propagation
+        tracker.update_tle("ISS", tle).unwrap();
+        tracker.get_position("ISS", Some(epoch)).unwrap();
+        assert_eq!(tracker.cache_misses(), 3);
+        tracker.clear_cache();
+        tracker.get_position("ISS", Some(epoch)).unwrap();
+        assert_eq!((tracker.cache_hits(), tracker.cache_misses()), (1, 4));
 
+        assert!(tracker
+            .update_tle("unknown", tracker.get_tle("ISS").unwrap().clone())
+            .is_err());
+        assert_eq!(Tracker::new().cache_hits(), 0);
+    }
+
+    #[test]
+    fn test_get_state_matches_position() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let at = tle.epoch() + Duration::minutes(37);
+        let mut tracker = Tracker::new();
         tracker.add_tle("ISS", tle);
 
-        let pos = tracker.get_position("ISS", Some(epoch)).unwrap();
-        assert!(pos.latitude.abs() <= 90.0);
-        assert!(pos.longitude.abs() <= 180.0);
+        let position = tracker.get_position("ISS", Some(at)).unwrap();
+        let ecef = tracker.get_state("ISS", Some(at), Frame::Ecef).unwrap();
+        let geodetic = ecef.to_position().unwrap();
+        assert!((geodetic.latitude - position.latitude).abs() < 1e-9);
+        assert!((geodetic.longitude - position.longitude).abs() < 1e-9);
+        assert!((geodetic.altitude_km - position.altitude_km).abs() < 1e-6);
+
+        // Earth-fixed speed is lower than inertial by roughly the ground rotation
+        let teme = tracker.get_state("ISS", Some(at), Frame::Teme).unwrap();
+        let speed = |v: [f64; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        assert!((speed(teme.velocity_km_s) - 7.66).abs() < 0.05);
+        assert!(speed(ecef.velocity_km_s) < speed(teme.velocity_km_s));
+
+        // Round trip through the Earth-fixed frame
+        let back = ecef.to_frame(Frame::Teme);
+        for i in 0..3 {
+            assert!((back.position_km[i] - teme.position_km[i]).abs() < 1e-6);
+            assert!((back.velocity_km_s[i] - teme.velocity_km_s[i]).abs() < 1e-9);
+        }
+        assert!(tracker.get_state("MISSING", Some(at), Frame::Eci).is_err());
     }
 
     #[test]
-    fn test_ground_station() {
+    fn test_daily_downlink_capacity() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let date = (tle.epoch() + Duration::days(1)).date_naive();
+        let mut tracker = Tracker::new();
+        tracker.add_tle("ISS", tle);
+        let budget = LinkBudget::new(10.0, 30.0, 8.2);
+
+        // Mid-latitudes see many ISS passes; near its latitude limit passes are few and low
+        let good = GroundStation::new("Good", Position::new(48.0, 11.0, 0.0).unwrap(), Some(5.0));
+        let poor = GroundStation::new("Poor", Position::new(66.0, 11.0, 0.0).unwrap(), Some(5.0));
+        let good_gb = tracker.daily_downlink_capacity_gb("ISS", &good, date, &budget);
+        let poor_gb = tracker.daily_downlink_capacity_gb("ISS", &poor, date, &budget);
+        assert!(good_gb > poor_gb, "{} vs {}", good_gb, poor_gb);
+        assert!(good_gb > 10.0 && good_gb < 500.0, "{}", good_gb);
+
+        // Station bandwidth caps the rate
+        let limited = good.clone().with_bandwidth(10.0);
+        assert!(
+            tracker.daily_downlink_capacity_gb("ISS", &limited, date, &budget) < good_gb / 10.0
+        );
+        assert_eq!(
+            tracker.daily_downlink_capacity_gb("MISSING", &good, date, &budget),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_add_satellite_preserves_metadata() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let at = tle.epoch() + Duration::minutes(15);
+
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(at));
+        let satellite = Satellite::new("ISS", 25544, "ISS (ZARYA)")
+            .with_operator("NASA/Roscosmos")
+            .with_constellation("ISS");
+        tracker.add_satellite(satellite, tle);
+
+        let satellite = tracker.get_satellite("ISS").unwrap();
+        assert_eq!(satellite.operator.as_deref(), Some("NASA/Roscosmos"));
+        assert_eq!(satellite.constellation.as_deref(), Some("ISS"));
+        assert!(satellite.orbit.is_some());
+
+        let position = satellite.position.unwrap();
+        let expected = tracker.get_position("ISS", None).unwrap();
+        assert_eq!(position.latitude, expected.latitude);
+        assert_eq!(position.longitude, expected.longitude);
+        assert!(tracker.get_satellite("unknown").is_none());
+    }
+
+    #[test]
+    fn test_pass_trajectory_azimuth_rate() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let overhead = tle.propagate(tle.epoch() + Duration::minutes(10)).unwrap();
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(tle.epoch()));
+        tracker.add_tle("ISS", tle);
+
+        // Offset slightly so the pass doesn't go exactly through zenith
+        let site = Position::new(overhead.latitude + 0.2, overhead.longitude, 0.0).unwrap();
+        let station = GroundStation::new("Site", site, Some(0.0));
+        let passes = tracker.predict_passes("ISS", &station, Some(1.0)).unwrap();
+        let pass = passes.iter().find(|p| p.max_elevation_deg > 80.0).unwrap();
+
+        let trajectory = tracker.pass_trajectory("ISS", &station, pass, Some(1));
+        assert_eq!(trajectory.first().unwrap().0, pass.aos);
+        assert!(trajectory.len() as f64 >= pass.duration_seconds());
+
+        let near_aos: Vec<_> = trajectory.iter().take(60).cloned().collect();
+        let near_tca: Vec<_> = trajectory
+            .iter()
+            .filter(|(t, _)| (*t - pass.tca).num_seconds().abs() <= 30)
+            .cloned()
+            .collect();
+        let aos_rate = max_azimuth_rate_deg_s(&near_aos);
+        let tca_rate = max_azimuth_rate_deg_s(&near_tca);
+        assert!(tca_rate > aos_rate, "tca {} vs aos {}", tca_rate, aos_rate);
+        assert!(max_azimuth_rate_deg_s(&trajectory) >= tca_rate);
+    }
+
+    /// AOS times from a numerically integrated two-body + J2 orbit (RK4),
+    /// started from the TLE's epoch state.
+    fn j2_truth_aos(
+        tle: &TLE,
+        station: &GroundStation,
+        start_hours: f64,
+        end_hours: f64,
+    ) -> Vec<DateTime<Utc>> {
+        use crate::tle::{ecef_to_geodetic, eci_to_ecef, gmst_rad};
+        use rotastellar::constants::EARTH_J2;
+        use rotastellar::EARTH_MU;
+
+        let accel = |r: [f64; 3]| {
+            let r2 = r[0] * r[0] + r[1] * r[1] + r[2] * r[2];
+            let r1 = r2.sqrt();
+            let k = 1.5 * EARTH_J2 * EARTH_MU * EARTH_RADIUS_KM * EARTH_RADIUS_KM / r2.powi(2) / r1;
+            let z2 = 5.0 * r[2] * r[2] / r2;
+            let f = -EARTH_MU / (r2 * r1);
+            [
+                f * r[0] + k * r[0] * (z2 - 1.0),
+                f * r[1] + k * r[1] * (z2 - 1.0),
+                f * r[2] + k * r[2] * (z2 - 3.0),
+            ]
+        };
+        let add =
+            |a: [f64; 3], b: [f64; 3], s: f64| [a[0] + b[0] * s, a[1] + b[1] * s, a[2] + b[2] * s];
+
+        let epoch = tle.epoch();
+        let accuracy = PropagationAccuracy::TwoBody;
+        let two_body = |t| tle.eci_position_km(t, accuracy).unwrap();
+        let mut r = two_body(epoch);
+        let before = two_body(epoch - Duration::seconds(1));
+        let after = two_body(epoch + Duration::seconds(1));
+        let mut v = [
+            (after[0] - before[0]) / 2.0,
+            (after[1] - before[1]) / 2.0,
+            (after[2] - before[2]) / 2.0,
+        ];
+
+        let h = 10.0;
+        let mut aos = Vec::new();
+        let mut was_visible = false;
+        for i in 0..(end_hours * 360.0) as i64 {
+            let t = epoch + Duration::seconds(i * 10);
+            if i % 3 == 0 && i as f64 >= start_hours * 360.0 {
+                let (lat, lon, alt) = ecef_to_geodetic(eci_to_ecef(r, gmst_rad(t)));
+                let visible = station.visible_from(&Position::new(lat, lon, alt).unwrap());
+                if visible && !was_visible {
+                    aos.push(t);
+                }
+                was_visible = visible;
+            }
+            let (k1r, k1v) = (v, accel(r));
+            let (k2r, k2v) = (add(v, k1v, h / 2.0), accel(add(r, k1r, h / 2.0)));
+            let (k3r, k3v) = (add(v, k2v, h / 2.0), accel(add(r, k2r, h / 2.0)));
+            let (k4r, k4v) = (add(v, k3v, h), accel(add(r, k3r, h)));
+            for j in 0..3 {
+                r[j] += h / 6.0 * (k1r[j] + 2.0 * k2r[j] + 2.0 * k3r[j] + k4r[j]);
+                v[j] += h / 6.0 * (k1v[j] + 2.0 * k2v[j] + 2.0 * k3v[j] + k4v[j]);
+            }
+        }
+        aos
+    }
+
+    #[test]
+    fn test_j2_pass_times_track_truth() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let mut tle = TLE::parse(&lines).unwrap();
+        // ~700 km sun-synchronous, starting at 45° argument of latitude where
+        // the osculating and mean semi-major axes coincide
+        tle.inclination = 98.19;
+        tle.eccentricity = 0.001;
+        tle.arg_perigee = 45.0;
+        tle.mean_anomaly = 0.0;
+        tle.mean_motion = 14.56;
+
         let station = GroundStation::new(
-            "Test Station",
-            Position::new(40.7128, -74.0060, 0.0).unwrap(),
-            Some(5.0),
+            "Boulder",
+            Position::new(40.0, -105.0, 1.6).unwrap(),
+            Some(10.0),
         );
-        assert_eq!(station.name, "Test Station");
-        assert_eq!(station.min_elevation_deg, 5.0);
+        let truth = j2_truth_aos(&tle, &station, 48.0, 72.0);
+        assert!(!truth.is_empty());
+
+        let max_aos_error = |accuracy: PropagationAccuracy| {
+            let mut tracker = Tracker::new()
+                .with_accuracy(accuracy)
+                .with_clock(crate::clock::FixedClock::new(tle.epoch()));
+            tracker.add_tle("SSO", tle.clone());
+            let passes = tracker.predict_passes("SSO", &station, Some(72.0)).unwrap();
+            truth
+                .iter()
+                .map(|t| {
+                    passes
+                        .iter()
+                        .map(|p| (p.aos - *t).num_seconds().abs())
+                        .min()
+                        .unwrap_or(i64::MAX)
+                })
+                .max()
+                .unwrap()
+        };
+
+        let two_body_error = max_aos_error(PropagationAccuracy::TwoBody);
+        let j2_error = max_aos_error(PropagationAccuracy::J2);
+        assert!(j2_error < two_body_error);
+        assert!(j2_error <= 60, "J2 AOS error {} s", j2_error);
+    }
+
+    #[test]
+    fn test_j2_pass_times_track_sgp4() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let station = GroundStation::new(
+            "Boulder",
+            Position::new(40.0, -105.0, 1.6).unwrap(),
+            Some(10.0),
+        );
+        let passes = |accuracy: PropagationAccuracy| {
+            let mut tracker = Tracker::new()
+                .with_accuracy(accuracy)
+                .with_clock(crate::clock::FixedClock::new(tle.epoch()));
+            tracker.add_tle("ISS", tle.clone());
+            let mut passes = tracker.predict_passes("ISS", &station, Some(72.0)).unwrap();
+            passes.sort_by_key(|pass| pass.aos);
+            passes
+        };
+
+        let sgp4 = passes(PropagationAccuracy::Sgp4);
+        let j2 = passes(PropagationAccuracy::J2);
+        assert!(sgp4.len() >= 10);
+        assert_eq!(j2.len(), sgp4.len());
+        for (a, b) in j2.iter().zip(&sgp4) {
+            let error = (a.aos - b.aos).num_seconds().abs();
+            assert!(error <= 60, "J2 AOS {} s from SGP4", error);
+        }
+    }
+
+    #[test]
+    fn test_predict_passes_refined() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(tle.epoch()));
+        tracker.add_tle("ISS", tle.clone());
+
+        let station = GroundStation::new(
+            "Boulder",
+            Position::new(40.0, -105.0, 1.6).unwrap(),
+            Some(10.0),
+        );
+        let passes = tracker.predict_passes("ISS", &station, Some(24.0)).unwrap();
+        assert!(passes.len() >= 3, "{} passes", passes.len());
+        for hours in [-1.0, f64::NAN, f64::INFINITY] {
+            let rejected = tracker.predict_passes("ISS", &station, Some(hours));
+            assert!(rejected.is_err());
+        }
+        assert!(tracker.predict_passes("HST", &station, None).is_err());
+
+        let position_at = |t| tle.propagate_with(t, tracker.accuracy()).unwrap();
+        let look_at = |t| station.look_angle(&position_at(t));
+        for pass in &passes {
+            assert!(pass.aos <= pass.tca && pass.tca <= pass.los);
+            assert!(pass.max_elevation_deg >= 10.0 && pass.max_elevation_deg <= 90.0);
+
+            // Crossings sit on the mask to within the refinement tolerance
+            assert!((look_at(pass.aos).elevation_deg - 10.0).abs() < 0.1);
+            assert!((look_at(pass.los).elevation_deg - 10.0).abs() < 0.1);
+            assert!(!station.visible_from(&position_at(pass.aos - Duration::seconds(2))));
+            assert!(!station.visible_from(&position_at(pass.los + Duration::seconds(2))));
+            assert!((look_at(pass.aos).azimuth_deg - pass.aos_azimuth_deg).abs() < 1e-9);
+            assert!((look_at(pass.los).azimuth_deg - pass.los_azimuth_deg).abs() < 1e-9);
+
+            // TCA is the peak, not just the best 30 s sample
+            let peak = look_at(pass.tca).elevation_deg;
+            assert!((peak - pass.max_elevation_deg).abs() < 1e-9);
+            assert!(look_at(pass.tca - Duration::seconds(5)).elevation_deg < peak);
+            assert!(look_at(pass.tca + Duration::seconds(5)).elevation_deg < peak);
+        }
+    }
+
+    #[test]
+    fn test_predict_passes_starts_at_clock() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let now = tle.epoch() + Duration::days(2);
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(now));
+        tracker.add_tle("ISS", tle);
+
+        let station = GroundStation::new(
+            "Boulder",
+            Position::new(40.0, -105.0, 1.6).unwrap(),
+            Some(10.0),
+        );
+        let passes = tracker.predict_passes("ISS", &station, Some(24.0)).unwrap();
+        assert!(!passes.is_empty());
+        for pass in &passes {
+            assert!(pass.aos >= now && pass.los <= now + Duration::hours(24));
+        }
+    }
+
+    #[test]
+    fn test_look_angles() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(tle.epoch()));
+        tracker.add_tle("ISS", tle);
+
+        let station = GroundStation::new(
+            "Boulder",
+            Position::new(40.0, -105.0, 1.6).unwrap(),
+            Some(10.0),
+        );
+        let passes = tracker.predict_passes("ISS", &station, Some(24.0)).unwrap();
+        let pass = passes.first().expect("pass");
+
+        let at_tca = tracker
+            .look_angles("ISS", &station, Some(pass.tca))
+            .unwrap();
+        assert!((at_tca.elevation_deg - pass.max_elevation_deg).abs() < 1e-9);
+        assert!(at_tca.elevation_deg <= 90.0);
+        assert!(at_tca.range_km > 400.0 && at_tca.range_km < 3000.0);
+        for look in tracker
+            .pass_trajectory("ISS", &station, pass, Some(10))
+            .iter()
+            .map(|(_, look)| look)
+        {
+            assert!((0.0..360.0).contains(&look.azimuth_deg));
+            assert!((-90.0..=90.0).contains(&look.elevation_deg));
+        }
+
+        // Well after LOS the satellite is below the horizon
+        let below = tracker
+            .look_angles("ISS", &station, Some(pass.los + Duration::minutes(20)))
+            .unwrap();
+        assert!(below.elevation_deg < 0.0);
+        assert!((0.0..360.0).contains(&below.azimuth_deg));
+
+        assert!(tracker
+            .look_angles("MISSING", &station, Some(pass.tca))
+            .is_err());
+    }
+
+    #[test]
+    fn test_doppler_shift() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(tle.epoch()));
+        let overhead = tle
+            .propagate_with(tle.epoch() + Duration::minutes(10), tracker.accuracy())
+            .unwrap();
+        tracker.add_tle("ISS", tle);
+
+        let station = GroundStation::new(
+            "Site",
+            Position::new(overhead.latitude, overhead.longitude, 0.0).unwrap(),
+            Some(0.0),
+        );
+        let passes = tracker.predict_passes("ISS", &station, Some(1.0)).unwrap();
+        let pass = passes
+            .iter()
+            .find(|p| p.max_elevation_deg > 80.0)
+            .expect("overhead pass");
+
+        let freq_hz = 437.8e6;
+        let doppler = |t| {
+            tracker
+                .doppler_shift_hz("ISS", &station, freq_hz, Some(t))
+                .unwrap()
+        };
+        let approaching = doppler(pass.tca - Duration::minutes(2));
+        let receding = doppler(pass.tca + Duration::minutes(2));
+        assert!(
+            approaching > 0.0 && receding < 0.0,
+            "{} {}",
+            approaching,
+            receding
+        );
+        // ~7.5 km/s at UHF gives a shift of order 10 kHz
+        let at_aos = doppler(pass.aos);
+        assert!(at_aos > 5_000.0 && at_aos < 11_000.0, "{}", at_aos);
+        assert!(doppler(pass.tca).abs() < 0.05 * at_aos);
+
+        let range_rate = tracker
+            .range_rate_km_s("ISS", &station, Some(pass.aos))
+            .unwrap();
+        assert!((at_aos + freq_hz * range_rate / SPEED_OF_LIGHT_KM_S).abs() < 1e-6);
+        assert!(tracker
+            .doppler_shift_hz("MISSING", &station, freq_hz, None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_eclipse_intervals() {
+        // ISS-like equatorial orbit at an equinox, so the Sun is in the orbit plane
+        let equinox = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 3, 20, 3, 6, 0).unwrap();
+        let tle = TLE::builder()
+            .epoch(equinox)
+            .semi_major_axis_km(EARTH_RADIUS_KM + 420.0)
+            .build()
+            .unwrap();
+        let period = Duration::milliseconds((tle.orbital_period_minutes() * 60_000.0) as i64);
+        let mut tracker = Tracker::new();
+        tracker.add_tle("SAT", tle);
+
+        let start = equinox;
+        let end = start + period * 3;
+        let intervals = tracker.eclipse_intervals("SAT", start, end).unwrap();
+        assert!(intervals.len() >= 3);
+        for eclipse in &intervals {
+            assert!(eclipse.start < eclipse.end);
+            let middle = eclipse.start + (eclipse.end - eclipse.start) / 2;
+            assert!(!tracker.is_sunlit("SAT", Some(middle)).unwrap());
+            if eclipse.start > start {
+                assert!(tracker
+                    .is_sunlit("SAT", Some(eclipse.start - Duration::seconds(2)))
+                    .unwrap());
+            }
+            if eclipse.end < end {
+                assert!(tracker
+                    .is_sunlit("SAT", Some(eclipse.end + Duration::seconds(2)))
+                    .unwrap());
+            }
+        }
+
+        // Matches the compute crate's zero-beta estimate, asin(R/r)/π ≈ 0.38
+        let eclipsed_hours: f64 = intervals.iter().map(QueryWindow::duration_hours).sum();
+        let fraction = eclipsed_hours / QueryWindow::between(start, end).duration_hours();
+        let expected = (EARTH_RADIUS_KM / (EARTH_RADIUS_KM + 420.0)).asin() / std::f64::consts::PI;
+        assert!(
+            (fraction - expected).abs() < 0.02,
+            "{} vs {}",
+            fraction,
+            expected
+        );
+        assert!(fraction > 0.3 && fraction < 0.4);
+
+        assert!(tracker.is_sunlit("MISSING", None).is_err());
+    }
+
+    #[test]
+    fn test_get_eci_ecef() {
+        let mut tracker = Tracker::new();
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let time = tle.epoch() + Duration::minutes(37);
+        let at = Some(time);
+        tracker.add_tle("ISS", tle);
+
+        let eci = tracker.get_eci("ISS", at).unwrap();
+        let ecef = tracker.get_ecef("ISS", at).unwrap();
+        let position = tracker.get_position("ISS", at).unwrap();
+
+        // Earth rotation changes the direction, not the length
+        let (eci_km, ecef_km) = (
+            Coordinate3D::from(eci).magnitude(),
+            Coordinate3D::from(ecef).magnitude(),
+        );
+        assert!((eci_km - ecef_km).abs() < 1e-9);
+        // Within the ellipsoid's ~21 km equator-to-pole spread of Earth radius plus altitude
+        assert!((ecef_km - (EARTH_RADIUS_KM + position.altitude_km)).abs() < 25.0);
+        assert!(eci != ecef);
+
+        let derived = StateVector {
+            time,
+            frame: Frame::Ecef,
+            position_km: ecef,
+            velocity_km_s: [0.0; 3],
+        }
+        .to_position()
+        .unwrap();
+        assert!((derived.latitude - position.latitude).abs() < 1e-6);
+        assert!((derived.longitude - position.longitude).abs() < 1e-6);
+        assert!((derived.altitude_km - position.altitude_km).abs() < 1e-6);
+
+        assert!(tracker.get_ecef("MISSING", at).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_get_positions_parallel_matches_serial() {
+        let mut tracker = Tracker::new().with_accuracy(PropagationAccuracy::Sgp4);
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let start = tle.epoch();
+        tracker.add_tle("ISS", tle);
+
+        // Two hours at 1 s spans many chunks
+        let end = start + Duration::hours(2);
+        let serial = tracker.get_positions("ISS", start, end, Some(1)).unwrap();
+        let parallel = tracker
+            .get_positions_parallel("ISS", start, end, Some(1))
+            .unwrap();
+        assert_eq!(serial.len(), 7201);
+        assert_eq!(serial, parallel);
+
+        assert!(tracker
+            .get_positions_parallel("ISS", end, start, None)
+            .unwrap()
+            .is_empty());
+        assert!(tracker
+            .get_positions_parallel("MISSING", start, end, None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_remove_tle() {
+        let mut tracker = Tracker::new().with_position_cache(16, Duration::seconds(10));
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let epoch = tle.epoch();
+        tracker.add_tle("ISS", tle.clone());
+        tracker.add_tle("ISS-2", tle);
+        tracker.get_position("ISS", Some(epoch)).unwrap();
+
+        assert_eq!(tracker.remove_tle("ISS").map(|t| t.norad_id), Some(25544));
+        assert_eq!(tracker.list_satellites().len(), 1);
+        assert!(tracker.get_satellite_info("ISS").is_none());
+        assert!(tracker.get_position("ISS", Some(epoch)).is_err());
+        assert!(tracker.remove_tle("ISS").is_none());
+
+        tracker.clear();
+        assert!(tracker.list_satellites().is_empty());
+        assert!(tracker.get_tle("ISS-2").is_none());
+    }
+
+    #[test]
+    fn test_next_pass() {
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let epoch = tle.epoch();
+        let mut tracker = Tracker::new().with_clock(crate::clock::FixedClock::new(tle.epoch()));
+        tracker.add_tle("ISS", tle);
+
+        let station = GroundStation::new(
+            "Boulder",
+            Position::new(40.0, -105.0, 1.6).unwrap(),
+            Some(10.0),
+        );
+        let mut all = tracker.predict_passes("ISS", &station, Some(24.0)).unwrap();
+        all.sort_by_key(|p| p.aos);
+
+        let mut after = epoch;
+        let mut previous: Option<SatellitePass> = None;
+        for expected in all.iter().filter(|p| p.aos > epoch).take(4) {
+            let pass = tracker
+                .next_pass("ISS", &station, after)
+                .expect("next pass");
+            assert!((pass.aos - expected.aos).num_seconds().abs() <= 2);
+            assert!((pass.los - expected.los).num_seconds().abs() <= 2);
+            if let Some(previous) = &previous {
+                assert!(pass.aos > previous.los);
+            }
+            after = pass.los;
+            previous = Some(pass);
+        }
+        assert!(previous.is_some());
+
+        // Starting mid-pass skips to the following one
+        let first = &all[0];
+        let mid = first.aos + (first.los - first.aos) / 2;
+        assert!(tracker.next_pass("ISS", &station, mid).unwrap().aos > first.los);
+
+        assert!(tracker.next_pass("MISSING", &station, epoch).is_none());
+    }
+
+    #[test]
+    fn test_next_pass_ignores_a_pass_spanning_chunks() {
+        let epoch = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 1, 1, 0, 0, 0).unwrap();
+        let geo = TLE::builder()
+            .epoch(epoch)
+            .semi_major_axis_km(42_164.0)
+            .inclination_deg(0.0)
+            .build()
+            .unwrap();
+        let mut tracker = Tracker::new();
+        tracker.add_tle("GEO", geo);
+
+        // Up for the whole week below it: no chunk after the first holds a rise
+        let longitude = tracker.get_position("GEO", Some(epoch)).unwrap().longitude;
+        let station = GroundStation::new(
+            "Below",
+            Position::new(0.0, longitude, 0.0).unwrap(),
+            Some(10.0),
+        );
+        assert!(tracker.next_pass("GEO", &station, epoch).is_none());
+    }
+
+    #[test]
+    fn test_footprint_radius() {
+        let epoch = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 1, 1, 0, 0, 0).unwrap();
+        let mut tracker = Tracker::new();
+        for altitude in [400.0, 800.0] {
+            let tle = TLE::builder()
+                .epoch(epoch)
+                .semi_major_axis_km(EARTH_RADIUS_KM + altitude)
+                .inclination_deg(53.0)
+                .build()
+                .unwrap();
+            tracker.add_tle(format!("SAT-{}", altitude), tle);
+        }
+
+        let low = tracker
+            .footprint_radius_km("SAT-400", Some(epoch), 10.0)
+            .unwrap();
+        let high = tracker
+            .footprint_radius_km("SAT-800", Some(epoch), 10.0)
+            .unwrap();
+        assert!(high > low, "{} vs {}", high, low);
+        // 400 km at 10° elevation sees out to roughly 1400 km
+        assert!(low > 1200.0 && low < 1600.0, "{}", low);
+        // Lowering the mask widens the footprint
+        assert!(
+            tracker
+                .footprint_radius_km("SAT-400", Some(epoch), 0.0)
+                .unwrap()
+                > low
+        );
+
+        // The circle's vertices sit on the footprint edge and see the satellite at the mask
+        let center = tracker.get_position("SAT-400", Some(epoch)).unwrap();
+        let circle = tracker
+            .visibility_circle("SAT-400", Some(epoch), 10.0, 36)
+            .unwrap();
+        assert_eq!(circle.len(), 36);
+        for vertex in &circle {
+            assert!((vertex.great_circle_distance(&center) - low).abs() < 1e-6);
+            let station = GroundStation::new("Edge", *vertex, Some(10.0));
+            assert!((station.look_angle(&center).elevation_deg - 10.0).abs() < 1.0);
+        }
+
+        assert!(tracker
+            .footprint_radius_km("SAT-400", Some(epoch), 90.0)
+            .is_err());
+        assert!(tracker
+            .visibility_circle("SAT-400", Some(epoch), 10.0, 2)
+            .is_err());
+    }
+
+    #[test]
+    fn test_ground_track_geojson() {
+        let mut tracker = Tracker::new();
+        let lines: Vec<String> = ISS_TLE.lines().map(|s| s.to_string()).collect();
+        let tle = TLE::parse(&lines).unwrap();
+        let start = tle.epoch();
+        tracker.add_tle("ISS", tle);
+
+        let geojson = tracker
+            .ground_track_geojson("ISS", start, start + Duration::hours(3), Some(60))
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&geojson).unwrap();
+        assert_eq!(value["type"], "FeatureCollection");
+        let features = value["features"].as_array().unwrap();
+        assert!(features.len() >= 2, "{} features", features.len());
+
+        for feature in features {
+            assert_eq!(feature["geometry"]["type"], "LineString");
+            assert_eq!(feature["properties"]["satellite_id"], "ISS");
+            assert!(
+                feature["properties"]["start"].as_str().unwrap()
+                    <= feature["properties"]["end"].as_str().unwrap()
+            );
+            let coordinates = feature["geometry"]["coordinates"].as_array().unwrap();
+            assert!(coordinates.len() >= 2);
+            assert!(coordinates
+                .windows(2)
+                .all(|w| (w[1][0].as_f64().unwrap() - w[0][0].as_f64().unwrap()).abs() <= 180.0));
+        }
+
+        // Consecutive features meet on opposite sides of the antimeridian
+        for pair in features.windows(2) {
+            let last = pair[0]["geometry"]["coordinates"]
+                .as_array()
+                .unwrap()
+                .last()
+                .unwrap()
+                .clone();
+            let first = pair[1]["geometry"]["coordinates"][0].clone();
+            assert_eq!(last[0].as_f64().unwrap().abs(), 180.0);
+            assert_eq!(first[0].as_f64().unwrap(), -last[0].as_f64().unwrap());
+            assert_eq!(first[1], last[1]);
+        }
+
+        // Coordinates follow the tracker's accuracy, not always SGP4
+        let end = start + Duration::minutes(10);
+        let first_coordinate = |tracker: &Tracker| -> serde_json::Value {
+            let geojson = tracker
+                .ground_track_geojson("ISS", start + Duration::minutes(5), end, Some(60))
+                .unwrap();
+            serde_json::from_str::<serde_json::Value>(&geojson).unwrap()["features"][0]["geometry"]
+                ["coordinates"][0]
+                .clone()
+        };
+        for accuracy in [PropagationAccuracy::TwoBody, PropagationAccuracy::Sgp4] {
+            tracker = tracker.with_accuracy(accuracy);
+            let expected = tracker
+                .get_position("ISS", Some(start + Duration::minutes(5)))
+                .unwrap();
+            let coordinate = first_coordinate(&tracker);
+            assert_eq!(coordinate[0].as_f64().unwrap(), expected.longitude);
+            assert_eq!(coordinate[1].as_f64().unwrap(), expected.latitude);
+        }
+
+        // A single sample can't form a LineString
+        let single = tracker
+            .ground_track_geojson("ISS", start, start, None)
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&single).unwrap();
+        assert_eq!(value["features"].as_array().unwrap().len(), 0);
+
+        assert!(tracker
+            .ground_track_geojson("MISSING", start, start, None)
+            .is_err());
     }
 }
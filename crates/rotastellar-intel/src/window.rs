@@ -0,0 +1,155 @@
+//! RotaStellar Intel - Query Windows
+//!
+//! Time windows used to scope pattern and conjunction queries.
+
+use chrono::{DateTime, Duration, Utc};
+use rotastellar::{TimeRange, ValidationError};
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{Clock, SystemClock};
+
+/// Longest span, in hours, a window built from an hour count covers (about
+/// 10,000 years, well inside the range `DateTime` can represent).
+pub const MAX_WINDOW_HOURS: f64 = 24.0 * 365.25 * 10_000.0;
+
+/// Closed time interval used to filter time-stamped results.
+///
+/// The `*_hours` constructors treat negative or NaN `hours` as zero, giving
+/// an empty window at the current time, and clamp longer spans to
+/// [`MAX_WINDOW_HOURS`].
+///
+/// # Example
+///
+/// ```
+/// use rotastellar_intel::QueryWindow;
+///
+/// let window = QueryWindow::last_hours(24.0);
+/// assert!(window.contains(chrono::Utc::now() - chrono::Duration::hours(1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryWindow {
+    /// Start of the window (inclusive)
+    pub start: DateTime<Utc>,
+    /// End of the window (inclusive)
+    pub end: DateTime<Utc>,
+}
+
+impl QueryWindow {
+    /// Create a window between two times.
+    ///
+    /// The bounds are swapped if `end` precedes `start`.
+    pub fn between(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        if end < start {
+            Self {
+                start: end,
+                end: start,
+            }
+        } else {
+            Self { start, end }
+        }
+    }
+
+    /// Window covering the last `hours` up to the system time.
+    pub fn last_hours(hours: f64) -> Self {
+        Self::last_hours_with(hours, &SystemClock)
+    }
+
+    /// Window covering the last `hours` up to the clock's current time.
+    pub fn last_hours_with(hours: f64, clock: &dyn Clock) -> Self {
+        let now = clock.now();
+        Self::between(now - hours_to_duration(hours), now)
+    }
+
+    /// Window covering the next `hours` from the system time.
+    pub fn next_hours(hours: f64) -> Self {
+        Self::next_hours_with(hours, &SystemClock)
+    }
+
+    /// Window covering the next `hours` from the clock's current time.
+    pub fn next_hours_with(hours: f64, clock: &dyn Clock) -> Self {
+        let now = clock.now();
+        Self::between(now, now + hours_to_duration(hours))
+    }
+
+    /// Check whether a time falls inside the window.
+    pub fn contains(&self, time: DateTime<Utc>) -> bool {
+        self.start <= time && time <= self.end
+    }
+
+    /// Length of the window in hours.
+    pub fn duration_hours(&self) -> f64 {
+        (self.end - self.start).num_milliseconds() as f64 / 3_600_000.0
+    }
+}
+
+impl TryFrom<&TimeRange> for QueryWindow {
+    type Error = ValidationError;
+
+    fn try_from(range: &TimeRange) -> Result<Self, Self::Error> {
+        Ok(Self::between(range.start_time()?, range.end_time()?))
+    }
+}
+
+/// Convert a span in hours to a duration.
+///
+/// # Returns
+///
+/// The duration to the nearest millisecond; negative and NaN `hours` give a
+/// zero duration, and spans beyond [`MAX_WINDOW_HOURS`] are clamped to it.
+pub(crate) fn hours_to_duration(hours: f64) -> Duration {
+    Duration::milliseconds((hours.clamp(0.0, MAX_WINDOW_HOURS) * 3_600_000.0) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_last_hours_contains() {
+        let now = Utc::now();
+        let window = QueryWindow::last_hours(24.0);
+        assert!(!window.contains(now - Duration::hours(25)));
+        assert!(window.contains(now - Duration::hours(1)));
+
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+        let window = QueryWindow::next_hours_with(48.0, &clock);
+        assert!(window.contains(clock.now() + Duration::hours(48)));
+        assert!(!window.contains(clock.now() - Duration::seconds(1)));
+        assert!((window.duration_hours() - 48.0).abs() < 1e-9);
+
+        // Negative and NaN spans collapse to an empty window at now
+        for hours in [-5.0, f64::NAN] {
+            let window = QueryWindow::last_hours_with(hours, &clock);
+            assert_eq!((window.start, window.end), (clock.now(), clock.now()));
+        }
+
+        // Huge spans are clamped rather than overflowing the time range
+        for hours in [1e300, f64::INFINITY] {
+            let window = QueryWindow::last_hours_with(hours, &clock);
+            assert_eq!(window.end, clock.now());
+            assert!((window.duration_hours() - MAX_WINDOW_HOURS).abs() < 1e-6);
+            let window = QueryWindow::next_hours_with(hours, &clock);
+            assert_eq!(window.start, clock.now());
+            assert!((window.duration_hours() - MAX_WINDOW_HOURS).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_from_time_range() {
+        let range = TimeRange::new("2024-03-02T00:00:00Z", "2024-03-01T00:00:00Z").unwrap();
+        let window = QueryWindow::try_from(&range).unwrap();
+        assert_eq!(
+            window.start,
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()
+        );
+        assert!((window.duration_hours() - 24.0).abs() < 1e-9);
+
+        let bad = TimeRange {
+            start: "yesterday".to_string(),
+            end: "2024-03-01T00:00:00Z".to_string(),
+        };
+        assert!(QueryWindow::try_from(&bad).is_err());
+    }
+}
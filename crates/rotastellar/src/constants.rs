@@ -0,0 +1,33 @@
+//! RotaStellar SDK - Physical Constants
+//!
+//! Earth constants shared by every RotaStellar crate.
+//!
+//! Two radii are provided because the crates mix two kinds of model:
+//!
+//! - [`EARTH_RADIUS_EQUATORIAL_KM`] (WGS84) belongs with anything tied to the
+//!   real ellipsoid or to element sets: geodetic conversion, perigee/apogee
+//!   altitudes from TLEs, and J2 terms (J2 is normalized to this radius).
+//! - [`EARTH_RADIUS_MEAN_KM`] belongs with spherical-Earth approximations:
+//!   eclipse geometry, Earth view factors, slant range and line-of-sight
+//!   checks, and periods derived from an altitude rather than an element set.
+//!
+//! Mixing the two within one calculation gives altitude errors of ~7 km.
+
+/// Earth's equatorial radius in kilometers (WGS84).
+pub const EARTH_RADIUS_EQUATORIAL_KM: f64 = 6378.137;
+
+/// WGS84 ellipsoid flattening.
+pub const WGS84_FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Earth's mean radius in kilometers (IUGG).
+pub const EARTH_RADIUS_MEAN_KM: f64 = 6371.0;
+
+/// Earth's gravitational parameter (km^3/s^2).
+/// Standard value used by GPS, TLE propagators, etc.
+pub const EARTH_MU: f64 = 398600.4418;
+
+/// Earth's second zonal harmonic (oblateness), unnormalized.
+pub const EARTH_J2: f64 = 1.08262668e-3;
+
+/// Earth's sidereal rotation rate (rad/s).
+pub const EARTH_ROTATION_RATE: f64 = 7.292115e-5;
@@ -34,7 +34,10 @@ pub enum AuthenticationError {
 
     /// API key format is invalid.
     #[error("Invalid API key format: {masked_key}. Keys should start with 'rs_live_' or 'rs_test_'")]
-    InvalidApiKey { masked_key: String },
+    InvalidApiKey {
+        /// The first characters of the rejected key.
+        masked_key: String,
+    },
 }
 
 impl AuthenticationError {
@@ -111,6 +114,35 @@ impl ApiError {
         self.request_id = Some(request_id.into());
         self
     }
+
+    /// Whether the request may succeed if retried (429 or 5xx).
+    pub fn is_retryable(&self) -> bool {
+        self.is_rate_limited() || self.is_server_error()
+    }
+
+    /// Whether this is a client error (4xx).
+    pub fn is_client_error(&self) -> bool {
+        (400..500).contains(&self.status_code)
+    }
+
+    /// Whether this is a server error (5xx).
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&self.status_code)
+    }
+
+    /// Whether this is a rate limit error (429).
+    pub fn is_rate_limited(&self) -> bool {
+        self.status_code == 429
+    }
+
+    /// Seconds to wait before retrying, if the API provided one.
+    pub fn retry_after(&self) -> Option<u32> {
+        self.details
+            .as_ref()?
+            .get("retry_after")?
+            .as_u64()
+            .and_then(|r| u32::try_from(r).ok())
+    }
 }
 
 /// Validation errors for input data.
@@ -149,5 +181,56 @@ pub enum NetworkError {
     Other(String),
 }
 
+#[cfg(feature = "async")]
+impl From<reqwest::Error> for RotaStellarError {
+    fn from(err: reqwest::Error) -> Self {
+        if let Some(status) = err.status() {
+            return Self::Api(ApiError::new(err.to_string(), status.as_u16()));
+        }
+        if err.is_connect() {
+            Self::Network(NetworkError::Connection(err.to_string()))
+        } else {
+            Self::Network(NetworkError::Other(err.to_string()))
+        }
+    }
+}
+
 /// Result type alias for RotaStellar operations.
 pub type Result<T> = std::result::Result<T, RotaStellarError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_classification() {
+        let rate_limited = ApiError::new("Too many requests", 429);
+        assert!(rate_limited.is_retryable());
+        assert!(rate_limited.is_client_error());
+        assert!(rate_limited.is_rate_limited());
+
+        for status in [500, 502, 503] {
+            let err = ApiError::new("Server error", status);
+            assert!(err.is_retryable());
+            assert!(!err.is_client_error());
+        }
+
+        for status in [400, 401, 404] {
+            let err = ApiError::new("Bad request", status);
+            assert!(!err.is_retryable());
+            assert!(err.is_client_error());
+            assert!(!err.is_rate_limited());
+        }
+
+        let ok = ApiError::new("Redirect", 302);
+        assert!(!ok.is_retryable());
+        assert!(!ok.is_client_error());
+    }
+
+    #[test]
+    fn test_retry_after() {
+        assert_eq!(ApiError::rate_limited(Some(30)).retry_after(), Some(30));
+        assert_eq!(ApiError::rate_limited(None).retry_after(), None);
+        assert_eq!(ApiError::not_found("Satellite", "x").retry_after(), None);
+    }
+}
@@ -48,6 +48,7 @@
 
 pub mod auth;
 pub mod config;
+pub mod constants;
 pub mod error;
 pub mod types;
 
@@ -57,7 +58,7 @@ pub use config::{Config, ConfigBuilder};
 pub use error::{
     ApiError, AuthenticationError, NetworkError, Result, RotaStellarError, ValidationError,
 };
-pub use types::{Orbit, Position, Satellite, TimeRange, EARTH_MU, EARTH_RADIUS_KM};
+pub use types::{Coordinate3D, Orbit, Position, Satellite, TimeRange, EARTH_MU, EARTH_RADIUS_KM};
 
 /// Current version of the crate.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
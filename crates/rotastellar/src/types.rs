@@ -8,21 +8,21 @@
 //! - Validate on construction to fail fast
 //! - Implement Copy for small types (Position, Orbit) for ergonomics
 
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
+use crate::constants::{EARTH_J2, WGS84_FLATTENING};
 use crate::error::ValidationError;
 
-// TODO(subhadipmitra): Consider adding a Coordinate3D type for ECI/ECEF vectors
+pub use crate::constants::EARTH_MU;
+
 // TODO: Add geodetic-to-geocentric conversion utilities
 
 /// Earth's equatorial radius in kilometers.
 /// NOTE(subhadipmitra): Using WGS84. Polar radius is 6356.752 km.
-pub const EARTH_RADIUS_KM: f64 = 6378.137;
-
-/// Earth's gravitational parameter (km^3/s^2).
-/// Standard value used by GPS, TLE propagators, etc.
-pub const EARTH_MU: f64 = 398600.4418;
+pub const EARTH_RADIUS_KM: f64 = crate::constants::EARTH_RADIUS_EQUATORIAL_KM;
 
 /// Geographic position with altitude.
 ///
@@ -64,6 +64,116 @@ impl Position {
         Ok(pos)
     }
 
+    /// Great-circle distance to another position in km.
+    ///
+    /// Uses the haversine formula on a spherical Earth of radius
+    /// [`EARTH_RADIUS_KM`]; altitude is ignored.
+    pub fn great_circle_distance(&self, other: &Position) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+
+        let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+    }
+
+    /// Position reached by travelling along a great circle.
+    ///
+    /// Paths may cross a pole; the result is wrapped back into valid
+    /// latitude/longitude ranges. Altitude is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `bearing_deg` - Initial bearing, clockwise from north
+    /// * `distance_km` - Surface distance to travel
+    ///
+    /// # Errors
+    ///
+    /// Returns a ValidationError if the bearing is not finite or the
+    /// distance is negative.
+    pub fn destination(
+        &self,
+        bearing_deg: f64,
+        distance_km: f64,
+    ) -> Result<Position, ValidationError> {
+        if !bearing_deg.is_finite() {
+            return Err(ValidationError::new("bearing_deg", "Must be finite"));
+        }
+        if !(distance_km >= 0.0 && distance_km.is_finite()) {
+            return Err(ValidationError::new("distance_km", "Must be non-negative"));
+        }
+
+        let lat1 = self.latitude.to_radians();
+        let lon1 = self.longitude.to_radians();
+        let bearing = bearing_deg.to_radians();
+        let delta = distance_km / EARTH_RADIUS_KM;
+
+        let lat2 = (lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * bearing.cos())
+            .clamp(-1.0, 1.0)
+            .asin();
+        let lon2 = lon1
+            + (bearing.sin() * delta.sin() * lat1.cos())
+                .atan2(delta.cos() - lat1.sin() * lat2.sin());
+
+        Position::new(
+            lat2.to_degrees(),
+            normalize_longitude(lon2.to_degrees()),
+            self.altitude_km,
+        )
+    }
+
+    /// Midpoint along the great circle to another position.
+    ///
+    /// Altitude is the average of the two altitudes.
+    pub fn midpoint(&self, other: &Position) -> Position {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let lon1 = self.longitude.to_radians();
+        let dlon = (other.longitude - self.longitude).to_radians();
+
+        let bx = lat2.cos() * dlon.cos();
+        let by = lat2.cos() * dlon.sin();
+        let lat = (lat1.sin() + lat2.sin()).atan2(((lat1.cos() + bx).powi(2) + by * by).sqrt());
+        let lon = lon1 + by.atan2(lat1.cos() + bx);
+
+        Self {
+            latitude: lat.to_degrees(),
+            longitude: normalize_longitude(lon.to_degrees()),
+            altitude_km: (self.altitude_km + other.altitude_km) / 2.0,
+        }
+    }
+
+    /// Earth-centered, Earth-fixed Cartesian coordinates in km.
+    ///
+    /// Latitude is geodetic on the WGS84 ellipsoid.
+    pub fn to_ecef(&self) -> Coordinate3D {
+        let lat = self.latitude.to_radians();
+        let lon = self.longitude.to_radians();
+        let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+        let n = EARTH_RADIUS_KM / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+
+        Coordinate3D::new(
+            (n + self.altitude_km) * lat.cos() * lon.cos(),
+            (n + self.altitude_km) * lat.cos() * lon.sin(),
+            (n * (1.0 - e2) + self.altitude_km) * lat.sin(),
+        )
+    }
+
+    /// East-North-Up offset of this position from an observer, in km.
+    ///
+    /// Up is along the observer's ellipsoid normal, so a point straight
+    /// above the observer has zero east and north components.
+    pub fn to_enu(&self, observer: &Position) -> [f64; 3] {
+        let d = self.to_ecef() - observer.to_ecef();
+
+        let (sin_lat, cos_lat) = observer.latitude.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = observer.longitude.to_radians().sin_cos();
+        [
+            -sin_lon * d.x + cos_lon * d.y,
+            -sin_lat * cos_lon * d.x - sin_lat * sin_lon * d.y + cos_lat * d.z,
+            cos_lat * cos_lon * d.x + cos_lat * sin_lon * d.y + sin_lat * d.z,
+        ]
+    }
+
     /// Validate position parameters.
     fn validate(&self) -> Result<(), ValidationError> {
         if !(-90.0..=90.0).contains(&self.latitude) {
@@ -85,6 +195,156 @@ impl Position {
     }
 }
 
+/// Cartesian vector in km, such as an ECI or ECEF position.
+///
+/// The frame isn't recorded; callers keep track of which one a value is in.
+/// Converts to and from `[f64; 3]` for code working on plain arrays.
+///
+/// # Example
+///
+/// ```
+/// use rotastellar::types::{Coordinate3D, Position};
+///
+/// let station = Position::new(40.0, -105.0, 1.6).unwrap().to_ecef();
+/// let overhead = Position::new(40.0, -105.0, 401.6).unwrap().to_ecef();
+/// assert!(((overhead - station).magnitude() - 400.0).abs() < 1e-6);
+///
+/// let zenith = (overhead - station).normalize();
+/// assert!((zenith.magnitude() - 1.0).abs() < 1e-12);
+/// assert!(zenith.dot(&Coordinate3D::new(0.0, 0.0, 1.0)) > 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Coordinate3D {
+    /// X component in km
+    pub x: f64,
+    /// Y component in km
+    pub y: f64,
+    /// Z component in km
+    pub z: f64,
+}
+
+impl Coordinate3D {
+    /// Create a vector from its components.
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Euclidean length.
+    pub fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Dot product.
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Cross product `self × other`.
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Unit vector in the same direction. The zero vector stays zero.
+    pub fn normalize(&self) -> Self {
+        let magnitude = self.magnitude();
+        if magnitude == 0.0 {
+            *self
+        } else {
+            *self / magnitude
+        }
+    }
+
+    /// Geodetic position of an ECEF point on the WGS84 ellipsoid.
+    ///
+    /// Iterates the geodetic latitude to convergence, which stays accurate at
+    /// the poles and well above the surface. Altitude is not validated, so a
+    /// point below the ellipsoid gives a negative altitude.
+    pub fn to_geodetic(&self) -> Position {
+        let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+        let p = self.x.hypot(self.y);
+        let mut lat = self.z.atan2(p * (1.0 - e2));
+        let mut n = EARTH_RADIUS_KM;
+        for _ in 0..10 {
+            n = EARTH_RADIUS_KM / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+            let next = (self.z + e2 * n * lat.sin()).atan2(p);
+            let converged = (next - lat).abs() < 1e-14;
+            lat = next;
+            if converged {
+                break;
+            }
+        }
+        // Valid at every latitude, unlike p / cos(lat) - N
+        let altitude_km = p * lat.cos() + self.z * lat.sin() - n * (1.0 - e2 * lat.sin().powi(2));
+
+        Position {
+            latitude: lat.to_degrees(),
+            longitude: self.y.atan2(self.x).to_degrees(),
+            altitude_km,
+        }
+    }
+}
+
+impl From<[f64; 3]> for Coordinate3D {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<Coordinate3D> for [f64; 3] {
+    fn from(c: Coordinate3D) -> Self {
+        [c.x, c.y, c.z]
+    }
+}
+
+impl Add for Coordinate3D {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Coordinate3D {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Coordinate3D {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f64> for Coordinate3D {
+    type Output = Self;
+
+    fn mul(self, scale: f64) -> Self {
+        Self::new(self.x * scale, self.y * scale, self.z * scale)
+    }
+}
+
+impl Div<f64> for Coordinate3D {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self {
+        Self::new(self.x / divisor, self.y / divisor, self.z / divisor)
+    }
+}
+
+/// Wrap a longitude in degrees into [-180, 180].
+fn normalize_longitude(longitude: f64) -> f64 {
+    (longitude + 180.0).rem_euclid(360.0) - 180.0
+}
+
 /// Keplerian orbital elements.
 ///
 /// # Example
@@ -190,6 +450,69 @@ impl Orbit {
     pub fn mean_motion(&self) -> f64 {
         86400.0 / self.orbital_period_seconds()
     }
+
+    /// Orbital radius at a true anomaly in kilometers.
+    fn radius_at_true_anomaly(&self, nu_deg: f64) -> f64 {
+        let e = self.eccentricity;
+        self.semi_major_axis_km * (1.0 - e * e) / (1.0 + e * nu_deg.to_radians().cos())
+    }
+
+    /// Orbital speed at a true anomaly in km/s (vis-viva).
+    pub fn velocity_at_true_anomaly(&self, nu_deg: f64) -> f64 {
+        let r = self.radius_at_true_anomaly(nu_deg);
+        (EARTH_MU * (2.0 / r - 1.0 / self.semi_major_axis_km)).sqrt()
+    }
+
+    /// Orbital speed at apogee in km/s.
+    pub fn apogee_velocity(&self) -> f64 {
+        self.velocity_at_true_anomaly(180.0)
+    }
+
+    /// Orbital speed at perigee in km/s.
+    pub fn perigee_velocity(&self) -> f64 {
+        self.velocity_at_true_anomaly(0.0)
+    }
+
+    /// Specific orbital energy in km²/s² (negative for bound orbits).
+    pub fn specific_orbital_energy(&self) -> f64 {
+        -EARTH_MU / (2.0 * self.semi_major_axis_km)
+    }
+
+    /// Secular RAAN drift due to J2 in degrees per day.
+    ///
+    /// Positive (eastward) for retrograde orbits; about +0.9856°/day for a
+    /// sun-synchronous orbit.
+    pub fn j2_raan_rate_deg_per_day(&self) -> f64 {
+        let cos_i = self.inclination_deg.to_radians().cos();
+        (-1.5 * self.j2_rate_factor() * cos_i * 86400.0).to_degrees()
+    }
+
+    /// Secular argument-of-perigee drift due to J2 in degrees per day.
+    ///
+    /// Zero at the critical inclinations (63.4° and 116.6°).
+    pub fn j2_arg_perigee_rate_deg_per_day(&self) -> f64 {
+        let cos_i = self.inclination_deg.to_radians().cos();
+        (0.75 * self.j2_rate_factor() * (5.0 * cos_i * cos_i - 1.0) * 86400.0).to_degrees()
+    }
+
+    /// Nodal (draconic) period in minutes: time between ascending node
+    /// crossings including secular J2 drift.
+    pub fn nodal_period_minutes(&self) -> f64 {
+        let n = 2.0 * PI / self.orbital_period_seconds();
+        let e = self.eccentricity;
+        let cos_i = self.inclination_deg.to_radians().cos();
+        let k = self.j2_rate_factor();
+        let mean_anomaly_rate = n + 0.75 * k * (1.0 - e * e).sqrt() * (3.0 * cos_i * cos_i - 1.0);
+        let arg_perigee_rate = 0.75 * k * (5.0 * cos_i * cos_i - 1.0);
+        2.0 * PI / (mean_anomaly_rate + arg_perigee_rate) / 60.0
+    }
+
+    /// Common factor `n · J2 · (R/p)²` of the secular J2 rates in rad/s.
+    fn j2_rate_factor(&self) -> f64 {
+        let n = 2.0 * PI / self.orbital_period_seconds();
+        let p = self.semi_major_axis_km * (1.0 - self.eccentricity * self.eccentricity);
+        n * EARTH_J2 * (EARTH_RADIUS_KM / p).powi(2)
+    }
 }
 
 /// Time range for queries.
@@ -201,7 +524,7 @@ impl Orbit {
 /// use std::time::Duration;
 ///
 /// let tr = TimeRange::next_hours(24.0);
-/// println!("Duration: {} hours", tr.duration_hours());
+/// println!("Duration: {} hours", tr.duration_hours().unwrap());
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimeRange {
@@ -213,83 +536,95 @@ pub struct TimeRange {
 
 impl TimeRange {
     /// Create a new TimeRange.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] on `start` or `end` if that bound is not
+    /// valid RFC 3339. A reversed range is accepted.
     pub fn new(start: impl Into<String>, end: impl Into<String>) -> Result<Self, ValidationError> {
         let range = Self {
             start: start.into(),
             end: end.into(),
         };
-        // Note: Full validation would require parsing dates
+        range.start_time()?;
+        range.end_time()?;
         Ok(range)
     }
 
     /// Create a time range starting now for the specified hours.
+    ///
+    /// # Returns
+    ///
+    /// A range from now to `hours` later, to the nearest millisecond. Negative
+    /// and NaN `hours` give an empty range at now, and the end is capped at
+    /// the last instant RFC 3339 can write (9999-12-31T23:59:59.999Z).
     pub fn next_hours(hours: f64) -> Self {
-        use std::time::{SystemTime, UNIX_EPOCH};
-
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
+        let latest = DateTime::parse_from_rfc3339("9999-12-31T23:59:59.999Z")
             .unwrap()
-            .as_secs();
-        let end = now + (hours * 3600.0) as u64;
-
-        // Simple ISO 8601 formatting
+            .with_timezone(&Utc);
+        let now = Utc::now();
+        let end = now
+            .checked_add_signed(Duration::milliseconds(
+                (hours.max(0.0) * 3_600_000.0) as i64,
+            ))
+            .map_or(latest, |end| end.min(latest));
         Self {
-            start: format_timestamp(now),
-            end: format_timestamp(end),
+            start: now.to_rfc3339_opts(SecondsFormat::Millis, true),
+            end: end.to_rfc3339_opts(SecondsFormat::Millis, true),
         }
     }
 
-    /// Duration in hours (approximate, based on string parsing).
-    pub fn duration_hours(&self) -> f64 {
-        // This is a simplified implementation
-        // Full implementation would parse the timestamps
-        24.0 // Placeholder
+    /// Start time parsed as RFC 3339.
+    pub fn start_time(&self) -> Result<DateTime<Utc>, ValidationError> {
+        parse_rfc3339("start", &self.start)
     }
-}
 
-/// Format a Unix timestamp as ISO 8601.
-fn format_timestamp(secs: u64) -> String {
-    // Simple implementation - in production use chrono
-    let days_since_epoch = secs / 86400;
-    let secs_today = secs % 86400;
-    let hours = secs_today / 3600;
-    let minutes = (secs_today % 3600) / 60;
-    let seconds = secs_today % 60;
-
-    // Approximate date calculation (not accounting for leap years properly)
-    let mut year = 1970;
-    let mut remaining_days = days_since_epoch;
-
-    while remaining_days >= 365 {
-        let days_in_year = if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
-            366
-        } else {
-            365
-        };
-        if remaining_days >= days_in_year {
-            remaining_days -= days_in_year;
-            year += 1;
-        } else {
-            break;
-        }
+    /// End time parsed as RFC 3339.
+    pub fn end_time(&self) -> Result<DateTime<Utc>, ValidationError> {
+        parse_rfc3339("end", &self.end)
     }
 
-    let days_in_months = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-    let mut month = 1;
-    for days in days_in_months {
-        if remaining_days >= days {
-            remaining_days -= days;
-            month += 1;
-        } else {
-            break;
-        }
+    /// Duration in hours (negative if reversed).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] on `start` or `end` if that bound is not
+    /// valid RFC 3339, which is possible when the fields are set directly or
+    /// deserialized.
+    pub fn duration_hours(&self) -> Result<f64, ValidationError> {
+        let (start, end) = (self.start_time()?, self.end_time()?);
+        Ok((end - start).num_milliseconds() as f64 / 3_600_000.0)
+    }
+
+    /// Check whether a time falls inside the range (bounds inclusive).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] on `start` or `end` if that bound is not
+    /// valid RFC 3339.
+    pub fn contains(&self, t: DateTime<Utc>) -> Result<bool, ValidationError> {
+        let (start, end) = (self.start_time()?, self.end_time()?);
+        Ok(start <= t && t <= end)
     }
-    let day = remaining_days + 1;
 
-    format!(
-        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-        year, month, day, hours, minutes, seconds
-    )
+    /// Check whether two ranges share at least one instant.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] for the first bound of either range that
+    /// is not valid RFC 3339.
+    pub fn overlaps(&self, other: &TimeRange) -> Result<bool, ValidationError> {
+        let (a_start, a_end) = (self.start_time()?, self.end_time()?);
+        let (b_start, b_end) = (other.start_time()?, other.end_time()?);
+        Ok(a_start <= b_end && b_start <= a_end)
+    }
+}
+
+/// Parse an RFC 3339 timestamp into UTC.
+fn parse_rfc3339(field: &str, value: &str) -> Result<DateTime<Utc>, ValidationError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| ValidationError::new(field, format!("Invalid timestamp '{}': {}", value, e)))
 }
 
 /// Satellite information.
@@ -358,6 +693,76 @@ impl Satellite {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_position_to_enu() {
+        let observer = Position::new(40.0, -105.0, 1.6).unwrap();
+        let above = Position::new(40.0, -105.0, 421.6).unwrap();
+        let [east, north, up] = above.to_enu(&observer);
+        assert!(east.abs() < 1e-9 && north.abs() < 1e-9);
+        assert!((up - 420.0).abs() < 1e-9);
+
+        // A point to the north-east at the same altitude dips below the local horizon
+        let offset = Position::new(41.0, -104.0, 1.6).unwrap();
+        let [east, north, up] = offset.to_enu(&observer);
+        assert!(east > 80.0 && north > 100.0 && up < 0.0);
+        assert_eq!(observer.to_enu(&observer), [0.0; 3]);
+
+        // Equator, prime meridian: ECEF x points up, y east, z north
+        let ecef = Position::new(0.0, 0.0, 0.0).unwrap().to_ecef();
+        assert!(
+            (ecef.x - EARTH_RADIUS_KM).abs() < 1e-9 && ecef.y.abs() < 1e-9 && ecef.z.abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_coordinate3d_ops() {
+        let a = Coordinate3D::new(1.0, 2.0, 2.0);
+        let b = Coordinate3D::from([0.0, 1.0, 0.0]);
+        assert_eq!(a.magnitude(), 3.0);
+        assert_eq!(a.dot(&b), 2.0);
+        assert_eq!(
+            Coordinate3D::new(1.0, 0.0, 0.0).cross(&b),
+            Coordinate3D::new(0.0, 0.0, 1.0)
+        );
+        assert_eq!(a + b - b, a);
+        assert_eq!(-a * 2.0, Coordinate3D::new(-2.0, -4.0, -4.0));
+        assert!((a.normalize().magnitude() - 1.0).abs() < 1e-15);
+        assert_eq!(Coordinate3D::default().normalize(), Coordinate3D::default());
+        assert_eq!(<[f64; 3]>::from(a / 2.0), [0.5, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_geodetic_ecef_round_trip() {
+        let cases = [
+            (0.0, 0.0, 0.0),
+            (28.5729, -80.649, 408.0),
+            (-33.9, 151.2, 0.05),
+            (51.6, 179.9, 35786.0),
+            (89.999, 45.0, 12.0),
+            (-90.0, 0.0, 1.0),
+            (64.2, -21.9, 0.0),
+        ];
+        for (lat, lon, alt) in cases {
+            let position = Position::new(lat, lon, alt).unwrap();
+            let back = position.to_ecef().to_geodetic();
+            // Compare as ECEF so longitude at the poles doesn't matter; 1 m tolerance
+            assert!(
+                (back.to_ecef() - position.to_ecef()).magnitude() < 1e-3,
+                "{:?} -> {:?}",
+                position,
+                back
+            );
+            assert!(
+                (back.latitude - lat).abs() < 1e-8 && (back.altitude_km - alt).abs() < 1e-6,
+                "{:?}",
+                back
+            );
+            if lat.abs() < 90.0 {
+                assert!((back.longitude - lon).abs() < 1e-9);
+            }
+        }
+    }
+
     #[test]
     fn test_position_valid() {
         let pos = Position::new(28.5729, -80.6490, 408.0).unwrap();
@@ -372,6 +777,28 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_destination_round_trip() {
+        let start = Position::new(28.5729, -80.6490, 0.0).unwrap();
+        for bearing in [0.0, 45.0, 135.0, 270.0] {
+            let end = start.destination(bearing, 1500.0).unwrap();
+            assert!((start.great_circle_distance(&end) - 1500.0).abs() < 1e-6);
+        }
+
+        let mid = start.midpoint(&start.destination(90.0, 1000.0).unwrap());
+        assert!((start.great_circle_distance(&mid) - 500.0).abs() < 1e-6);
+
+        // Heading north from 80°N over the pole comes down the other side
+        let arctic = Position::new(80.0, 10.0, 0.0).unwrap();
+        let over_pole = arctic
+            .destination(0.0, 20.0_f64.to_radians() * EARTH_RADIUS_KM)
+            .unwrap();
+        assert!((over_pole.latitude - 80.0).abs() < 1e-6);
+        assert!((over_pole.longitude + 170.0).abs() < 1e-6);
+
+        assert!(start.destination(0.0, -1.0).is_err());
+    }
+
     #[test]
     fn test_orbit_valid() {
         let orbit = Orbit::new(6778.0, 0.0001, 51.6, 100.0, 90.0, 0.0).unwrap();
@@ -385,4 +812,90 @@ mod tests {
         assert!((orbit.apogee_km() - 400.5).abs() < 1.0);
         assert!((orbit.perigee_km() - 399.2).abs() < 1.0);
     }
+
+    #[test]
+    fn test_j2_secular_rates() {
+        // ~650 km sun-synchronous orbit
+        let sso = Orbit::new(7030.0, 0.001, 98.0, 0.0, 0.0, 0.0).unwrap();
+        assert!((sso.j2_raan_rate_deg_per_day() - 0.99).abs() < 0.02);
+        let nodal = sso.nodal_period_minutes();
+        assert!(nodal > sso.orbital_period_minutes() && nodal - sso.orbital_period_minutes() < 0.5);
+
+        let critical = Orbit::new(26_600.0, 0.74, 63.4349, 0.0, 270.0, 0.0).unwrap();
+        assert!(critical.j2_arg_perigee_rate_deg_per_day().abs() < 1e-4);
+
+        let iss = Orbit::new(6778.0, 0.0001, 51.6, 100.0, 90.0, 0.0).unwrap();
+        assert!((iss.j2_raan_rate_deg_per_day() + 5.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_orbital_velocity() {
+        let circular = Orbit::new(6778.0, 0.0, 51.6, 0.0, 0.0, 0.0).unwrap();
+        let expected = (EARTH_MU / 6778.0).sqrt();
+        assert!((circular.velocity_at_true_anomaly(123.0) - expected).abs() < 1e-9);
+        assert!((circular.perigee_velocity() - 7.67).abs() < 0.01);
+
+        // GTO: 185 km perigee, geostationary apogee
+        let gto = Orbit::new(24_400.0, 0.73, 28.5, 0.0, 0.0, 0.0).unwrap();
+        assert!(gto.perigee_velocity() > gto.apogee_velocity());
+        assert!((gto.perigee_velocity() - 10.24).abs() < 0.05);
+        assert!((gto.apogee_velocity() - 1.60).abs() < 0.05);
+
+        // Energy is conserved between perigee and apogee
+        let at = |v: f64, r: f64| v * v / 2.0 - EARTH_MU / r;
+        let rp = gto.semi_major_axis_km * (1.0 - gto.eccentricity);
+        let ra = gto.semi_major_axis_km * (1.0 + gto.eccentricity);
+        assert!((at(gto.perigee_velocity(), rp) - gto.specific_orbital_energy()).abs() < 1e-9);
+        assert!((at(gto.apogee_velocity(), ra) - gto.specific_orbital_energy()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_time_range_parsing() {
+        use chrono::TimeZone;
+
+        let range = TimeRange::new("2024-03-01T06:00:00Z", "2024-03-01T12:00:00Z").unwrap();
+        let at = |h, m, s| Utc.with_ymd_and_hms(2024, 3, 1, h, m, s).unwrap();
+        assert_eq!(range.duration_hours().unwrap(), 6.0);
+        assert!(range.contains(at(9, 0, 0)).unwrap());
+        assert!(range.contains(at(12, 0, 0)).unwrap());
+        assert!(!range.contains(at(5, 59, 59)).unwrap());
+
+        let later = TimeRange::new("2024-03-01T11:00:00+02:00", "2024-03-01T18:00:00Z").unwrap();
+        assert!(range.overlaps(&later).unwrap() && later.overlaps(&range).unwrap());
+        let disjoint = TimeRange::new("2024-03-01T13:00:00Z", "2024-03-01T14:00:00Z").unwrap();
+        assert!(!range.overlaps(&disjoint).unwrap());
+
+        assert_eq!(
+            TimeRange::new("yesterday", "2024-03-01T12:00:00Z")
+                .unwrap_err()
+                .field,
+            "start"
+        );
+        assert_eq!(
+            TimeRange::new("2024-03-01T12:00:00Z", "")
+                .unwrap_err()
+                .field,
+            "end"
+        );
+
+        // Fields set directly are only checked when parsed
+        let bad = TimeRange {
+            start: "yesterday".to_string(),
+            end: "2024-03-01T12:00:00Z".to_string(),
+        };
+        assert_eq!(bad.duration_hours().unwrap_err().field, "start");
+        assert_eq!(bad.contains(at(9, 0, 0)).unwrap_err().field, "start");
+        assert_eq!(range.overlaps(&bad).unwrap_err().field, "start");
+
+        let next = TimeRange::next_hours(0.0001);
+        assert_eq!(next.duration_hours().unwrap(), 0.0001);
+        for hours in [-5.0, f64::NAN, f64::NEG_INFINITY] {
+            assert_eq!(TimeRange::next_hours(hours).duration_hours().unwrap(), 0.0);
+        }
+        for hours in [1e12, f64::INFINITY] {
+            let capped = TimeRange::next_hours(hours);
+            assert_eq!(capped.end, "9999-12-31T23:59:59.999Z");
+            assert!(capped.end_time().is_ok());
+        }
+    }
 }